@@ -1,7 +1,8 @@
 use axiom_ext_tx::ExternalTransaction;
-use axiom_types::{Slot, Epoch, Hash};
+use axiom_types::{Address, Slot, Epoch, Hash};
 
 use crate::hash::block_hash;
+use crate::{BlockHeader, compute_transactions_root};
 
 /// A block is an ordered batch of external transactions.
 ///
@@ -17,6 +18,15 @@ pub struct Block {
     /// Epoch context for protocol transitions
     pub epoch: Epoch,
 
+    /// Base fee every transaction in this block pays
+    pub base_fee: u64,
+
+    /// Gas consumed by all transactions in this block
+    pub gas_used: u64,
+
+    /// Account credited with this block's unburned fees
+    pub proposer: Address,
+
     /// State root AFTER executing this block
     pub state_root: Hash,
 
@@ -28,7 +38,23 @@ pub struct Block {
 }
 
 impl Block {
-    /// Compute the canonical hash of this block.
+    /// Build the header committing to this block.
+    pub fn header(&self) -> BlockHeader {
+        BlockHeader {
+            parent_hash: self.parent_hash,
+            slot: self.slot,
+            epoch: self.epoch,
+            base_fee: self.base_fee,
+            transaction_count: self.transactions.len() as u64,
+            gas_used: self.gas_used,
+            proposer: self.proposer,
+            state_root: self.state_root,
+            receipts_root: self.receipts_root,
+            transactions_root: compute_transactions_root(&self.transactions),
+        }
+    }
+
+    /// Compute the canonical hash of this block (its header hash).
     pub fn hash(&self) -> Hash {
         block_hash(self)
     }
@@ -0,0 +1,298 @@
+use std::collections::{BTreeMap, HashMap};
+
+use axiom_ext_tx::{ExternalTransaction, MultisigApproval, MultisigPolicy, SchemeId, Signature, ValidityWindow};
+use axiom_tx::{CallData, TransactionCell, WriteIntent};
+use axiom_types::{Address, Epoch, Hash, ObjectId, Slot};
+
+use crate::encode::CODEC_VERSION;
+use crate::{Block, DecodeError};
+
+/// Minimum encoded sizes, used to bound counts before allocating.
+const READ_ENTRY_SIZE: usize = 32 + 8;
+const WRITE_ENTRY_SIZE: usize = 32 + 1;
+const MEMBER_SIZE: usize = 32;
+const SIGNATURE_MIN_SIZE: usize = 1 + 4 + 4;
+const APPROVAL_MIN_SIZE: usize = 4 + 4 + 4;
+const CELL_MIN_SIZE: usize = 4 + 4 + 32 + 4 + 4;
+const TRANSACTION_MIN_SIZE: usize = 8 + 32 + 8 + 16 + 16 + 8 + 4 + SIGNATURE_MIN_SIZE + 4;
+
+/// Decode a block produced by `encode_block`.
+///
+/// The whole input must be consumed.
+pub fn decode_block(bytes: &[u8]) -> Result<Block, DecodeError> {
+    let mut decoder = Decoder::new(bytes);
+    decoder.read_version()?;
+    let block = decoder.read_block()?;
+    decoder.finish()?;
+    Ok(block)
+}
+
+/// Decode a transaction produced by `encode_transaction`.
+///
+/// The whole input must be consumed.
+pub fn decode_transaction(bytes: &[u8]) -> Result<ExternalTransaction, DecodeError> {
+    let mut decoder = Decoder::new(bytes);
+    decoder.read_version()?;
+    let tx = decoder.read_transaction()?;
+    decoder.finish()?;
+    Ok(tx)
+}
+
+/// Strict reader over the canonical encoding.
+///
+/// Every read either consumes a complete, canonical value or fails;
+/// lengths and counts are checked against the remaining input.
+pub struct Decoder<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Decoder<'a> {
+    /// Create a decoder over `bytes`.
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    /// Fail unless all input was consumed.
+    pub fn finish(self) -> Result<(), DecodeError> {
+        if !self.bytes.is_empty() {
+            return Err(DecodeError::TrailingBytes { remaining: self.bytes.len() });
+        }
+        Ok(())
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], DecodeError> {
+        if len > self.bytes.len() {
+            return Err(DecodeError::UnexpectedEnd {
+                needed: len,
+                remaining: self.bytes.len(),
+            });
+        }
+
+        let (head, tail) = self.bytes.split_at(len);
+        self.bytes = tail;
+        Ok(head)
+    }
+
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N], DecodeError> {
+        let mut out = [0u8; N];
+        out.copy_from_slice(self.take(N)?);
+        Ok(out)
+    }
+
+    fn read_u8(&mut self) -> Result<u8, DecodeError> {
+        Ok(self.read_array::<1>()?[0])
+    }
+
+    fn read_u32(&mut self) -> Result<u32, DecodeError> {
+        Ok(u32::from_be_bytes(self.read_array()?))
+    }
+
+    fn read_u64(&mut self) -> Result<u64, DecodeError> {
+        Ok(u64::from_be_bytes(self.read_array()?))
+    }
+
+    fn read_hash(&mut self) -> Result<Hash, DecodeError> {
+        Ok(Hash::new(self.read_array()?))
+    }
+
+    fn read_object_id(&mut self) -> Result<ObjectId, DecodeError> {
+        Ok(ObjectId::new(self.read_hash()?))
+    }
+
+    fn read_address(&mut self) -> Result<Address, DecodeError> {
+        Ok(Address::new(self.read_array()?))
+    }
+
+    /// Read a count of items each at least `item_size` bytes long.
+    fn read_count(&mut self, item_size: usize) -> Result<usize, DecodeError> {
+        let count = self.read_u32()?;
+
+        if (count as usize).saturating_mul(item_size) > self.bytes.len() {
+            return Err(DecodeError::LengthOverflow {
+                length: count,
+                remaining: self.bytes.len(),
+            });
+        }
+
+        Ok(count as usize)
+    }
+
+    /// Read a u32 length-prefixed byte string.
+    fn read_bytes(&mut self) -> Result<Vec<u8>, DecodeError> {
+        let len = self.read_count(1)?;
+        Ok(self.take(len)?.to_vec())
+    }
+
+    fn read_optional_hash(&mut self) -> Result<Option<Hash>, DecodeError> {
+        match self.read_u8()? {
+            0 => Ok(None),
+            1 => Ok(Some(self.read_hash()?)),
+            tag => Err(DecodeError::InvalidTag { field: "parent_hash", tag }),
+        }
+    }
+
+    /// Read and check the codec version byte.
+    pub fn read_version(&mut self) -> Result<(), DecodeError> {
+        match self.read_u8()? {
+            CODEC_VERSION => Ok(()),
+            version => Err(DecodeError::UnsupportedVersion { version }),
+        }
+    }
+
+    /// Read a block (without the version byte).
+    pub fn read_block(&mut self) -> Result<Block, DecodeError> {
+        let parent_hash = self.read_optional_hash()?;
+        let slot = Slot::new(self.read_u64()?);
+        let epoch = Epoch::new(self.read_u64()?);
+        let base_fee = self.read_u64()?;
+        let gas_used = self.read_u64()?;
+        let proposer = self.read_address()?;
+        let state_root = self.read_hash()?;
+        let receipts_root = self.read_hash()?;
+
+        let count = self.read_count(TRANSACTION_MIN_SIZE)?;
+        let mut transactions = Vec::with_capacity(count);
+        for _ in 0..count {
+            transactions.push(self.read_transaction()?);
+        }
+
+        Ok(Block {
+            parent_hash,
+            slot,
+            epoch,
+            base_fee,
+            gas_used,
+            proposer,
+            state_root,
+            receipts_root,
+            transactions,
+        })
+    }
+
+    /// Read an external transaction (without the version byte).
+    pub fn read_transaction(&mut self) -> Result<ExternalTransaction, DecodeError> {
+        let chain_id = self.read_u64()?;
+        let signer = self.read_address()?;
+        let nonce = self.read_u64()?;
+        let validity = ValidityWindow::new(Slot::new(self.read_u64()?), Slot::new(self.read_u64()?));
+        let max_fee = self.read_u64()?;
+        let priority_fee = self.read_u64()?;
+        let gas_limit = self.read_u64()?;
+
+        let count = self.read_count(CELL_MIN_SIZE)?;
+        let mut cells = Vec::with_capacity(count);
+        for _ in 0..count {
+            cells.push(self.read_cell()?);
+        }
+
+        let signature = self.read_signature()?;
+
+        let count = self.read_count(APPROVAL_MIN_SIZE)?;
+        let mut approvals = Vec::with_capacity(count);
+        for _ in 0..count {
+            approvals.push(self.read_approval()?);
+        }
+
+        Ok(ExternalTransaction {
+            chain_id,
+            signer,
+            nonce,
+            validity,
+            max_fee,
+            priority_fee,
+            gas_limit,
+            cells,
+            signature,
+            approvals,
+        })
+    }
+
+    /// Read a transaction cell.
+    ///
+    /// Read and write sets MUST be strictly ascending by object id.
+    pub fn read_cell(&mut self) -> Result<TransactionCell, DecodeError> {
+        let count = self.read_count(READ_ENTRY_SIZE)?;
+        let mut read_set = HashMap::with_capacity(count);
+        let mut previous: Option<ObjectId> = None;
+        for _ in 0..count {
+            let id = self.read_object_id()?;
+            if previous.is_some_and(|previous| previous >= id) {
+                return Err(DecodeError::NonCanonicalOrder { field: "read_set" });
+            }
+            previous = Some(id);
+
+            read_set.insert(id, self.read_u64()?);
+        }
+
+        let count = self.read_count(WRITE_ENTRY_SIZE)?;
+        let mut write_set = BTreeMap::new();
+        let mut previous: Option<ObjectId> = None;
+        for _ in 0..count {
+            let id = self.read_object_id()?;
+            if previous.is_some_and(|previous| previous >= id) {
+                return Err(DecodeError::NonCanonicalOrder { field: "write_set" });
+            }
+            previous = Some(id);
+
+            let tag = self.read_u8()?;
+            let intent = WriteIntent::from_u8(tag)
+                .ok_or(DecodeError::InvalidTag { field: "write_intent", tag })?;
+            write_set.insert(id, intent);
+        }
+
+        let call = self.read_call_data()?;
+
+        TransactionCell::new(read_set, write_set, call)
+            .map_err(DecodeError::InvalidCell)
+    }
+
+    /// Read call data.
+    pub fn read_call_data(&mut self) -> Result<CallData, DecodeError> {
+        Ok(CallData {
+            target: self.read_object_id()?,
+            selector: self.read_bytes()?,
+            payload: self.read_bytes()?,
+        })
+    }
+
+    /// Read a multisig approval.
+    ///
+    /// Members MUST be strictly ascending (the canonical policy form).
+    pub fn read_approval(&mut self) -> Result<MultisigApproval, DecodeError> {
+        let threshold = self.read_u32()?;
+
+        let count = self.read_count(MEMBER_SIZE)?;
+        let mut members: Vec<Address> = Vec::with_capacity(count);
+        for _ in 0..count {
+            let member = self.read_address()?;
+            if members.last().is_some_and(|previous| *previous >= member) {
+                return Err(DecodeError::NonCanonicalOrder { field: "members" });
+            }
+            members.push(member);
+        }
+
+        let policy = MultisigPolicy::new(threshold, members)
+            .map_err(DecodeError::InvalidPolicy)?;
+
+        let count = self.read_count(SIGNATURE_MIN_SIZE)?;
+        let mut signatures = Vec::with_capacity(count);
+        for _ in 0..count {
+            signatures.push(self.read_signature()?);
+        }
+
+        Ok(MultisigApproval { policy, signatures })
+    }
+
+    /// Read a signature.
+    pub fn read_signature(&mut self) -> Result<Signature, DecodeError> {
+        let tag = self.read_u8()?;
+        let scheme = SchemeId::from_u8(tag)
+            .ok_or(DecodeError::InvalidTag { field: "scheme", tag })?;
+
+        Ok(Signature {
+            scheme,
+            public_key: self.read_bytes()?,
+            bytes: self.read_bytes()?,
+        })
+    }
+}
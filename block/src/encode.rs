@@ -1,6 +1,13 @@
+use axiom_ext_tx::{ExternalTransaction, MultisigApproval, Signature};
+use axiom_tx::{CallData, TransactionCell};
+use axiom_types::Hash;
 
-use axiom_ext_tx::ExternalTransaction;
-use axiom_types::{Slot, Epoch, Hash};
+use crate::{Block, BlockHeader};
+
+/// Version of the binary block and transaction codec.
+///
+/// Written as the first byte of every top-level encoding.
+pub const CODEC_VERSION: u8 = 8;
 
 /// Append a u64 as big-endian bytes.
 fn push_u64(buf: &mut Vec<u8>, value: u64) {
@@ -12,26 +19,15 @@ fn push_u32(buf: &mut Vec<u8>, value: u32) {
     buf.extend_from_slice(&value.to_be_bytes());
 }
 
-/// Canonically encode a block.
-pub fn encode_block(
-    parent_hash: Option<Hash>,
-    slot: Slot,
-    epoch: Epoch,
-    state_root: Hash,
-    receipts_root: Hash,
-    transactions: &[ExternalTransaction],
-) -> Vec<u8> {
-    let mut buf = Vec::new();
-
-    // -------------------------------------------------------------
-    // Domain separator (block)
-    // -------------------------------------------------------------
-    buf.extend_from_slice(b"Axiom::Block::v1");
+/// Append a u32 length prefix followed by the bytes.
+fn push_bytes(buf: &mut Vec<u8>, bytes: &[u8]) {
+    push_u32(buf, bytes.len() as u32);
+    buf.extend_from_slice(bytes);
+}
 
-    // -------------------------------------------------------------
-    // Parent hash
-    // -------------------------------------------------------------
-    match parent_hash {
+/// Append an optional hash behind a presence flag.
+fn push_optional_hash(buf: &mut Vec<u8>, hash: Option<Hash>) {
+    match hash {
         Some(hash) => {
             buf.push(1); // presence flag
             buf.extend_from_slice(hash.as_bytes());
@@ -40,45 +36,101 @@ pub fn encode_block(
             buf.push(0); // genesis
         }
     }
+}
+
+/// Canonically encode a block header.
+pub fn encode_block_header(header: &BlockHeader) -> Vec<u8> {
+    let mut buf = Vec::new();
+
+    // -------------------------------------------------------------
+    // Domain separator (block header)
+    // -------------------------------------------------------------
+    buf.extend_from_slice(b"Axiom::BlockHeader::v4");
+
+    // -------------------------------------------------------------
+    // Parent hash
+    // -------------------------------------------------------------
+    push_optional_hash(&mut buf, header.parent_hash);
 
     // -------------------------------------------------------------
     // Slot
     // -------------------------------------------------------------
-    push_u64(&mut buf, slot.value());
+    push_u64(&mut buf, header.slot.value());
 
     // -------------------------------------------------------------
     // Epoch
     // -------------------------------------------------------------
-    push_u64(&mut buf, epoch.value());
+    push_u64(&mut buf, header.epoch.value());
+
+    // -------------------------------------------------------------
+    // Base fee, transaction count and gas used
+    // -------------------------------------------------------------
+    push_u64(&mut buf, header.base_fee);
+    push_u64(&mut buf, header.transaction_count);
+    push_u64(&mut buf, header.gas_used);
+
+    // -------------------------------------------------------------
+    // Proposer
+    // -------------------------------------------------------------
+    buf.extend_from_slice(header.proposer.as_bytes());
 
     // -------------------------------------------------------------
     // State root
     // -------------------------------------------------------------
-    buf.extend_from_slice(state_root.as_bytes());
+    buf.extend_from_slice(header.state_root.as_bytes());
 
     // -------------------------------------------------------------
     // Receipts root
     // -------------------------------------------------------------
-    buf.extend_from_slice(receipts_root.as_bytes());
+    buf.extend_from_slice(header.receipts_root.as_bytes());
 
     // -------------------------------------------------------------
-    // Transactions
+    // Transactions root
     // -------------------------------------------------------------
-    push_u32(&mut buf, transactions.len() as u32);
+    buf.extend_from_slice(header.transactions_root.as_bytes());
+
+    buf
+}
+
+/// Encode a full block (header fields and body) for storage or transport.
+///
+/// Decoded by `decode_block`.
+pub fn encode_block(block: &Block) -> Vec<u8> {
+    let mut buf = vec![CODEC_VERSION];
+
+    push_optional_hash(&mut buf, block.parent_hash);
+    push_u64(&mut buf, block.slot.value());
+    push_u64(&mut buf, block.epoch.value());
+    push_u64(&mut buf, block.base_fee);
+    push_u64(&mut buf, block.gas_used);
+    buf.extend_from_slice(block.proposer.as_bytes());
+    buf.extend_from_slice(block.state_root.as_bytes());
+    buf.extend_from_slice(block.receipts_root.as_bytes());
 
-    for tx in transactions {
+    push_u32(&mut buf, block.transactions.len() as u32);
+
+    for tx in &block.transactions {
         encode_external_transaction(&mut buf, tx);
     }
 
     buf
 }
 
-/// Canonically encode an external transaction (for block hashing).
-fn encode_external_transaction(buf: &mut Vec<u8>, tx: &ExternalTransaction) {
+/// Encode a single external transaction for storage or transport.
+///
+/// Decoded by `decode_transaction`.
+pub fn encode_transaction(tx: &ExternalTransaction) -> Vec<u8> {
+    let mut buf = vec![CODEC_VERSION];
+    encode_external_transaction(&mut buf, tx);
+    buf
+}
+
+/// Canonically encode an external transaction.
+pub fn encode_external_transaction(buf: &mut Vec<u8>, tx: &ExternalTransaction) {
     // -------------------------------------------------------------
-    // Domain separator (external tx)
+    // Chain id
     // -------------------------------------------------------------
-    buf.extend_from_slice(b"Axiom::ExternalTx::v1");
+    push_u64(buf, tx.chain_id);
 
     // -------------------------------------------------------------
     // Signer
@@ -91,20 +143,79 @@ fn encode_external_transaction(buf: &mut Vec<u8>, tx: &ExternalTransaction) {
     push_u64(buf, tx.nonce);
 
     // -------------------------------------------------------------
-    // Cells (ordered by cell id)
+    // Validity window
     // -------------------------------------------------------------
-    let mut cell_ids: Vec<_> = tx.cells.iter().map(|c| c.id()).collect();
-    cell_ids.sort();
+    push_u64(buf, tx.validity.from.value());
+    push_u64(buf, tx.validity.until.value());
 
-    push_u32(buf, cell_ids.len() as u32);
+    // -------------------------------------------------------------
+    // Fees
+    // -------------------------------------------------------------
+    push_u64(buf, tx.max_fee);
+    push_u64(buf, tx.priority_fee);
 
-    for id in cell_ids {
-        buf.extend_from_slice(id.as_bytes());
+    // -------------------------------------------------------------
+    // Gas limit
+    // -------------------------------------------------------------
+    push_u64(buf, tx.gas_limit);
+
+    // -------------------------------------------------------------
+    // Cells (in declared order, which is execution order)
+    // -------------------------------------------------------------
+    push_u32(buf, tx.cells.len() as u32);
+
+    for cell in &tx.cells {
+        encode_cell(buf, cell);
     }
 
     // -------------------------------------------------------------
     // Signature
     // -------------------------------------------------------------
-    push_u32(buf, tx.signature.bytes.len() as u32);
-    buf.extend_from_slice(&tx.signature.bytes);
+    encode_signature(buf, &tx.signature);
+
+    // -------------------------------------------------------------
+    // Multisig approvals (in declared order)
+    // -------------------------------------------------------------
+    push_u32(buf, tx.approvals.len() as u32);
+
+    for approval in &tx.approvals {
+        encode_approval(buf, approval);
+    }
+}
+
+/// Canonically encode a transaction cell.
+///
+/// Read and write sets are encoded in ascending object id order.
+pub fn encode_cell(buf: &mut Vec<u8>, cell: &TransactionCell) {
+    buf.extend_from_slice(&cell.encode());
+}
+
+/// Canonically encode call data.
+pub fn encode_call_data(buf: &mut Vec<u8>, call: &CallData) {
+    buf.extend_from_slice(call.target.as_hash().as_bytes());
+    push_bytes(buf, &call.selector);
+    push_bytes(buf, &call.payload);
+}
+
+/// Canonically encode a multisig approval.
+pub fn encode_approval(buf: &mut Vec<u8>, approval: &MultisigApproval) {
+    push_u32(buf, approval.policy.threshold());
+    push_u32(buf, approval.policy.members().len() as u32);
+
+    for member in approval.policy.members() {
+        buf.extend_from_slice(member.as_bytes());
+    }
+
+    push_u32(buf, approval.signatures.len() as u32);
+
+    for signature in &approval.signatures {
+        encode_signature(buf, signature);
+    }
+}
+
+/// Canonically encode a signature (scheme tag first so the encoding is unambiguous).
+pub fn encode_signature(buf: &mut Vec<u8>, signature: &Signature) {
+    buf.push(signature.scheme.as_u8());
+    push_bytes(buf, &signature.public_key);
+    push_bytes(buf, &signature.bytes);
 }
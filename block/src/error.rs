@@ -0,0 +1,80 @@
+use axiom_ext_tx::SignatureError;
+use axiom_state::SupplyError;
+use axiom_tx::TxError;
+use axiom_types::{Epoch, Hash, Slot};
+
+/// Errors returned when decoding blocks and transactions.
+#[derive(Debug)]
+pub enum DecodeError {
+    /// Input ended before a field was complete.
+    UnexpectedEnd { needed: usize, remaining: usize },
+
+    /// Input continued after the decoded value.
+    TrailingBytes { remaining: usize },
+
+    /// Codec version is not supported.
+    UnsupportedVersion { version: u8 },
+
+    /// A length or count cannot fit in the remaining input.
+    LengthOverflow { length: u32, remaining: usize },
+
+    /// A tag byte has no meaning for `field`.
+    InvalidTag { field: &'static str, tag: u8 },
+
+    /// Entries of `field` are not strictly ascending.
+    NonCanonicalOrder { field: &'static str },
+
+    /// Decoded cell violates cell invariants.
+    InvalidCell(TxError),
+
+    /// Decoded multisig policy is invalid.
+    InvalidPolicy(SignatureError),
+}
+
+/// Errors returned when validating a received block.
+#[derive(Debug)]
+pub enum BlockError {
+    /// Block does not build on the given parent.
+    ParentHashMismatch { expected: Option<Hash>, found: Option<Hash> },
+
+    /// Block slot is not after the parent slot.
+    InvalidSlot { parent: Slot, found: Slot },
+
+    /// Block epoch is before the parent epoch.
+    InvalidEpoch { parent: Epoch, found: Epoch },
+
+    /// Block names the zero address as its proposer, and is not a
+    /// genesis block without a reward.
+    ZeroProposer,
+
+    /// Block base fee is not the one derived from its parent.
+    BaseFeeMismatch { expected: u64, found: u64 },
+
+    /// Block carries more transactions than allowed.
+    TooManyTransactions { max: u64, found: u64 },
+
+    /// Block consumes more gas than allowed.
+    TooMuchGas { max: u64, found: u64 },
+
+    /// Transaction at `index` may use more gas than the block has left.
+    TransactionGasExceedsBlock { index: u32, gas_limit: u64, remaining: u64 },
+
+    /// Claimed state root differs from the executed one.
+    StateRootMismatch { claimed: Hash, computed: Hash },
+
+    /// Claimed receipts root differs from the executed one.
+    ReceiptsRootMismatch { claimed: Hash, computed: Hash },
+
+    /// Claimed gas used differs from the executed total.
+    GasUsedMismatch { claimed: u64, computed: u64 },
+
+    /// Summing or crediting the block's `field` does not fit in a u64.
+    AmountOverflow { field: &'static str },
+
+    /// Balances changed by `found` while supply changed by `expected`.
+    SupplyMismatch { expected: i128, found: i128 },
+
+    /// Supply object is malformed, or the full scan of balances
+    /// (debug builds) found supply not conserved.
+    Supply(SupplyError),
+}
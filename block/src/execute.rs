@@ -1,58 +1,281 @@
 use axiom_execution_engine::{ExecutionEngine, ExecutionContext};
-use axiom_protocol::{process_external_transaction};
-use axiom_state::{StateStore, compute_state_root};
+use axiom_protocol::{ChainConfig, process_external_transaction};
+use axiom_state::{
+    Checkpoint,
+    StateObject,
+    StateStore,
+    Supply,
+    SupplyError,
+    balance_object_id,
+    check_supply,
+    compute_state_root,
+    decode_balance,
+    encode_balance,
+    is_balance_object,
+    read_supply,
+    supply_object_id,
+};
+use axiom_types::Address;
 
-use crate::{Block, BlockExecutionResult, TransactionResult, compute_receipts_root};
+use crate::{Block, BlockError, BlockExecutionResult, Receipt, TransactionResult, compute_receipts_root, transaction_hash};
 
 /// Execute a block against the given state.
 ///
 /// Transactions are executed sequentially in block order.
-/// Each transaction is atomic: failures do not affect state.
+/// Each transaction is atomic: a failed transaction only pays its fee
+/// and advances its nonce, and a rejected one does not affect state.
+///
+/// The block may carry at most `config.fees.max_transactions`, and the
+/// gas limit of each transaction must fit in what is left of
+/// `config.max_block_gas` after the ones before it.
+///
+/// Once all transactions ran, collected fees are routed: a share of the
+/// base fee part set by `config.fees` is burned and the rest, every tip
+/// included, credited to `block.proposer`. Fails, leaving `state` as it
+/// was, if the block's gas or fees or the proposer's credited balance do
+/// not fit in a u64, or if a reward would go to the zero address.
+///
+/// The block must conserve supply: the balances it writes must change by
+/// exactly as much as issued minus burned. In debug builds, if `state`
+/// tracks supply, every balance is also summed to check that all of them
+/// plus everything burned equal everything issued.
+///
+/// Each transaction runs in its own `StateStore` checkpoint layer.
+/// Writes are staged in `state`; to execute speculatively, open a
+/// checkpoint before calling this and release or roll it back after.
+/// Call `StateStore::commit` once the block is accepted to persist
+/// its writes as a single batch.
 pub fn execute_block<E: ExecutionEngine>(
     state: &mut StateStore,
     block: &mut Block,
     engine: &E,
-) -> BlockExecutionResult {
-    let mut tx_results = Vec::with_capacity(block.transactions.len());
-    let mut tx_hashes = Vec::with_capacity(block.transactions.len());
+    config: &ChainConfig,
+) -> Result<BlockExecutionResult, BlockError> {
+    let checkpoint = state.checkpoint();
 
-    let context = ExecutionContext {
-        slot: block.slot,
-        epoch: block.epoch,
+    let result = match execute_transactions(state, block, engine, config, checkpoint) {
+        Ok(result) => {
+            state.release(checkpoint).expect("block checkpoint is open");
+            result
+        }
+        Err(err) => {
+            state.rollback(checkpoint).expect("block checkpoint is open");
+            return Err(err);
+        }
     };
 
-    for tx in &block.transactions {
-        let tx_hash = tx.signing_hash();
-        tx_hashes.push(tx_hash);
-        
+    // 🔒 Commit execution results
+    block.state_root = compute_state_root(state);
+    block.receipts_root = compute_receipts_root(&result.receipts);
+    block.gas_used = result.gas_used;
+
+    Ok(result)
+}
+
+/// Execute the transactions of `block`, route their fees and check supply
+/// conservation, without touching its header.
+///
+/// `checkpoint` MUST have been opened right before the call; the caller
+/// rolls it back on error.
+pub(crate) fn execute_transactions<E: ExecutionEngine>(
+    state: &mut StateStore,
+    block: &Block,
+    engine: &E,
+    config: &ChainConfig,
+    checkpoint: Checkpoint,
+) -> Result<BlockExecutionResult, BlockError> {
+    let found = block.transactions.len() as u64;
+    if found > config.fees.max_transactions {
+        return Err(BlockError::TooManyTransactions { max: config.fees.max_transactions, found });
+    }
+
+    let mut tx_results = Vec::with_capacity(block.transactions.len());
+    let mut receipts = Vec::with_capacity(block.transactions.len());
+    let mut gas_used: u64 = 0;
+
+    let context = ExecutionContext::new(block.slot, block.epoch, block.base_fee);
+
+    for (index, tx) in block.transactions.iter().enumerate() {
+        // Every transaction must fit in the gas the block has left, even
+        // if it uses all of its limit
+        let remaining = config.max_block_gas.saturating_sub(gas_used);
+        if tx.gas_limit > remaining {
+            return Err(BlockError::TransactionGasExceedsBlock {
+                index: index as u32,
+                gas_limit: tx.gas_limit,
+                remaining,
+            });
+        }
+
+        let tx_hash = transaction_hash(tx);
+        let checkpoint = state.checkpoint();
+
         let result = process_external_transaction(
             state,
             tx.clone(),
             engine,
             context,
+            config,
         );
 
-        match result {
-            Ok(()) => {
-                // For now, fee is fixed and known (e.g. 1)
-                tx_results.push(TransactionResult::Success {
-                    fee_charged: 1,
-                });
+        let tx_result = match result {
+            Ok(outcome) => {
+                state.release(checkpoint).expect("transaction checkpoint is open");
+
+                match outcome.failure {
+                    None => TransactionResult::Success {
+                        fee_charged: outcome.fee_charged,
+                        gas_used: outcome.gas_used,
+                    },
+                    Some(error) => TransactionResult::Failure {
+                        error,
+                        fee_charged: outcome.fee_charged,
+                        gas_used: outcome.gas_used,
+                    },
+                }
             }
             Err(err) => {
-                // State must be unchanged here (guaranteed by pipeline)
-                tx_results.push(TransactionResult::Failure {
+                // Discard anything the rejected transaction staged
+                state.rollback(checkpoint).expect("transaction checkpoint is open");
+                TransactionResult::Failure {
                     error: err,
-                });
+                    fee_charged: 0,
+                    gas_used: 0,
+                }
             }
-        }
+        };
+
+        let receipt = Receipt::new(index as u32, tx_hash, &tx_result);
+        gas_used = gas_used.checked_add(receipt.gas_used)
+            .ok_or(BlockError::AmountOverflow { field: "gas_used" })?;
+
+        receipts.push(receipt);
+        tx_results.push(tx_result);
     }
 
-    // 🔒 Commit execution results
-    block.state_root = compute_state_root(state);
-    block.receipts_root = compute_receipts_root(&tx_hashes, &tx_results);
+    // -------------------------------------------------------------
+    // Fee routing (burn, proposer reward)
+    // -------------------------------------------------------------
+    let mut total_fees: u64 = 0;
+
+    for receipt in &receipts {
+        total_fees = total_fees.checked_add(receipt.fee_charged)
+            .ok_or(BlockError::AmountOverflow { field: "total_fees" })?;
+    }
+
+    // Only the base fee part is burned, the rest goes to the proposer
+    let base_fees = gas_used.checked_mul(block.base_fee)
+        .ok_or(BlockError::AmountOverflow { field: "base_fees" })?;
+    let burned = config.fees.burned(base_fees);
+    let proposer_reward = total_fees.checked_sub(burned)
+        .ok_or(BlockError::AmountOverflow { field: "proposer_reward" })?;
+
+    // The zero address must never hold a balance, even in genesis
+    if proposer_reward > 0 && block.proposer == Address::zero() {
+        return Err(BlockError::ZeroProposer);
+    }
+
+    credit(state, block.proposer, proposer_reward)?;
+    burn(state, burned)?;
+
+    // -------------------------------------------------------------
+    // Supply conservation
+    // -------------------------------------------------------------
+    check_supply_delta(state, checkpoint, burned)?;
+
+    if cfg!(debug_assertions) && read_supply(state).map_err(BlockError::Supply)?.is_some() {
+        check_supply(state).map_err(BlockError::Supply)?;
+    }
 
-    BlockExecutionResult {
+    Ok(BlockExecutionResult {
         tx_results,
+        receipts,
+        gas_used,
+        total_fees,
+        burned,
+        proposer_reward,
+    })
+}
+
+/// Record `amount` as burned in the supply object, if `state` tracks supply.
+fn burn(state: &mut StateStore, amount: u64) -> Result<(), BlockError> {
+    if amount == 0 {
+        return Ok(());
     }
+
+    let Some(mut supply) = read_supply(state).map_err(BlockError::Supply)? else {
+        return Ok(());
+    };
+
+    supply.burned = supply.burned.checked_add(amount)
+        .ok_or(BlockError::AmountOverflow { field: "burned" })?;
+
+    let updated = state.get(&supply_object_id())
+        .expect("supply object exists")
+        .next_with_data(supply.encode());
+
+    state.insert_or_update(updated).expect("supply continues its version");
+    Ok(())
+}
+
+/// Credit `amount` to the balance object of `account`, creating it if
+/// needed.
+fn credit(state: &mut StateStore, account: Address, amount: u64) -> Result<(), BlockError> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    let id = balance_object_id(account);
+
+    let credited = match state.get(&id) {
+        Some(existing) => {
+            let balance = decode_balance(existing).checked_add(amount)
+                .ok_or(BlockError::AmountOverflow { field: "proposer_reward" })?;
+            existing.next_with_data(encode_balance(balance))
+        }
+        None => match state.tombstone(&id) {
+            Some(tombstone) => StateObject::recreate(id, account, encode_balance(amount), tombstone),
+            None => StateObject::new(id, account, encode_balance(amount)),
+        },
+    };
+
+    state.insert_or_update(credited).expect("credited balance continues its version");
+    Ok(())
+}
+
+/// Check that the balances written since `checkpoint` changed by exactly
+/// as much as the supply (issued minus burned) did.
+///
+/// Supply only changes by `burned` when `state` does not track it. Costs
+/// one lookup per written object instead of a scan of every balance.
+fn check_supply_delta(state: &StateStore, checkpoint: Checkpoint, burned: u64) -> Result<(), BlockError> {
+    let written = state.written_since(checkpoint).expect("block checkpoint is open");
+
+    let mut found: i128 = 0;
+    for id in &written {
+        let before = state.get_before(checkpoint, id).expect("block checkpoint is open");
+        let balance = |object: Option<&StateObject>| match object {
+            Some(object) if is_balance_object(object) => decode_balance(object) as i128,
+            _ => 0,
+        };
+
+        found += balance(state.get(id)) - balance(before);
+    }
+
+    let net = |supply: Supply| supply.issued as i128 - supply.burned as i128;
+    let before = match state.get_before(checkpoint, &supply_object_id()).expect("block checkpoint is open") {
+        Some(object) => Some(Supply::decode(object.data()).ok_or(BlockError::Supply(SupplyError::MalformedSupply))?),
+        None => None,
+    };
+
+    let expected = match (before, read_supply(state).map_err(BlockError::Supply)?) {
+        (Some(before), Some(after)) => net(after) - net(before),
+        _ => -(burned as i128),
+    };
+
+    if found != expected {
+        return Err(BlockError::SupplyMismatch { expected, found });
+    }
+
+    Ok(())
 }
@@ -1,19 +1,11 @@
 use axiom_types::Hash;
-use blake3;
 
-use crate::encode::encode_block;
 use crate::Block;
 
 /// Compute the canonical hash of a block.
+///
+/// This is the hash of the block header; the body is committed
+/// through the transactions root.
 pub fn block_hash(block: &Block) -> Hash {
-    let bytes = encode_block(
-        block.parent_hash,
-        block.slot,
-        block.epoch,
-        block.state_root,
-        block.receipts_root,
-        &block.transactions,
-    );
-
-    Hash::new(blake3::hash(&bytes).into())
+    block.header().hash()
 }
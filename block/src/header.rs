@@ -0,0 +1,59 @@
+use axiom_ext_tx::ExternalTransaction;
+use axiom_protocol::FeeParameters;
+use axiom_types::{Address, Slot, Epoch, Hash};
+
+use crate::encode::encode_block_header;
+use crate::compute_transactions_root;
+
+/// A block header commits to the full block through Merkle roots.
+///
+/// Headers can be synced and verified without transaction bodies.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockHeader {
+    /// Hash of the parent block (None for genesis)
+    pub parent_hash: Option<Hash>,
+
+    /// Slot in which this block is executed
+    pub slot: Slot,
+
+    /// Epoch context for protocol transitions
+    pub epoch: Epoch,
+
+    /// Base fee every transaction in the block pays
+    pub base_fee: u64,
+
+    /// Number of transactions in the block body
+    pub transaction_count: u64,
+
+    /// Gas consumed by all transactions in the block
+    pub gas_used: u64,
+
+    /// Account credited with the block's unburned fees
+    pub proposer: Address,
+
+    /// State root AFTER executing this block
+    pub state_root: Hash,
+
+    /// Receipts root committing tx results
+    pub receipts_root: Hash,
+
+    /// Transactions root committing the ordered block body
+    pub transactions_root: Hash,
+}
+
+impl BlockHeader {
+    /// Compute the canonical hash of this header (the block hash).
+    pub fn hash(&self) -> Hash {
+        Hash::new(blake3::hash(&encode_block_header(self)).into())
+    }
+
+    /// Base fee the child of this block must declare.
+    pub fn next_base_fee(&self, fees: &FeeParameters) -> u64 {
+        fees.next_base_fee(self.base_fee, self.gas_used)
+    }
+
+    /// Check that `transactions` is the body committed by this header.
+    pub fn verify_body(&self, transactions: &[ExternalTransaction]) -> bool {
+        compute_transactions_root(transactions) == self.transactions_root
+    }
+}
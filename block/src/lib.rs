@@ -5,12 +5,32 @@
 pub mod block;
 pub mod result;
 pub mod execute;
+pub mod validate;
 pub mod encode;
+pub mod decode;
+pub mod error;
 pub mod hash;
 pub mod receipts_root;
+pub mod receipt;
+pub mod merkle;
+pub mod header;
+pub mod transactions_root;
 
 pub use block::Block;
+pub use header::BlockHeader;
+pub use transactions_root::{
+    compute_transactions_root,
+    transaction_hash,
+    transaction_proof,
+    verify_transaction_proof,
+};
 pub use result::{TransactionResult, BlockExecutionResult};
 pub use execute::execute_block;
+pub use validate::{validate_block, validate_header};
 pub use hash::block_hash;
-pub use receipts_root::compute_receipts_root;
+pub use encode::{CODEC_VERSION, encode_block, encode_transaction};
+pub use decode::{Decoder, decode_block, decode_transaction};
+pub use error::{DecodeError, BlockError};
+pub use receipts_root::{compute_receipts_root, receipt_proof, verify_receipt_proof};
+pub use receipt::{Receipt, ReceiptStatus};
+pub use merkle::MerkleProof;
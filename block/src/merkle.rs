@@ -0,0 +1,124 @@
+use axiom_types::Hash;
+use blake3;
+
+// Binary Merkle tree over an ordered list of leaf hashes.
+//
+// Levels are built bottom-up by hashing adjacent pairs; an unpaired last
+// node is promoted unchanged to the next level (never duplicated, so two
+// different lists cannot share a root). The leaf count is bound into the
+// root under a caller-supplied domain.
+
+/// Proof that a leaf is at `index` in a list of `leaf_count` leaves.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MerkleProof {
+    /// Position of the proven leaf.
+    pub index: u32,
+
+    /// Number of leaves in the tree.
+    pub leaf_count: u32,
+
+    /// Sibling hashes from the leaf level upwards.
+    ///
+    /// Levels where the node was promoted have no sibling.
+    pub siblings: Vec<Hash>,
+}
+
+fn node_hash(left: &Hash, right: &Hash) -> Hash {
+    let mut bytes = Vec::with_capacity(1 + 64);
+    bytes.push(1); // internal node tag
+    bytes.extend_from_slice(left.as_bytes());
+    bytes.extend_from_slice(right.as_bytes());
+
+    Hash::new(blake3::hash(&bytes).into())
+}
+
+/// Bind the tree root to its domain and leaf count.
+fn finalize(domain: &[u8], leaf_count: u32, tree_root: Hash) -> Hash {
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(domain);
+    bytes.extend_from_slice(&leaf_count.to_be_bytes());
+    bytes.extend_from_slice(tree_root.as_bytes());
+
+    Hash::new(blake3::hash(&bytes).into())
+}
+
+fn next_level(level: &[Hash]) -> Vec<Hash> {
+    level
+        .chunks(2)
+        .map(|pair| match pair {
+            [left, right] => node_hash(left, right),
+            [single] => *single,
+            _ => unreachable!("chunks(2) yields one or two nodes"),
+        })
+        .collect()
+}
+
+/// Compute the Merkle root of `leaves` under `domain`.
+pub fn merkle_root(domain: &[u8], leaves: &[Hash]) -> Hash {
+    let mut level = leaves.to_vec();
+
+    while level.len() > 1 {
+        level = next_level(&level);
+    }
+
+    finalize(domain, leaves.len() as u32, level.first().copied().unwrap_or(Hash::zero()))
+}
+
+/// Build a proof for the leaf at `index`, or `None` if out of range.
+pub fn merkle_proof(leaves: &[Hash], index: usize) -> Option<MerkleProof> {
+    if index >= leaves.len() {
+        return None;
+    }
+
+    let mut siblings = Vec::new();
+    let mut level = leaves.to_vec();
+    let mut position = index;
+
+    while level.len() > 1 {
+        let sibling = position ^ 1;
+        if sibling < level.len() {
+            siblings.push(level[sibling]);
+        }
+
+        level = next_level(&level);
+        position /= 2;
+    }
+
+    Some(MerkleProof {
+        index: index as u32,
+        leaf_count: leaves.len() as u32,
+        siblings,
+    })
+}
+
+/// Verify that `leaf` is committed by `root` under `domain`.
+pub fn verify_merkle_proof(domain: &[u8], root: &Hash, leaf: Hash, proof: &MerkleProof) -> bool {
+    if proof.index >= proof.leaf_count {
+        return false;
+    }
+
+    let mut siblings = proof.siblings.iter();
+    let mut current = leaf;
+    let mut position = proof.index;
+    let mut width = proof.leaf_count;
+
+    while width > 1 {
+        let sibling = position ^ 1;
+        if sibling < width {
+            let Some(hash) = siblings.next() else {
+                return false;
+            };
+
+            current = if position.is_multiple_of(2) {
+                node_hash(&current, hash)
+            } else {
+                node_hash(hash, &current)
+            };
+        }
+
+        position /= 2;
+        width = width.div_ceil(2);
+    }
+
+    siblings.next().is_none() && finalize(domain, proof.leaf_count, current) == *root
+}
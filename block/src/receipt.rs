@@ -0,0 +1,77 @@
+use axiom_types::Hash;
+use blake3;
+
+use crate::TransactionResult;
+
+/// Outcome of a transaction as committed in a receipt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum ReceiptStatus {
+    /// Transaction failed; at most its fee and nonce update were applied.
+    Failure = 0,
+
+    /// Transaction executed successfully.
+    Success = 1,
+}
+
+/// Committed result of a single transaction within a block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Receipt {
+    /// Position of the transaction in the block.
+    pub index: u32,
+
+    /// Hash of the transaction.
+    pub tx_hash: Hash,
+
+    /// Whether the transaction succeeded.
+    pub status: ReceiptStatus,
+
+    /// Fee charged for the transaction.
+    pub fee_charged: u64,
+
+    /// Gas consumed by execution.
+    pub gas_used: u64,
+}
+
+impl Receipt {
+    /// Build the receipt of the transaction at `index`.
+    pub fn new(index: u32, tx_hash: Hash, result: &TransactionResult) -> Self {
+        let (status, fee_charged, gas_used) = match result {
+            TransactionResult::Success { fee_charged, gas_used } => {
+                (ReceiptStatus::Success, *fee_charged, *gas_used)
+            }
+            TransactionResult::Failure { fee_charged, gas_used, .. } => {
+                (ReceiptStatus::Failure, *fee_charged, *gas_used)
+            }
+        };
+
+        Self {
+            index,
+            tx_hash,
+            status,
+            fee_charged,
+            gas_used,
+        }
+    }
+
+    /// Canonically encode this receipt.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+
+        // Domain separation
+        buf.extend_from_slice(b"Axiom::Receipt::v1");
+
+        buf.extend_from_slice(&self.index.to_be_bytes());
+        buf.extend_from_slice(self.tx_hash.as_bytes());
+        buf.push(self.status as u8);
+        buf.extend_from_slice(&self.fee_charged.to_be_bytes());
+        buf.extend_from_slice(&self.gas_used.to_be_bytes());
+
+        buf
+    }
+
+    /// Leaf hash of this receipt in the receipts tree.
+    pub fn hash(&self) -> Hash {
+        Hash::new(blake3::hash(&self.encode()).into())
+    }
+}
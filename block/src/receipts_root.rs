@@ -1,41 +1,30 @@
 use axiom_types::Hash;
-use blake3;
 
-use crate::TransactionResult;
+use crate::merkle::{MerkleProof, merkle_proof, merkle_root, verify_merkle_proof};
+use crate::Receipt;
+
+/// Domain of the receipts tree root.
+const RECEIPTS_ROOT_DOMAIN: &[u8] = b"Axiom::ReceiptsRoot::v2";
 
 /// Compute the canonical receipts root for a block.
 ///
-/// Receipts are ordered exactly as transactions appear in the block.
-pub fn compute_receipts_root(
-    tx_hashes: &[Hash],
-    results: &[TransactionResult],
-) -> Hash {
-    assert_eq!(
-        tx_hashes.len(),
-        results.len(),
-        "tx hashes and results length mismatch"
-    );
-
-    let mut bytes = Vec::new();
-
-    // Domain separation
-    bytes.extend_from_slice(b"Axiom::ReceiptsRoot::v1");
-
-    for (tx_hash, result) in tx_hashes.iter().zip(results.iter()) {
-        // Transaction hash
-        bytes.extend_from_slice(tx_hash.as_bytes());
+/// Receipts are ordered exactly as transactions appear in the block and
+/// committed as the leaves of a binary Merkle tree.
+pub fn compute_receipts_root(receipts: &[Receipt]) -> Hash {
+    let leaves: Vec<Hash> = receipts.iter().map(Receipt::hash).collect();
+    merkle_root(RECEIPTS_ROOT_DOMAIN, &leaves)
+}
 
-        match result {
-            TransactionResult::Success { fee_charged } => {
-                bytes.push(1); // success flag
-                bytes.extend_from_slice(&fee_charged.to_be_bytes());
-            }
-            TransactionResult::Failure { .. } => {
-                bytes.push(0); // failure flag
-                bytes.extend_from_slice(&0u64.to_be_bytes()); // no fee
-            }
-        }
-    }
+/// Build an inclusion proof for the receipt at `index`.
+pub fn receipt_proof(receipts: &[Receipt], index: usize) -> Option<MerkleProof> {
+    let leaves: Vec<Hash> = receipts.iter().map(Receipt::hash).collect();
+    merkle_proof(&leaves, index)
+}
 
-    Hash::new(blake3::hash(&bytes).into())
+/// Verify that `receipt` is committed by `receipts_root`.
+///
+/// The proof position MUST match the receipt's own index.
+pub fn verify_receipt_proof(receipts_root: &Hash, receipt: &Receipt, proof: &MerkleProof) -> bool {
+    receipt.index == proof.index
+        && verify_merkle_proof(RECEIPTS_ROOT_DOMAIN, receipts_root, receipt.hash(), proof)
 }
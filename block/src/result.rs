@@ -1,5 +1,7 @@
 use axiom_protocol::ProtocolError;
 
+use crate::Receipt;
+
 /// Result of executing a single transaction within a block.
 #[derive(Debug)]
 pub enum TransactionResult {
@@ -7,12 +9,24 @@ pub enum TransactionResult {
     Success {
         /// Fee charged for the transaction.
         fee_charged: u64,
+
+        /// Gas consumed by execution.
+        gas_used: u64,
     },
 
-    /// Transaction failed and caused no state changes.
+    /// Transaction failed.
+    ///
+    /// Its writes were discarded; if it was charged, only its fee and
+    /// nonce update were applied.
     Failure {
         /// Reason for failure.
         error: ProtocolError,
+
+        /// Fee charged for the transaction (0 if it was rejected).
+        fee_charged: u64,
+
+        /// Gas consumed by execution.
+        gas_used: u64,
     },
 }
 
@@ -21,4 +35,19 @@ pub enum TransactionResult {
 pub struct BlockExecutionResult {
     /// Per-transaction execution results in block order.
     pub tx_results: Vec<TransactionResult>,
+
+    /// Per-transaction receipts in block order, committed by the receipts root.
+    pub receipts: Vec<Receipt>,
+
+    /// Sum of the gas consumed by all transactions.
+    pub gas_used: u64,
+
+    /// Sum of the fees charged by all transactions.
+    pub total_fees: u64,
+
+    /// Portion of the base fees in `total_fees` removed from supply.
+    pub burned: u64,
+
+    /// Rest of `total_fees`, tips included, credited to the block proposer.
+    pub proposer_reward: u64,
 }
@@ -0,0 +1,44 @@
+use axiom_ext_tx::ExternalTransaction;
+use axiom_types::Hash;
+use blake3;
+
+use crate::encode::encode_transaction;
+use crate::merkle::{MerkleProof, merkle_proof, merkle_root, verify_merkle_proof};
+
+/// Domain of the transactions tree root.
+const TRANSACTIONS_ROOT_DOMAIN: &[u8] = b"Axiom::TransactionsRoot::v1";
+
+/// Compute the hash of a transaction as included in a block.
+///
+/// Unlike `signing_hash`, this covers the full canonical encoding,
+/// including signatures and approvals, so it identifies the exact
+/// transaction body.
+pub fn transaction_hash(tx: &ExternalTransaction) -> Hash {
+    let mut bytes = Vec::new();
+
+    // Domain separation
+    bytes.extend_from_slice(b"Axiom::TransactionHash::v1");
+    bytes.extend_from_slice(&encode_transaction(tx));
+
+    Hash::new(blake3::hash(&bytes).into())
+}
+
+/// Compute the canonical transactions root for a block body.
+///
+/// Transactions are committed in block order as the leaves of a binary
+/// Merkle tree.
+pub fn compute_transactions_root(transactions: &[ExternalTransaction]) -> Hash {
+    let leaves: Vec<Hash> = transactions.iter().map(transaction_hash).collect();
+    merkle_root(TRANSACTIONS_ROOT_DOMAIN, &leaves)
+}
+
+/// Build an inclusion proof for the transaction at `index`.
+pub fn transaction_proof(transactions: &[ExternalTransaction], index: usize) -> Option<MerkleProof> {
+    let leaves: Vec<Hash> = transactions.iter().map(transaction_hash).collect();
+    merkle_proof(&leaves, index)
+}
+
+/// Verify that the transaction hashing to `tx_hash` is committed by `transactions_root`.
+pub fn verify_transaction_proof(transactions_root: &Hash, tx_hash: Hash, proof: &MerkleProof) -> bool {
+    verify_merkle_proof(TRANSACTIONS_ROOT_DOMAIN, transactions_root, tx_hash, proof)
+}
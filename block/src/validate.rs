@@ -0,0 +1,113 @@
+use axiom_execution_engine::ExecutionEngine;
+use axiom_protocol::ChainConfig;
+use axiom_state::{StateStore, compute_state_root};
+use axiom_types::Address;
+
+use crate::execute::execute_transactions;
+use crate::{Block, BlockError, BlockExecutionResult, BlockHeader, compute_receipts_root};
+
+/// Check that `block` links correctly to `parent`.
+///
+/// `parent` is `None` when validating a genesis block. The slot must
+/// strictly advance, the epoch must not go backwards, the proposer must
+/// not be the zero address, and the base fee must follow from the parent
+/// under `config.fees`. Genesis has no parent and no proposer. The block may carry
+/// at most `config.fees.max_transactions` and claim at most
+/// `config.max_block_gas`; `validate_block` then checks the claimed gas
+/// against execution.
+pub fn validate_header(
+    block: &Block,
+    parent: Option<&BlockHeader>,
+    config: &ChainConfig,
+) -> Result<(), BlockError> {
+    let expected = parent.map(BlockHeader::hash);
+    if block.parent_hash != expected {
+        return Err(BlockError::ParentHashMismatch {
+            expected,
+            found: block.parent_hash,
+        });
+    }
+
+    if let Some(parent) = parent {
+        if block.slot <= parent.slot {
+            return Err(BlockError::InvalidSlot { parent: parent.slot, found: block.slot });
+        }
+
+        if block.epoch < parent.epoch {
+            return Err(BlockError::InvalidEpoch { parent: parent.epoch, found: block.epoch });
+        }
+
+        // Fees are credited to the proposer, and the zero address must never hold a balance
+        if block.proposer == Address::zero() {
+            return Err(BlockError::ZeroProposer);
+        }
+
+        let expected = parent.next_base_fee(&config.fees);
+        if block.base_fee != expected {
+            return Err(BlockError::BaseFeeMismatch { expected, found: block.base_fee });
+        }
+    }
+
+    let found = block.transactions.len() as u64;
+    if found > config.fees.max_transactions {
+        return Err(BlockError::TooManyTransactions { max: config.fees.max_transactions, found });
+    }
+
+    if block.gas_used > config.max_block_gas {
+        return Err(BlockError::TooMuchGas { max: config.max_block_gas, found: block.gas_used });
+    }
+
+    Ok(())
+}
+
+/// Validate a received block against its parent state.
+///
+/// Unlike `execute_block`, the block is never modified: its claimed
+/// state and receipts roots and gas used are checked against the executed
+/// ones, and it is rejected unless it conserves supply.
+///
+/// `state` must be the post-state of `parent`. On success the block's
+/// writes stay staged in `state`, ready for `StateStore::commit`; on
+/// any error `state` is left as it was.
+pub fn validate_block<E: ExecutionEngine>(
+    state: &mut StateStore,
+    block: &Block,
+    parent: Option<&BlockHeader>,
+    engine: &E,
+    config: &ChainConfig,
+) -> Result<BlockExecutionResult, BlockError> {
+    validate_header(block, parent, config)?;
+
+    let checkpoint = state.checkpoint();
+    let result = match execute_transactions(state, block, engine, config, checkpoint) {
+        Ok(result) => result,
+        Err(err) => {
+            state.rollback(checkpoint).expect("block checkpoint is open");
+            return Err(err);
+        }
+    };
+
+    let computed_state = compute_state_root(state);
+    let computed_receipts = compute_receipts_root(&result.receipts);
+
+    let mismatch = if computed_state != block.state_root {
+        Some(BlockError::StateRootMismatch { claimed: block.state_root, computed: computed_state })
+    } else if computed_receipts != block.receipts_root {
+        Some(BlockError::ReceiptsRootMismatch { claimed: block.receipts_root, computed: computed_receipts })
+    } else if result.gas_used != block.gas_used {
+        Some(BlockError::GasUsedMismatch { claimed: block.gas_used, computed: result.gas_used })
+    } else {
+        None
+    };
+
+    match mismatch {
+        None => {
+            state.release(checkpoint).expect("block checkpoint is open");
+            Ok(result)
+        }
+        Some(err) => {
+            state.rollback(checkpoint).expect("block checkpoint is open");
+            Err(err)
+        }
+    }
+}
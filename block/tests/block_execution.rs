@@ -1,33 +1,39 @@
+mod common;
+
 use std::collections::{HashMap, BTreeMap};
 
-use axiom_block::{Block, execute_block, TransactionResult};
-use axiom_execution_engine::{ReferenceExecutionEngine};
-// use axiom_protocol::process_external_transaction;
-use axiom_state::{StateStore, StateObject};
+use axiom_block::{Block, BlockError, execute_block, TransactionResult};
+use axiom_execution::ExecutionPlan;
+use axiom_execution_engine::{
+    ExecutionContext,
+    ExecutionEngine,
+    ExecutionError,
+    ExecutionOutcome,
+    GasSchedule,
+    ReferenceExecutionEngine,
+    StateView,
+};
+use axiom_state::{StateStore, StateObject, ObjectWrite, SupplyError};
 use axiom_state::nonce::nonce_object_id;
 use axiom_state::balance::{balance_object_id, encode_balance, decode_balance};
-use axiom_ext_tx::{ExternalTransaction, Signature};
+use axiom_ext_tx::{ExternalTransaction, ValidityWindow};
+use ed25519_dalek::SigningKey;
 use axiom_tx::{TransactionCell, CallData};
-use axiom_types::{Address, Slot, Epoch, ObjectId};
-use axiom_state::compute_state_root;
+use axiom_types::{Address, Slot, Epoch};
+use axiom_state::{Supply, check_supply, compute_state_root, read_supply};
 use axiom_types::Hash;
-use axiom_block::compute_receipts_root;
+use axiom_block::{compute_receipts_root, transaction_hash, ReceiptStatus};
+use axiom_protocol::ChainConfig;
 
+use common::{CHAIN_ID, make_cell, signing_key, address_of, unsigned, sign};
 
-// -------------------------------------------------------------
-// Helper: create a minimal valid TransactionCell
-// -------------------------------------------------------------
-fn make_cell(target: ObjectId) -> TransactionCell {
-    TransactionCell::new(
-        Slot::new(1),
-        HashMap::new(),
-        BTreeMap::new(),
-        CallData {
-            target,
-            selector: vec![],
-            payload: vec![],
-        },
-    ).unwrap()
+/// Gas limit of every test transaction, and a balance that affords many.
+const GAS_LIMIT: u64 = 1_000;
+const FUNDS: u64 = 100 * GAS_LIMIT;
+
+/// Gas used by a transaction running one `make_cell`.
+fn call_gas() -> u64 {
+    GasSchedule::default().call
 }
 
 // -------------------------------------------------------------
@@ -36,56 +42,80 @@ fn make_cell(target: ObjectId) -> TransactionCell {
 #[test]
 fn block_executes_all_valid_transactions() {
     let mut state = StateStore::new();
-    let signer = Address::new([1u8; 32]);
+    let key = signing_key(1);
+    let signer = address_of(&key);
+    let proposer = address_of(&signing_key(101));
 
     let balance_id = balance_object_id(signer);
-    state.insert(StateObject::new(balance_id, signer, encode_balance(10))).unwrap();
+    state.insert(StateObject::new(balance_id, signer, encode_balance(FUNDS))).unwrap();
 
     let txs = vec![
-        ExternalTransaction {
+        sign(&key, ExternalTransaction {
+            chain_id: CHAIN_ID,
             signer,
             nonce: 0,
+            validity: ValidityWindow::unbounded(),
+            max_fee: 1,
+            priority_fee: 0,
+            gas_limit: GAS_LIMIT,
             cells: vec![make_cell(balance_id)],
-            signature: Signature { bytes: vec![] },
-        },
-        ExternalTransaction {
+            signature: unsigned(),
+            approvals: vec![],
+        }),
+        sign(&key, ExternalTransaction {
+            chain_id: CHAIN_ID,
             signer,
             nonce: 0,
+            validity: ValidityWindow::unbounded(),
+            max_fee: 1,
+            priority_fee: 0,
+            gas_limit: GAS_LIMIT,
             cells: vec![make_cell(balance_id)],
-            signature: Signature { bytes: vec![] },
-        },
+            signature: unsigned(),
+            approvals: vec![],
+        }),
     ];
 
     let mut block = Block {
         parent_hash: None,
         slot: Slot::new(1),
         epoch: Epoch::new(0),
+        base_fee: 1,
+        gas_used: 0,
+        proposer,
         state_root: Hash::zero(),
         receipts_root: Hash::zero(),
         transactions: txs,
     };
 
-    let engine = ReferenceExecutionEngine::default();
-    let result = execute_block(&mut state, &mut block, &engine);
+    let engine = ReferenceExecutionEngine;
+
+    let config = ChainConfig::standard(CHAIN_ID);
+    let result = execute_block(&mut state, &mut block, &engine, &config).unwrap();
 
     let tx_hashes: Vec<_> = block
         .transactions
         .iter()
-        .map(|tx| tx.signing_hash())
+        .map(transaction_hash)
         .collect();
 
     let expected_receipts_root =
-        compute_receipts_root(&tx_hashes, &result.tx_results);
+        compute_receipts_root(&result.receipts);
 
     assert_eq!(block.receipts_root, expected_receipts_root);
+    assert!(result.receipts.iter().map(|r| r.tx_hash).eq(tx_hashes));
 
     assert_eq!(result.tx_results.len(), 2);
     assert!(matches!(result.tx_results[0], TransactionResult::Success { .. }));
     assert!(matches!(result.tx_results[1], TransactionResult::Success { .. }));
 
-    assert_eq!(decode_balance(state.get(&balance_id).unwrap()), 8);
+    assert_eq!(decode_balance(state.get(&balance_id).unwrap()), FUNDS - 2 * call_gas());
     assert_eq!(state.get(&nonce_object_id(signer)).unwrap().version(), 1);
 
+    // Half of the base fees is burned, the rest credited to the proposer
+    assert_eq!(result.proposer_reward, call_gas());
+    assert_eq!(decode_balance(state.get(&balance_object_id(proposer)).unwrap()), call_gas());
+
     // ✅ State root correctness
     let expected_root = compute_state_root(&state);
     assert_eq!(block.state_root, expected_root);
@@ -97,57 +127,83 @@ fn block_executes_all_valid_transactions() {
 #[test]
 fn block_failure_does_not_affect_later_txs() {
     let mut state = StateStore::new();
-    let signer = Address::new([2u8; 32]);
+    let key = signing_key(2);
+    let signer = address_of(&key);
+    let proposer = address_of(&signing_key(102));
 
     let balance_id = balance_object_id(signer);
-    state.insert(StateObject::new(balance_id, signer, encode_balance(10))).unwrap();
+    state.insert(StateObject::new(balance_id, signer, encode_balance(FUNDS))).unwrap();
 
     let txs = vec![
         // tx #1 valid
-        ExternalTransaction {
+        sign(&key, ExternalTransaction {
+            chain_id: CHAIN_ID,
             signer,
             nonce: 0,
+            validity: ValidityWindow::unbounded(),
+            max_fee: 1,
+            priority_fee: 0,
+            gas_limit: GAS_LIMIT,
             cells: vec![make_cell(balance_id)],
-            signature: Signature { bytes: vec![] },
-        },
+            signature: unsigned(),
+            approvals: vec![],
+        }),
         // tx #2 invalid (bad nonce)
-        ExternalTransaction {
+        sign(&key, ExternalTransaction {
+            chain_id: CHAIN_ID,
             signer,
             nonce: 5, // ❌ invalid
+            validity: ValidityWindow::unbounded(),
+            max_fee: 1,
+            priority_fee: 0,
+            gas_limit: GAS_LIMIT,
             cells: vec![make_cell(balance_id)],
-            signature: Signature { bytes: vec![] },
-        },
+            signature: unsigned(),
+            approvals: vec![],
+        }),
         // tx #3 valid again
-        ExternalTransaction {
+        sign(&key, ExternalTransaction {
+            chain_id: CHAIN_ID,
             signer,
             nonce: 0,
+            validity: ValidityWindow::unbounded(),
+            max_fee: 1,
+            priority_fee: 0,
+            gas_limit: GAS_LIMIT,
             cells: vec![make_cell(balance_id)],
-            signature: Signature { bytes: vec![] },
-        },
+            signature: unsigned(),
+            approvals: vec![],
+        }),
     ];
 
     let mut block = Block {
         parent_hash: None,
         slot: Slot::new(1),
         epoch: Epoch::new(0),
+        base_fee: 1,
+        gas_used: 0,
+        proposer,
         state_root: Hash::zero(),
         receipts_root: Hash::zero(),
         transactions: txs,
     };
 
-    let engine = ReferenceExecutionEngine::default();
-    let result = execute_block(&mut state, &mut block, &engine);
+    let engine = ReferenceExecutionEngine;
+
+    let config = ChainConfig::standard(CHAIN_ID);
+    let result = execute_block(&mut state, &mut block, &engine, &config).unwrap();
 
     let tx_hashes: Vec<_> = block
         .transactions
         .iter()
-        .map(|tx| tx.signing_hash())
+        .map(transaction_hash)
         .collect();
 
     let expected_receipts_root =
-        compute_receipts_root(&tx_hashes, &result.tx_results);
+        compute_receipts_root(&result.receipts);
 
     assert_eq!(block.receipts_root, expected_receipts_root);
+    assert!(result.receipts.iter().map(|r| r.tx_hash).eq(tx_hashes));
 
 
     assert!(matches!(result.tx_results[0], TransactionResult::Success { .. }));
@@ -155,7 +211,8 @@ fn block_failure_does_not_affect_later_txs() {
     assert!(matches!(result.tx_results[2], TransactionResult::Success { .. }));
 
     // Only two successful txs should charge fees
-    assert_eq!(decode_balance(state.get(&balance_id).unwrap()), 8);
+    assert_eq!(decode_balance(state.get(&balance_id).unwrap()), FUNDS - 2 * call_gas());
+    assert_eq!(decode_balance(state.get(&balance_object_id(proposer)).unwrap()), call_gas());
     
     // ✅ State root correctness
     let expected_root = compute_state_root(&state);
@@ -168,55 +225,76 @@ fn block_failure_does_not_affect_later_txs() {
 #[test]
 fn block_all_invalid_txs_no_state_change() {
     let mut state = StateStore::new();
-    let signer = Address::new([3u8; 32]);
+    let key = signing_key(3);
+    let signer = address_of(&key);
+    let proposer = address_of(&signing_key(103));
 
     let balance_id = balance_object_id(signer);
-    state.insert(StateObject::new(balance_id, signer, encode_balance(5))).unwrap();
+    state.insert(StateObject::new(balance_id, signer, encode_balance(FUNDS))).unwrap();
 
     let txs = vec![
-        ExternalTransaction {
+        sign(&key, ExternalTransaction {
+            chain_id: CHAIN_ID,
             signer,
             nonce: 1,
+            validity: ValidityWindow::unbounded(),
+            max_fee: 1,
+            priority_fee: 0,
+            gas_limit: GAS_LIMIT,
             cells: vec![make_cell(balance_id)],
-            signature: Signature { bytes: vec![] },
-        },
-        ExternalTransaction {
+            signature: unsigned(),
+            approvals: vec![],
+        }),
+        sign(&key, ExternalTransaction {
+            chain_id: CHAIN_ID,
             signer,
             nonce: 2,
+            validity: ValidityWindow::unbounded(),
+            max_fee: 1,
+            priority_fee: 0,
+            gas_limit: GAS_LIMIT,
             cells: vec![make_cell(balance_id)],
-            signature: Signature { bytes: vec![] },
-        },
+            signature: unsigned(),
+            approvals: vec![],
+        }),
     ];
 
     let mut block = Block {
         parent_hash: None,
         slot: Slot::new(1),
         epoch: Epoch::new(0),
+        base_fee: 1,
+        gas_used: 0,
+        proposer,
         state_root: Hash::zero(),
         receipts_root: Hash::zero(),
         transactions: txs,
     };
 
-    let engine = ReferenceExecutionEngine::default();
-    let result = execute_block(&mut state, &mut block, &engine);
+    let engine = ReferenceExecutionEngine;
+
+    let config = ChainConfig::standard(CHAIN_ID);
+    let result = execute_block(&mut state, &mut block, &engine, &config).unwrap();
 
     let tx_hashes: Vec<_> = block
         .transactions
         .iter()
-        .map(|tx| tx.signing_hash())
+        .map(transaction_hash)
         .collect();
 
     let expected_receipts_root =
-        compute_receipts_root(&tx_hashes, &result.tx_results);
+        compute_receipts_root(&result.receipts);
 
     assert_eq!(block.receipts_root, expected_receipts_root);
+    assert!(result.receipts.iter().map(|r| r.tx_hash).eq(tx_hashes));
 
 
     assert!(result.tx_results.iter().all(|r| matches!(r, TransactionResult::Failure { .. })));
 
     // State unchanged
-    assert_eq!(decode_balance(state.get(&balance_id).unwrap()), 5);
+    assert_eq!(decode_balance(state.get(&balance_id).unwrap()), FUNDS);
     assert!(state.get(&nonce_object_id(signer)).is_none());
+    assert!(state.get(&balance_object_id(proposer)).is_none());
 
     // ✅ State root correctness
     let expected_root = compute_state_root(&state);
@@ -228,49 +306,565 @@ fn block_all_invalid_txs_no_state_change() {
 // -------------------------------------------------------------
 #[test]
 fn block_execution_is_deterministic() {
-    let signer = Address::new([4u8; 32]);
+    let key = signing_key(4);
+    let signer = address_of(&key);
+    let proposer = address_of(&signing_key(104));
 
     let mut state1 = StateStore::new();
     let mut state2 = StateStore::new();
 
     let balance_id = balance_object_id(signer);
-    let obj = StateObject::new(balance_id, signer, encode_balance(10));
+    let obj = StateObject::new(balance_id, signer, encode_balance(FUNDS));
 
     state1.insert(obj.clone()).unwrap();
     state2.insert(obj).unwrap();
 
     let txs = vec![
-        ExternalTransaction {
+        sign(&key, ExternalTransaction {
+            chain_id: CHAIN_ID,
             signer,
             nonce: 0,
+            validity: ValidityWindow::unbounded(),
+            max_fee: 1,
+            priority_fee: 0,
+            gas_limit: GAS_LIMIT,
             cells: vec![make_cell(balance_id)],
-            signature: Signature { bytes: vec![] },
-        },
-        ExternalTransaction {
+            signature: unsigned(),
+            approvals: vec![],
+        }),
+        sign(&key, ExternalTransaction {
+            chain_id: CHAIN_ID,
             signer,
             nonce: 0,
+            validity: ValidityWindow::unbounded(),
+            max_fee: 1,
+            priority_fee: 0,
+            gas_limit: GAS_LIMIT,
             cells: vec![make_cell(balance_id)],
-            signature: Signature { bytes: vec![] },
-        },
+            signature: unsigned(),
+            approvals: vec![],
+        }),
     ];
 
     let mut block = Block {
         parent_hash: None,
         slot: Slot::new(1),
         epoch: Epoch::new(0),
+        base_fee: 1,
+        gas_used: 0,
+        proposer,
         state_root: Hash::zero(),
         receipts_root: Hash::zero(),
         transactions: txs,
     };
 
-    let engine = ReferenceExecutionEngine::default();
+    let engine = ReferenceExecutionEngine;
 
-    let r1 = execute_block(&mut state1, &mut block, &engine);
-    let r2 = execute_block(&mut state2, &mut block, &engine);
+    let config = ChainConfig::standard(CHAIN_ID);
+
+    let r1 = execute_block(&mut state1, &mut block, &engine, &config).unwrap();
+    let r2 = execute_block(&mut state2, &mut block, &engine, &config).unwrap();
 
     assert_eq!(r1.tx_results.len(), r2.tx_results.len());
     assert_eq!(
         decode_balance(state1.get(&balance_id).unwrap()),
         decode_balance(state2.get(&balance_id).unwrap())
     );
+    assert_eq!(decode_balance(state1.get(&balance_object_id(proposer)).unwrap()), call_gas());
+    assert_eq!(decode_balance(state2.get(&balance_object_id(proposer)).unwrap()), call_gas());
+}
+
+// -------------------------------------------------------------
+// Test: Speculatively executed blocks can be discarded
+// -------------------------------------------------------------
+#[test]
+fn block_execution_can_be_rolled_back() {
+    let mut state = StateStore::new();
+    let key = signing_key(4);
+    let signer = address_of(&key);
+    let proposer = address_of(&signing_key(105));
+
+    let balance_id = balance_object_id(signer);
+    state.insert(StateObject::new(balance_id, signer, encode_balance(FUNDS))).unwrap();
+    let root_before = compute_state_root(&state);
+
+    let mut block = Block {
+        parent_hash: None,
+        slot: Slot::new(1),
+        epoch: Epoch::new(0),
+        base_fee: 1,
+        gas_used: 0,
+        proposer,
+        state_root: Hash::zero(),
+        receipts_root: Hash::zero(),
+        transactions: vec![sign(&key, ExternalTransaction {
+            chain_id: CHAIN_ID,
+            signer,
+            nonce: 0,
+            validity: ValidityWindow::unbounded(),
+            max_fee: 1,
+            priority_fee: 0,
+            gas_limit: GAS_LIMIT,
+            cells: vec![make_cell(balance_id)],
+            signature: unsigned(),
+            approvals: vec![],
+        })],
+    };
+
+    let engine = ReferenceExecutionEngine;
+    let config = ChainConfig::standard(CHAIN_ID);
+
+    let checkpoint = state.checkpoint();
+    let result = execute_block(&mut state, &mut block, &engine, &config).unwrap();
+    assert!(matches!(result.tx_results[0], TransactionResult::Success { .. }));
+    assert_ne!(compute_state_root(&state), root_before);
+    assert_eq!(decode_balance(state.get(&balance_object_id(proposer)).unwrap()), call_gas() - call_gas() / 2);
+
+    state.rollback(checkpoint).unwrap();
+
+    assert_eq!(compute_state_root(&state), root_before);
+    assert_eq!(decode_balance(state.get(&balance_id).unwrap()), FUNDS);
+    assert!(state.get(&nonce_object_id(signer)).is_none());
+    assert!(state.get(&balance_object_id(proposer)).is_none());
+}
+
+// -------------------------------------------------------------
+// Test: Receipts record the fee charged and the gas used
+// -------------------------------------------------------------
+#[test]
+fn block_receipts_record_charged_fee() {
+    let mut state = StateStore::new();
+    let key = signing_key(6);
+    let signer = address_of(&key);
+    let proposer = address_of(&signing_key(106));
+
+    let balance_id = balance_object_id(signer);
+    state.insert(StateObject::new(balance_id, signer, encode_balance(FUNDS))).unwrap();
+
+    let mut block = Block {
+        parent_hash: None,
+        slot: Slot::new(1),
+        epoch: Epoch::new(0),
+        base_fee: 4,
+        gas_used: 0,
+        proposer,
+        state_root: Hash::zero(),
+        receipts_root: Hash::zero(),
+        transactions: vec![sign(&key, ExternalTransaction {
+            chain_id: CHAIN_ID,
+            signer,
+            nonce: 0,
+            validity: ValidityWindow::unbounded(),
+            max_fee: 10,
+            priority_fee: 2,
+            gas_limit: GAS_LIMIT,
+            cells: vec![make_cell(balance_id)],
+            signature: unsigned(),
+            approvals: vec![],
+        })],
+    };
+
+    let config = ChainConfig::standard(CHAIN_ID);
+    let result = execute_block(&mut state, &mut block, &ReferenceExecutionEngine, &config).unwrap();
+
+    let TransactionResult::Success { fee_charged, gas_used } = result.tx_results[0] else {
+        panic!("transaction failed");
+    };
+    // Gas used at base fee 4 plus tip 2
+    assert_eq!(gas_used, call_gas());
+    assert_eq!(fee_charged, 6 * call_gas());
+    assert_eq!(result.receipts[0].fee_charged, fee_charged);
+    assert_eq!(result.receipts[0].gas_used, gas_used);
+    assert_eq!(result.gas_used, gas_used);
+    assert_eq!(block.gas_used, gas_used);
+    assert_eq!(decode_balance(state.get(&balance_id).unwrap()), FUNDS - fee_charged);
+
+    // Half of the base fee part is burned; the proposer keeps the rest and the tip
+    assert_eq!(decode_balance(state.get(&balance_object_id(proposer)).unwrap()), 4 * call_gas());
+}
+
+// -------------------------------------------------------------
+// Test: Failed transactions pay their fee and record it in receipts
+// -------------------------------------------------------------
+#[test]
+fn block_failed_transaction_is_charged() {
+    let mut state = StateStore::new();
+    let key = signing_key(7);
+    let signer = address_of(&key);
+    let proposer = address_of(&signing_key(107));
+
+    let balance_id = balance_object_id(signer);
+    state.insert(StateObject::new(balance_id, signer, encode_balance(FUNDS))).unwrap();
+
+    // Unknown selector fails execution after the fee is charged
+    let failing = TransactionCell::new(
+        HashMap::new(),
+        BTreeMap::new(),
+        CallData { target: balance_id, selector: b"axiom::unknown".to_vec(), payload: vec![] },
+    ).unwrap();
+
+    let mut block = Block {
+        parent_hash: None,
+        slot: Slot::new(1),
+        epoch: Epoch::new(0),
+        base_fee: 3,
+        gas_used: 0,
+        proposer,
+        state_root: Hash::zero(),
+        receipts_root: Hash::zero(),
+        transactions: vec![sign(&key, ExternalTransaction {
+            chain_id: CHAIN_ID,
+            signer,
+            nonce: 0,
+            validity: ValidityWindow::unbounded(),
+            max_fee: 3,
+            priority_fee: 0,
+            gas_limit: GAS_LIMIT,
+            cells: vec![failing],
+            signature: unsigned(),
+            approvals: vec![],
+        })],
+    };
+
+    let config = ChainConfig::standard(CHAIN_ID);
+    let result = execute_block(&mut state, &mut block, &ReferenceExecutionEngine, &config).unwrap();
+
+    // A failure consumes its whole gas limit
+    assert!(matches!(
+        result.tx_results[0],
+        TransactionResult::Failure { fee_charged, gas_used: GAS_LIMIT, .. } if fee_charged == 3 * GAS_LIMIT
+    ));
+    assert_eq!(result.receipts[0].status, ReceiptStatus::Failure);
+    assert_eq!(result.receipts[0].fee_charged, 3 * GAS_LIMIT);
+    assert_eq!(block.receipts_root, compute_receipts_root(&result.receipts));
+
+    assert_eq!(decode_balance(state.get(&balance_id).unwrap()), FUNDS - 3 * GAS_LIMIT);
+    assert_eq!(state.get(&nonce_object_id(signer)).unwrap().version(), 0);
+    assert_eq!(decode_balance(state.get(&balance_object_id(proposer)).unwrap()), 3 * GAS_LIMIT - 3 * GAS_LIMIT / 2);
+    assert_eq!(block.state_root, compute_state_root(&state));
+}
+
+// -------------------------------------------------------------
+// Test: Base fees are split between burning and the proposer, tips are not
+// -------------------------------------------------------------
+#[test]
+fn block_routes_fees_to_proposer_and_burn() {
+    let mut state = StateStore::new();
+    let key = signing_key(8);
+    let signer = address_of(&key);
+    let proposer = Address::new([5u8; 32]);
+
+    let balance_id = balance_object_id(signer);
+    state.insert(StateObject::new(balance_id, signer, encode_balance(FUNDS))).unwrap();
+
+    let tx = |nonce| sign(&key, ExternalTransaction {
+        chain_id: CHAIN_ID,
+        signer,
+        nonce,
+        validity: ValidityWindow::unbounded(),
+        max_fee: 10,
+        priority_fee: 2,
+        gas_limit: GAS_LIMIT,
+        cells: vec![make_cell(balance_id)],
+        signature: unsigned(),
+        approvals: vec![],
+    });
+
+    let mut block = Block {
+        parent_hash: None,
+        slot: Slot::new(1),
+        epoch: Epoch::new(0),
+        base_fee: 3,
+        gas_used: 0,
+        proposer,
+        state_root: Hash::zero(),
+        receipts_root: Hash::zero(),
+        transactions: vec![tx(0), tx(0)],
+    };
+
+    let mut config = ChainConfig::standard(CHAIN_ID);
+    config.fees.burn_basis_points = 2_500;
+
+    let result = execute_block(&mut state, &mut block, &ReferenceExecutionEngine, &config).unwrap();
+
+    // Two transactions' gas at base fee 3 plus tip 2; only the base fee part is burned
+    let total = 2 * call_gas() * 5;
+    let burned = 2 * call_gas() * 3 / 4;
+    assert_eq!(result.total_fees, total);
+    assert_eq!(result.burned, burned);
+    assert_eq!(result.proposer_reward, total - burned);
+
+    assert_eq!(decode_balance(state.get(&balance_id).unwrap()), FUNDS - total);
+    assert_eq!(decode_balance(state.get(&balance_object_id(proposer)).unwrap()), total - burned);
+    assert_eq!(block.state_root, compute_state_root(&state));
+}
+
+// -------------------------------------------------------------
+// Test: A reward overflowing the proposer's balance fails the block
+// -------------------------------------------------------------
+#[test]
+fn block_rejects_proposer_balance_overflow() {
+    let mut state = StateStore::new();
+    let key = signing_key(11);
+    let signer = address_of(&key);
+    let proposer = Address::new([7u8; 32]);
+
+    let balance_id = balance_object_id(signer);
+    state.insert(StateObject::new(balance_id, signer, encode_balance(FUNDS))).unwrap();
+    state.insert(StateObject::new(balance_object_id(proposer), proposer, encode_balance(u64::MAX))).unwrap();
+    let root_before = compute_state_root(&state);
+
+    let mut block = Block {
+        parent_hash: None,
+        slot: Slot::new(1),
+        epoch: Epoch::new(0),
+        base_fee: 1,
+        gas_used: 0,
+        proposer,
+        state_root: Hash::zero(),
+        receipts_root: Hash::zero(),
+        transactions: vec![sign(&key, ExternalTransaction {
+            chain_id: CHAIN_ID,
+            signer,
+            nonce: 0,
+            validity: ValidityWindow::unbounded(),
+            max_fee: 1,
+            priority_fee: 0,
+            gas_limit: GAS_LIMIT,
+            cells: vec![make_cell(balance_id)],
+            signature: unsigned(),
+            approvals: vec![],
+        })],
+    };
+
+    let result = execute_block(&mut state, &mut block, &ReferenceExecutionEngine, &ChainConfig::standard(CHAIN_ID));
+
+    assert!(matches!(result, Err(BlockError::AmountOverflow { field: "proposer_reward" })));
+    assert_eq!(compute_state_root(&state), root_before);
+    assert_eq!(decode_balance(state.get(&balance_id).unwrap()), FUNDS);
+}
+
+// -------------------------------------------------------------
+// Test: Rewards are never credited to the zero address
+// -------------------------------------------------------------
+#[test]
+fn block_rejects_rewarding_zero_proposer() {
+    let mut state = StateStore::new();
+    let key = signing_key(13);
+    let signer = address_of(&key);
+
+    let balance_id = balance_object_id(signer);
+    state.insert(StateObject::new(balance_id, signer, encode_balance(FUNDS))).unwrap();
+    let root_before = compute_state_root(&state);
+
+    let mut block = Block {
+        parent_hash: None,
+        slot: Slot::new(1),
+        epoch: Epoch::new(0),
+        base_fee: 1,
+        gas_used: 0,
+        proposer: Address::zero(),
+        state_root: Hash::zero(),
+        receipts_root: Hash::zero(),
+        transactions: vec![sign(&key, ExternalTransaction {
+            chain_id: CHAIN_ID,
+            signer,
+            nonce: 0,
+            validity: ValidityWindow::unbounded(),
+            max_fee: 1,
+            priority_fee: 0,
+            gas_limit: GAS_LIMIT,
+            cells: vec![make_cell(balance_id)],
+            signature: unsigned(),
+            approvals: vec![],
+        })],
+    };
+
+    let result = execute_block(&mut state, &mut block, &ReferenceExecutionEngine, &ChainConfig::standard(CHAIN_ID));
+
+    assert!(matches!(result, Err(BlockError::ZeroProposer)));
+    assert_eq!(compute_state_root(&state), root_before);
+    assert!(state.get(&balance_object_id(Address::zero())).is_none());
+
+    // Without a reward, as in genesis, no proposer is needed
+    block.transactions.clear();
+    execute_block(&mut state, &mut block, &ReferenceExecutionEngine, &ChainConfig::standard(CHAIN_ID)).unwrap();
+}
+
+// -------------------------------------------------------------
+// Test: Transaction count and gas limits must fit in the block
+// -------------------------------------------------------------
+#[test]
+fn block_enforces_block_limits() {
+    let mut state = StateStore::new();
+    let key = signing_key(12);
+    let signer = address_of(&key);
+
+    let balance_id = balance_object_id(signer);
+    state.insert(StateObject::new(balance_id, signer, encode_balance(FUNDS))).unwrap();
+    let root_before = compute_state_root(&state);
+
+    let tx = || sign(&key, ExternalTransaction {
+        chain_id: CHAIN_ID,
+        signer,
+        nonce: 0,
+        validity: ValidityWindow::unbounded(),
+        max_fee: 1,
+        priority_fee: 0,
+        gas_limit: GAS_LIMIT,
+        cells: vec![make_cell(balance_id)],
+        signature: unsigned(),
+        approvals: vec![],
+    });
+
+    let mut block = Block {
+        parent_hash: None,
+        slot: Slot::new(1),
+        epoch: Epoch::new(0),
+        base_fee: 1,
+        gas_used: 0,
+        proposer: Address::new([5u8; 32]),
+        state_root: Hash::zero(),
+        receipts_root: Hash::zero(),
+        transactions: vec![tx(), tx()],
+    };
+
+    let mut config = ChainConfig::standard(CHAIN_ID);
+    config.fees.max_transactions = 1;
+    assert!(matches!(
+        execute_block(&mut state, &mut block, &ReferenceExecutionEngine, &config),
+        Err(BlockError::TooManyTransactions { max: 1, found: 2 })
+    ));
+
+    // The second limit does not fit in what the first one left, even
+    // though it would only use a fraction of it
+    let mut config = ChainConfig::standard(CHAIN_ID);
+    config.max_block_gas = GAS_LIMIT + call_gas() - 1;
+    let result = execute_block(&mut state, &mut block, &ReferenceExecutionEngine, &config);
+
+    assert!(matches!(
+        result,
+        Err(BlockError::TransactionGasExceedsBlock { index: 1, gas_limit: GAS_LIMIT, remaining })
+            if remaining == GAS_LIMIT - 1
+    ));
+    assert_eq!(compute_state_root(&state), root_before);
+    assert!(state.get(&nonce_object_id(signer)).is_none());
+}
+
+// -------------------------------------------------------------
+// Helper: a funded signer on a chain that tracks supply
+// -------------------------------------------------------------
+fn fee_paying_block(
+    state: &mut StateStore,
+    key: &SigningKey,
+    issued: u64,
+    funded: u64,
+) -> Block {
+    let signer = address_of(key);
+    let balance_id = balance_object_id(signer);
+
+    state.insert(StateObject::new(balance_id, signer, encode_balance(funded))).unwrap();
+    state.insert(Supply { issued, burned: 0 }.to_object()).unwrap();
+
+    let tx = sign(key, ExternalTransaction {
+        chain_id: CHAIN_ID,
+        signer,
+        nonce: 0,
+        validity: ValidityWindow::unbounded(),
+        max_fee: 10,
+        priority_fee: 0,
+        gas_limit: GAS_LIMIT,
+        cells: vec![make_cell(balance_id)],
+        signature: unsigned(),
+        approvals: vec![],
+    });
+
+    Block {
+        parent_hash: None,
+        slot: Slot::new(1),
+        epoch: Epoch::new(0),
+        base_fee: 4,
+        gas_used: 0,
+        proposer: Address::new([6u8; 32]),
+        state_root: Hash::zero(),
+        receipts_root: Hash::zero(),
+        transactions: vec![tx],
+    }
+}
+
+// -------------------------------------------------------------
+// Test: Burned fees are recorded in the supply
+// -------------------------------------------------------------
+#[test]
+fn block_records_burned_supply() {
+    let mut state = StateStore::new();
+    let mut block = fee_paying_block(&mut state, &signing_key(9), FUNDS, FUNDS);
+
+    let result = execute_block(&mut state, &mut block, &ReferenceExecutionEngine, &ChainConfig::standard(CHAIN_ID)).unwrap();
+
+    // Half of the gas used at base fee 4
+    let burned = 4 * call_gas() / 2;
+    assert_eq!(result.burned, burned);
+    assert_eq!(read_supply(&state).unwrap(), Some(Supply { issued: FUNDS, burned }));
+    assert_eq!(check_supply(&state).unwrap().burned, burned);
+    assert_eq!(block.state_root, compute_state_root(&state));
+}
+
+// -------------------------------------------------------------
+// Test: Executing on a state that does not conserve supply fails
+// -------------------------------------------------------------
+#[cfg(debug_assertions)]
+#[test]
+fn block_rejects_unconserved_supply() {
+    let mut state = StateStore::new();
+
+    // 10 more tokens in balances than were issued
+    let mut block = fee_paying_block(&mut state, &signing_key(10), FUNDS - 10, FUNDS);
+    let root_before = compute_state_root(&state);
+
+    let result = execute_block(&mut state, &mut block, &ReferenceExecutionEngine, &ChainConfig::standard(CHAIN_ID));
+
+    assert!(matches!(result, Err(BlockError::Supply(SupplyError::NotConserved { .. }))));
+    assert_eq!(compute_state_root(&state), root_before);
+}
+
+// -------------------------------------------------------------
+// Helper: engine that also mints a token to a fixed account
+// -------------------------------------------------------------
+struct MintingEngine;
+
+impl ExecutionEngine for MintingEngine {
+    fn execute(
+        &self,
+        plan: &ExecutionPlan,
+        state: &dyn StateView,
+        context: ExecutionContext,
+    ) -> Result<ExecutionOutcome, ExecutionError> {
+        let mut outcome = ReferenceExecutionEngine.execute(plan, state, context)?;
+
+        let account = Address::new([12u8; 32]);
+        let minted = StateObject::new(balance_object_id(account), account, encode_balance(1));
+        outcome.writes.insert(minted.id(), ObjectWrite::Put(minted));
+
+        Ok(outcome)
+    }
+}
+
+// -------------------------------------------------------------
+// Test: Balances changing by more than the supply fail the block
+// -------------------------------------------------------------
+#[test]
+fn block_rejects_minted_balance() {
+    let mut state = StateStore::new();
+    let mut block = fee_paying_block(&mut state, &signing_key(11), FUNDS, FUNDS);
+    let root_before = compute_state_root(&state);
+
+    let result = execute_block(&mut state, &mut block, &MintingEngine, &ChainConfig::standard(CHAIN_ID));
+
+    let burned = (4 * call_gas() / 2) as i128;
+    assert!(matches!(
+        result,
+        Err(BlockError::SupplyMismatch { expected, found }) if expected == -burned && found == 1 - burned
+    ));
+    assert_eq!(compute_state_root(&state), root_before);
 }
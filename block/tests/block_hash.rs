@@ -1,38 +1,31 @@
-use std::collections::{HashMap, BTreeMap};
+mod common;
 
 use axiom_block::Block;
-use axiom_ext_tx::{ExternalTransaction, Signature};
-use axiom_tx::{TransactionCell, CallData};
+use axiom_ext_tx::{ExternalTransaction, Signature, SchemeId, ValidityWindow};
 use axiom_types::{Address, Slot, Epoch, ObjectId};
 use axiom_types::Hash;
 
-// -------------------------------------------------------------
-// Helper: create a minimal valid TransactionCell
-// -------------------------------------------------------------
-fn make_cell(target: ObjectId) -> TransactionCell {
-    TransactionCell::new(
-        Slot::new(1),
-        HashMap::new(),
-        BTreeMap::new(),
-        CallData {
-            target,
-            selector: vec![],
-            payload: vec![],
-        },
-    ).unwrap()
-}
+use common::{CHAIN_ID, make_cell};
 
 // -------------------------------------------------------------
 // Helper: create a minimal ExternalTransaction
 // -------------------------------------------------------------
 fn make_tx(signer: Address, nonce: u64, target: ObjectId, sig_byte: u8) -> ExternalTransaction {
     ExternalTransaction {
+        chain_id: CHAIN_ID,
         signer,
         nonce,
+        validity: ValidityWindow::unbounded(),
+        max_fee: 1,
+        priority_fee: 0,
+        gas_limit: 100_000,
         cells: vec![make_cell(target)],
         signature: Signature {
+            scheme: SchemeId::Ed25519,
+            public_key: vec![0u8; 32],
             bytes: vec![sig_byte; 64],
         },
+        approvals: vec![],
     }
 }
 
@@ -52,6 +45,9 @@ fn block_hash_is_deterministic() {
     let block1 = Block {
         slot: Slot::new(10),
         epoch: Epoch::new(1),
+        base_fee: 1,
+        gas_used: 0,
+        proposer: Address::zero(),
         transactions: txs.clone(),
         parent_hash: None,
         state_root: Hash::zero(),
@@ -61,6 +57,9 @@ fn block_hash_is_deterministic() {
     let block2 = Block {
         slot: Slot::new(10),
         epoch: Epoch::new(1),
+        base_fee: 1,
+        gas_used: 0,
+        proposer: Address::zero(),
         transactions: txs,
         parent_hash: None,
         state_root: Hash::zero(),
@@ -87,6 +86,9 @@ fn block_hash_changes_with_tx_order() {
     let block_a = Block {
         slot: Slot::new(5),
         epoch: Epoch::new(0),
+        base_fee: 1,
+        gas_used: 0,
+        proposer: Address::zero(),
         transactions: vec![tx1.clone(), tx2.clone()],
         parent_hash: None,
         state_root: Hash::zero(),
@@ -96,6 +98,9 @@ fn block_hash_changes_with_tx_order() {
     let block_b = Block {
         slot: Slot::new(5),
         epoch: Epoch::new(0),
+        base_fee: 1,
+        gas_used: 0,
+        proposer: Address::zero(),
         transactions: vec![tx2, tx1],
         parent_hash: None,
         state_root: Hash::zero(),
@@ -123,6 +128,9 @@ fn block_hash_changes_with_signature() {
     let block_a = Block {
         slot: Slot::new(7),
         epoch: Epoch::new(2),
+        base_fee: 1,
+        gas_used: 0,
+        proposer: Address::zero(),
         transactions: vec![tx_a],
         parent_hash: None,
         state_root: Hash::zero(),
@@ -132,6 +140,9 @@ fn block_hash_changes_with_signature() {
     let block_b = Block {
         slot: Slot::new(7),
         epoch: Epoch::new(2),
+        base_fee: 1,
+        gas_used: 0,
+        proposer: Address::zero(),
         transactions: vec![tx_b],
         parent_hash: None,
         state_root: Hash::zero(),
@@ -158,6 +169,9 @@ fn block_hash_changes_with_context() {
     let block_slot_a = Block {
         slot: Slot::new(1),
         epoch: Epoch::new(0),
+        base_fee: 1,
+        gas_used: 0,
+        proposer: Address::zero(),
         transactions: vec![tx.clone()],
         parent_hash: None,
         state_root: Hash::zero(),
@@ -167,6 +181,9 @@ fn block_hash_changes_with_context() {
     let block_slot_b = Block {
         slot: Slot::new(2),
         epoch: Epoch::new(0),
+        base_fee: 1,
+        gas_used: 0,
+        proposer: Address::zero(),
         transactions: vec![tx.clone()],
         parent_hash: None,
         state_root: Hash::zero(),
@@ -176,6 +193,9 @@ fn block_hash_changes_with_context() {
     let block_epoch_c = Block {
         slot: Slot::new(1),
         epoch: Epoch::new(1),
+        base_fee: 1,
+        gas_used: 0,
+        proposer: Address::zero(),
         transactions: vec![tx],
         parent_hash: None,
         state_root: Hash::zero(),
@@ -199,6 +219,9 @@ fn canonical_encoding_is_stable() {
     let block = Block {
         slot: Slot::new(42),
         epoch: Epoch::new(9),
+        base_fee: 1,
+        gas_used: 0,
+        proposer: Address::zero(),
         transactions: vec![tx],
         parent_hash: None,
         state_root: Hash::zero(),
@@ -225,6 +248,9 @@ fn block_hash_changes_with_state_root() {
         parent_hash: None,
         slot: Slot::new(1),
         epoch: Epoch::new(0),
+        base_fee: 1,
+        gas_used: 0,
+        proposer: Address::zero(),
         state_root: Hash::zero(),
         receipts_root: Hash::zero(),
         transactions: vec![tx.clone()],
@@ -234,6 +260,9 @@ fn block_hash_changes_with_state_root() {
         parent_hash: None,
         slot: Slot::new(1),
         epoch: Epoch::new(0),
+        base_fee: 1,
+        gas_used: 0,
+        proposer: Address::zero(),
         state_root: Hash::new([7u8; 32]),
         receipts_root: Hash::zero(),
         transactions: vec![tx],
@@ -260,6 +289,9 @@ fn block_hash_changes_with_receipts_root() {
         parent_hash: None,
         slot: Slot::new(1),
         epoch: Epoch::new(0),
+        base_fee: 1,
+        gas_used: 0,
+        proposer: Address::zero(),
         state_root: Hash::zero(),
         receipts_root: Hash::zero(),
         transactions: vec![tx.clone()],
@@ -269,6 +301,9 @@ fn block_hash_changes_with_receipts_root() {
         parent_hash: None,
         slot: Slot::new(1),
         epoch: Epoch::new(0),
+        base_fee: 1,
+        gas_used: 0,
+        proposer: Address::zero(),
         state_root: Hash::zero(),
         receipts_root: Hash::new([9u8; 32]),
         transactions: vec![tx],
@@ -280,3 +315,104 @@ fn block_hash_changes_with_receipts_root() {
         "changing receipts root must change block hash"
     );
 }
+
+// -------------------------------------------------------------
+// Test: Block hash commits to the signature scheme tag
+// -------------------------------------------------------------
+#[test]
+fn block_hash_changes_with_signature_scheme() {
+    let signer = Address::new([6u8; 32]);
+    let target = ObjectId::new(Hash::new([2u8; 32]));
+
+    let tx_a = make_tx(signer, 0, target, 1);
+    let mut tx_b = tx_a.clone();
+    tx_b.signature.scheme = SchemeId::Secp256k1;
+
+    let block_a = Block {
+        parent_hash: None,
+        slot: Slot::new(1),
+        epoch: Epoch::new(0),
+        base_fee: 1,
+        gas_used: 0,
+        proposer: Address::zero(),
+        state_root: Hash::zero(),
+        receipts_root: Hash::zero(),
+        transactions: vec![tx_a],
+    };
+
+    let block_b = Block {
+        transactions: vec![tx_b],
+        ..block_a.clone()
+    };
+
+    assert_ne!(
+        block_a.hash(),
+        block_b.hash(),
+        "signature scheme must be committed to block hash"
+    );
+}
+
+// -------------------------------------------------------------
+// Test: Block hash commits to transaction chain ids
+// -------------------------------------------------------------
+#[test]
+fn block_hash_changes_with_chain_id() {
+    let signer = Address::new([7u8; 32]);
+    let target = ObjectId::new(Hash::new([3u8; 32]));
+
+    let tx_a = make_tx(signer, 0, target, 1);
+    let mut tx_b = tx_a.clone();
+    tx_b.chain_id += 1;
+
+    let block_a = Block {
+        parent_hash: None,
+        slot: Slot::new(1),
+        epoch: Epoch::new(0),
+        base_fee: 1,
+        gas_used: 0,
+        proposer: Address::zero(),
+        state_root: Hash::zero(),
+        receipts_root: Hash::zero(),
+        transactions: vec![tx_a],
+    };
+
+    let block_b = Block {
+        transactions: vec![tx_b],
+        ..block_a.clone()
+    };
+
+    assert_ne!(
+        block_a.hash(),
+        block_b.hash(),
+        "chain id must be committed to block hash"
+    );
+}
+
+// -------------------------------------------------------------
+// Test: Block hash commits to the base fee, gas used and proposer
+// -------------------------------------------------------------
+#[test]
+fn block_hash_changes_with_fee_fields() {
+    let signer = Address::new([8u8; 32]);
+    let target = ObjectId::new(Hash::new([4u8; 32]));
+
+    let block = Block {
+        parent_hash: None,
+        slot: Slot::new(1),
+        epoch: Epoch::new(0),
+        base_fee: 1,
+        gas_used: 0,
+        proposer: Address::zero(),
+        state_root: Hash::zero(),
+        receipts_root: Hash::zero(),
+        transactions: vec![make_tx(signer, 0, target, 1)],
+    };
+
+    let other_fee = Block { base_fee: 2, ..block.clone() };
+    let other_gas = Block { gas_used: 1, ..block.clone() };
+    let other_proposer = Block { proposer: signer, ..block.clone() };
+
+    assert_ne!(block.hash(), other_fee.hash());
+    assert_ne!(block.hash(), other_gas.hash());
+    assert_ne!(block.hash(), other_proposer.hash());
+}
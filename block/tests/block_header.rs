@@ -0,0 +1,101 @@
+use std::collections::{HashMap, BTreeMap};
+
+use axiom_block::{Block, transaction_hash, transaction_proof, verify_transaction_proof};
+use axiom_ext_tx::{ExternalTransaction, Signature, SchemeId, ValidityWindow};
+use axiom_tx::{TransactionCell, CallData};
+use axiom_types::{Address, Slot, Epoch, ObjectId};
+use axiom_types::Hash;
+
+const CHAIN_ID: u64 = 1;
+
+// -------------------------------------------------------------
+// Helper: create a minimal ExternalTransaction
+// -------------------------------------------------------------
+fn make_tx(nonce: u64, sig_byte: u8) -> ExternalTransaction {
+    let cell = TransactionCell::new(
+        HashMap::new(),
+        BTreeMap::new(),
+        CallData {
+            target: ObjectId::new(Hash::new([9u8; 32])),
+            selector: vec![],
+            payload: vec![],
+        },
+    ).unwrap();
+
+    ExternalTransaction {
+        chain_id: CHAIN_ID,
+        signer: Address::new([1u8; 32]),
+        nonce,
+        validity: ValidityWindow::unbounded(),
+        max_fee: 1,
+        priority_fee: 0,
+        gas_limit: 100_000,
+        cells: vec![cell],
+        signature: Signature {
+            scheme: SchemeId::Ed25519,
+            public_key: vec![0u8; 32],
+            bytes: vec![sig_byte; 64],
+        },
+        approvals: vec![],
+    }
+}
+
+fn make_block(transactions: Vec<ExternalTransaction>) -> Block {
+    Block {
+        parent_hash: Some(Hash::new([3u8; 32])),
+        slot: Slot::new(10),
+        epoch: Epoch::new(1),
+        base_fee: 1,
+        gas_used: 0,
+        proposer: Address::zero(),
+        state_root: Hash::new([4u8; 32]),
+        receipts_root: Hash::new([5u8; 32]),
+        transactions,
+    }
+}
+
+// -------------------------------------------------------------
+// Test: Block hash is the header hash
+// -------------------------------------------------------------
+#[test]
+fn block_hash_is_header_hash() {
+    let block = make_block(vec![make_tx(0, 1), make_tx(1, 2)]);
+    let header = block.header();
+
+    assert_eq!(block.hash(), header.hash());
+    assert_eq!(header.state_root, block.state_root);
+    assert_eq!(header.receipts_root, block.receipts_root);
+}
+
+// -------------------------------------------------------------
+// Test: Header verifies exactly the committed body
+// -------------------------------------------------------------
+#[test]
+fn header_verifies_body() {
+    let block = make_block(vec![make_tx(0, 1), make_tx(1, 2)]);
+    let header = block.header();
+
+    assert!(header.verify_body(&block.transactions));
+
+    // Reordered, truncated or re-signed bodies do not match
+    assert!(!header.verify_body(&[make_tx(1, 2), make_tx(0, 1)]));
+    assert!(!header.verify_body(&[make_tx(0, 1)]));
+    assert!(!header.verify_body(&[make_tx(0, 1), make_tx(1, 3)]));
+}
+
+// -------------------------------------------------------------
+// Test: Single transactions prove against the transactions root
+// -------------------------------------------------------------
+#[test]
+fn transaction_proofs_verify() {
+    let txs: Vec<_> = (0..5).map(|nonce| make_tx(nonce, nonce as u8)).collect();
+    let header = make_block(txs.clone()).header();
+
+    for (index, tx) in txs.iter().enumerate() {
+        let proof = transaction_proof(&txs, index).unwrap();
+        assert!(verify_transaction_proof(&header.transactions_root, transaction_hash(tx), &proof));
+    }
+
+    let proof = transaction_proof(&txs, 1).unwrap();
+    assert!(!verify_transaction_proof(&header.transactions_root, transaction_hash(&txs[2]), &proof));
+}
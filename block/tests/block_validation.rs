@@ -0,0 +1,295 @@
+mod common;
+
+use axiom_block::{Block, BlockError, execute_block, validate_block};
+use axiom_execution::ExecutionPlan;
+use axiom_execution_engine::{
+    ExecutionContext,
+    ExecutionEngine,
+    ExecutionError,
+    ExecutionOutcome,
+    ReferenceExecutionEngine,
+    StateView,
+};
+use axiom_state::{StateStore, StateObject, ObjectWrite, compute_state_root};
+use axiom_state::balance::{balance_object_id, encode_balance, decode_balance};
+use axiom_ext_tx::{ExternalTransaction, ValidityWindow};
+use ed25519_dalek::SigningKey;
+use axiom_types::{Address, Slot, Epoch, Hash};
+use axiom_protocol::ChainConfig;
+
+use common::{CHAIN_ID, make_cell, signing_key, address_of, unsigned, sign};
+
+/// Gas limit of every test transaction, and a balance that affords many.
+const GAS_LIMIT: u64 = 1_000;
+const FUNDS: u64 = 10 * GAS_LIMIT;
+
+// -------------------------------------------------------------
+// Helper: signed transaction spending from the key's balance
+// -------------------------------------------------------------
+fn signed_tx(key: &SigningKey) -> ExternalTransaction {
+    let signer = address_of(key);
+
+    sign(key, ExternalTransaction {
+        chain_id: CHAIN_ID,
+        signer,
+        nonce: 0,
+        validity: ValidityWindow::unbounded(),
+        max_fee: 1,
+        priority_fee: 0,
+        gas_limit: GAS_LIMIT,
+        cells: vec![make_cell(balance_object_id(signer))],
+        signature: unsigned(),
+        approvals: vec![],
+    })
+}
+
+// -------------------------------------------------------------
+// Helper: parent state funding the key, and a produced child block
+// -------------------------------------------------------------
+fn parent_state(key: &SigningKey) -> StateStore {
+    let signer = address_of(key);
+    let mut state = StateStore::new();
+    state.insert(StateObject::new(balance_object_id(signer), signer, encode_balance(FUNDS))).unwrap();
+    state.commit().unwrap();
+    state
+}
+
+fn parent_block() -> Block {
+    Block {
+        parent_hash: None,
+        slot: Slot::new(1),
+        epoch: Epoch::new(0),
+        base_fee: 1,
+        gas_used: 0,
+        proposer: Address::zero(),
+        state_root: Hash::zero(),
+        receipts_root: Hash::zero(),
+        transactions: vec![],
+    }
+}
+
+fn produce_child(key: &SigningKey, parent: &Block) -> Block {
+    let mut state = parent_state(key);
+    let mut block = Block {
+        parent_hash: Some(parent.hash()),
+        slot: Slot::new(2),
+        epoch: Epoch::new(0),
+        base_fee: 1,
+        gas_used: 0,
+        proposer: Address::new([5u8; 32]),
+        state_root: Hash::zero(),
+        receipts_root: Hash::zero(),
+        transactions: vec![signed_tx(key)],
+    };
+
+    execute_block(&mut state, &mut block, &ReferenceExecutionEngine, &ChainConfig::standard(CHAIN_ID)).unwrap();
+    block
+}
+
+// -------------------------------------------------------------
+// Test: A correctly produced block validates on a follower
+// -------------------------------------------------------------
+#[test]
+fn valid_block_is_accepted() {
+    let key = signing_key(1);
+    let parent = parent_block();
+    let block = produce_child(&key, &parent);
+
+    let mut follower = parent_state(&key);
+    let result = validate_block(
+        &mut follower,
+        &block,
+        Some(&parent.header()),
+        &ReferenceExecutionEngine,
+        &ChainConfig::standard(CHAIN_ID),
+    ).unwrap();
+
+    assert_eq!(result.receipts.len(), 1);
+    assert_eq!(compute_state_root(&follower), block.state_root);
+    assert!(follower.has_pending());
+}
+
+// -------------------------------------------------------------
+// Test: Wrong claimed roots or gas are rejected and state is untouched
+// -------------------------------------------------------------
+#[test]
+fn mismatched_roots_are_rejected() {
+    let key = signing_key(2);
+    let signer = address_of(&key);
+    let parent = parent_block();
+    let block = produce_child(&key, &parent);
+    let config = ChainConfig::standard(CHAIN_ID);
+
+    let mut bad_state = block.clone();
+    bad_state.state_root = Hash::new([7; 32]);
+
+    let mut follower = parent_state(&key);
+    let root_before = compute_state_root(&follower);
+
+    let err = validate_block(&mut follower, &bad_state, Some(&parent.header()), &ReferenceExecutionEngine, &config)
+        .unwrap_err();
+    assert!(matches!(err, BlockError::StateRootMismatch { computed, .. } if computed == block.state_root));
+    assert_eq!(compute_state_root(&follower), root_before);
+    assert_eq!(decode_balance(follower.get(&balance_object_id(signer)).unwrap()), FUNDS);
+    assert!(!follower.has_pending());
+
+    let mut bad_receipts = block.clone();
+    bad_receipts.receipts_root = Hash::new([8; 32]);
+
+    let err = validate_block(&mut follower, &bad_receipts, Some(&parent.header()), &ReferenceExecutionEngine, &config)
+        .unwrap_err();
+    assert!(matches!(err, BlockError::ReceiptsRootMismatch { .. }));
+    assert_eq!(compute_state_root(&follower), root_before);
+
+    let mut bad_gas = block.clone();
+    bad_gas.gas_used += 1;
+
+    let err = validate_block(&mut follower, &bad_gas, Some(&parent.header()), &ReferenceExecutionEngine, &config)
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        BlockError::GasUsedMismatch { claimed, computed } if claimed == computed + 1 && computed == block.gas_used
+    ));
+    assert_eq!(compute_state_root(&follower), root_before);
+}
+
+// -------------------------------------------------------------
+// Test: Blocks must link to their parent header
+// -------------------------------------------------------------
+#[test]
+fn header_linkage_is_checked() {
+    let key = signing_key(3);
+    let parent = parent_block();
+    let block = produce_child(&key, &parent);
+    let config = ChainConfig::standard(CHAIN_ID);
+    let mut follower = parent_state(&key);
+
+    let mut orphan = block.clone();
+    orphan.parent_hash = Some(Hash::new([9; 32]));
+    assert!(matches!(
+        validate_block(&mut follower, &orphan, Some(&parent.header()), &ReferenceExecutionEngine, &config),
+        Err(BlockError::ParentHashMismatch { .. })
+    ));
+
+    // Genesis must not claim a parent
+    assert!(matches!(
+        validate_block(&mut follower, &block, None, &ReferenceExecutionEngine, &config),
+        Err(BlockError::ParentHashMismatch { expected: None, .. })
+    ));
+
+    let mut stale_slot = block.clone();
+    stale_slot.slot = parent.slot;
+    assert!(matches!(
+        validate_block(&mut follower, &stale_slot, Some(&parent.header()), &ReferenceExecutionEngine, &config),
+        Err(BlockError::InvalidSlot { .. })
+    ));
+
+    let mut later_parent = parent.clone();
+    later_parent.epoch = Epoch::new(1);
+    let mut child = block.clone();
+    child.parent_hash = Some(later_parent.hash());
+    assert!(matches!(
+        validate_block(&mut follower, &child, Some(&later_parent.header()), &ReferenceExecutionEngine, &config),
+        Err(BlockError::InvalidEpoch { .. })
+    ));
+
+    // Only genesis may go without a proposer
+    let mut unproposed = block.clone();
+    unproposed.proposer = Address::zero();
+    assert!(matches!(
+        validate_block(&mut follower, &unproposed, Some(&parent.header()), &ReferenceExecutionEngine, &config),
+        Err(BlockError::ZeroProposer)
+    ));
+
+    assert!(!follower.has_pending());
+}
+
+// -------------------------------------------------------------
+// Test: Block base fee must follow from the parent, within the limits
+// -------------------------------------------------------------
+#[test]
+fn base_fee_is_derived_from_parent() {
+    let key = signing_key(4);
+    let parent = parent_block();
+    let block = produce_child(&key, &parent);
+    let mut config = ChainConfig::standard(CHAIN_ID);
+    let mut follower = parent_state(&key);
+
+    let mut wrong_fee = block.clone();
+    wrong_fee.base_fee = 2;
+    assert!(matches!(
+        validate_block(&mut follower, &wrong_fee, Some(&parent.header()), &ReferenceExecutionEngine, &config),
+        Err(BlockError::BaseFeeMismatch { expected: 1, found: 2 })
+    ));
+
+    // Gas used above the target raises the base fee the child must declare
+    config.fees.target_gas = block.gas_used - 1;
+    assert_eq!(block.header().next_base_fee(&config.fees), 2);
+
+    // Transaction count alone does not
+    let mut crowded = parent.clone();
+    crowded.transactions = vec![signed_tx(&key), signed_tx(&key), signed_tx(&key)];
+    assert_eq!(crowded.header().next_base_fee(&config.fees), 1);
+
+    config.fees.max_transactions = 0;
+    assert!(matches!(
+        validate_block(&mut follower, &block, Some(&parent.header()), &ReferenceExecutionEngine, &config),
+        Err(BlockError::TooManyTransactions { max: 0, found: 1 })
+    ));
+
+    config = ChainConfig::standard(CHAIN_ID);
+    config.max_block_gas = block.gas_used - 1;
+    assert!(matches!(
+        validate_block(&mut follower, &block, Some(&parent.header()), &ReferenceExecutionEngine, &config),
+        Err(BlockError::TooMuchGas { max, found }) if max == block.gas_used - 1 && found == block.gas_used
+    ));
+
+    assert!(!follower.has_pending());
+}
+
+// -------------------------------------------------------------
+// Helper: engine that also mints a token to a fixed account
+// -------------------------------------------------------------
+struct MintingEngine;
+
+impl ExecutionEngine for MintingEngine {
+    fn execute(
+        &self,
+        plan: &ExecutionPlan,
+        state: &dyn StateView,
+        context: ExecutionContext,
+    ) -> Result<ExecutionOutcome, ExecutionError> {
+        let mut outcome = ReferenceExecutionEngine.execute(plan, state, context)?;
+
+        let account = Address::new([12u8; 32]);
+        let minted = StateObject::new(balance_object_id(account), account, encode_balance(1));
+        outcome.writes.insert(minted.id(), ObjectWrite::Put(minted));
+
+        Ok(outcome)
+    }
+}
+
+// -------------------------------------------------------------
+// Test: Blocks that do not conserve supply are rejected
+// -------------------------------------------------------------
+#[test]
+fn supply_mismatch_is_rejected() {
+    let key = signing_key(5);
+    let parent = parent_block();
+    let block = produce_child(&key, &parent);
+
+    let mut follower = parent_state(&key);
+    let root_before = compute_state_root(&follower);
+
+    let err = validate_block(
+        &mut follower,
+        &block,
+        Some(&parent.header()),
+        &MintingEngine,
+        &ChainConfig::standard(CHAIN_ID),
+    ).unwrap_err();
+
+    assert!(matches!(err, BlockError::SupplyMismatch { expected, found } if found == expected + 1));
+    assert_eq!(compute_state_root(&follower), root_before);
+    assert!(!follower.has_pending());
+}
@@ -0,0 +1,178 @@
+use std::collections::{HashMap, BTreeMap};
+
+use axiom_block::{
+    Block,
+    CODEC_VERSION,
+    DecodeError,
+    decode_block,
+    decode_transaction,
+    encode_block,
+    encode_transaction,
+};
+use axiom_ext_tx::{ExternalTransaction, MultisigApproval, MultisigPolicy, Signature, SchemeId, ValidityWindow};
+use axiom_tx::{TransactionCell, CallData, WriteIntent};
+use axiom_types::{Address, Slot, Epoch, ObjectId, Hash};
+
+const CHAIN_ID: u64 = 1;
+
+// -------------------------------------------------------------
+// Helper: transaction exercising every encoded field
+// -------------------------------------------------------------
+fn object(seed: u8) -> ObjectId {
+    ObjectId::new(Hash::new([seed; 32]))
+}
+
+fn signature(seed: u8) -> Signature {
+    Signature {
+        scheme: SchemeId::Secp256k1,
+        public_key: vec![seed; 33],
+        bytes: vec![seed; 64],
+    }
+}
+
+fn make_tx() -> ExternalTransaction {
+    let cell = TransactionCell::new(
+        HashMap::from([(object(3), 4), (object(1), 0), (object(2), 9)]),
+        BTreeMap::from([(object(1), WriteIntent::Modify), (object(3), WriteIntent::Delete)]),
+        CallData {
+            target: object(1),
+            selector: b"axiom::write".to_vec(),
+            payload: vec![1, 2, 3],
+        },
+    ).unwrap();
+
+    let noop = TransactionCell::new(
+        HashMap::new(),
+        BTreeMap::new(),
+        CallData { target: object(5), selector: vec![], payload: vec![] },
+    ).unwrap();
+
+    let policy = MultisigPolicy::new(
+        1,
+        vec![Address::new([9u8; 32]), Address::new([8u8; 32])],
+    ).unwrap();
+
+    ExternalTransaction {
+        chain_id: CHAIN_ID,
+        signer: Address::new([1u8; 32]),
+        nonce: 42,
+        validity: ValidityWindow::unbounded(),
+        max_fee: 1,
+        priority_fee: 0,
+        gas_limit: 100_000,
+        cells: vec![cell, noop],
+        signature: signature(5),
+        approvals: vec![MultisigApproval { policy, signatures: vec![signature(6)] }],
+    }
+}
+
+fn make_block() -> Block {
+    Block {
+        parent_hash: Some(Hash::new([2u8; 32])),
+        slot: Slot::new(10),
+        epoch: Epoch::new(1),
+        base_fee: 1,
+        gas_used: 0,
+        proposer: Address::zero(),
+        state_root: Hash::new([3u8; 32]),
+        receipts_root: Hash::new([4u8; 32]),
+        transactions: vec![make_tx(), make_tx()],
+    }
+}
+
+// -------------------------------------------------------------
+// Test: Blocks and transactions round-trip exactly
+// -------------------------------------------------------------
+#[test]
+fn codec_round_trips() {
+    let tx = make_tx();
+    let decoded = decode_transaction(&encode_transaction(&tx)).unwrap();
+
+    assert_eq!(encode_transaction(&decoded), encode_transaction(&tx));
+    assert_eq!(decoded.signing_hash(), tx.signing_hash());
+    assert_eq!(decoded.cells[0].read_set(), tx.cells[0].read_set());
+    assert_eq!(decoded.cells[0].write_set(), tx.cells[0].write_set());
+    assert_eq!(decoded.approvals[0].policy, tx.approvals[0].policy);
+
+    let block = make_block();
+    let decoded = decode_block(&encode_block(&block)).unwrap();
+
+    assert_eq!(encode_block(&decoded), encode_block(&block));
+    assert_eq!(decoded.hash(), block.hash());
+}
+
+// -------------------------------------------------------------
+// Test: Every truncation and any trailing byte is rejected
+// -------------------------------------------------------------
+#[test]
+fn codec_rejects_truncated_and_trailing_input() {
+    let bytes = encode_block(&make_block());
+
+    for len in 0..bytes.len() {
+        assert!(decode_block(&bytes[..len]).is_err(), "prefix of length {len} decoded");
+    }
+
+    let mut extended = bytes.clone();
+    extended.push(0);
+    assert!(matches!(
+        decode_block(&extended),
+        Err(DecodeError::TrailingBytes { remaining: 1 })
+    ));
+}
+
+// -------------------------------------------------------------
+// Test: Unknown versions and tags are rejected
+// -------------------------------------------------------------
+#[test]
+fn codec_rejects_unknown_version_and_tags() {
+    let mut bytes = encode_transaction(&make_tx());
+    bytes[0] = CODEC_VERSION + 1;
+    assert!(matches!(
+        decode_transaction(&bytes),
+        Err(DecodeError::UnsupportedVersion { .. })
+    ));
+
+    let mut bytes = encode_block(&make_block());
+    bytes[1] = 2; // parent hash presence flag
+    assert!(matches!(
+        decode_block(&bytes),
+        Err(DecodeError::InvalidTag { field: "parent_hash", tag: 2 })
+    ));
+}
+
+// -------------------------------------------------------------
+// Test: Oversized counts fail before allocating
+// -------------------------------------------------------------
+#[test]
+fn codec_rejects_length_overflow() {
+    let mut bytes = encode_transaction(&make_tx());
+
+    // Cell count follows version, chain id, signer, nonce, validity window, fees and gas limit
+    let offset = 1 + 8 + 32 + 8 + 16 + 16 + 8;
+    bytes[offset..offset + 4].copy_from_slice(&u32::MAX.to_be_bytes());
+
+    assert!(matches!(
+        decode_transaction(&bytes),
+        Err(DecodeError::LengthOverflow { length: u32::MAX, .. })
+    ));
+}
+
+// -------------------------------------------------------------
+// Test: Non-canonical read set ordering is rejected
+// -------------------------------------------------------------
+#[test]
+fn codec_rejects_non_canonical_order() {
+    let mut bytes = encode_transaction(&make_tx());
+
+    // First read entry follows cell count and read count
+    let first = 1 + 8 + 32 + 8 + 16 + 16 + 8 + 4 + 4;
+    let second = first + 40;
+
+    let (head, tail) = bytes.split_at_mut(second);
+    head[first..first + 32].swap_with_slice(&mut tail[..32]);
+
+    assert!(matches!(
+        decode_transaction(&bytes),
+        Err(DecodeError::NonCanonicalOrder { field: "read_set" })
+    ));
+}
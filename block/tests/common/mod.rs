@@ -0,0 +1,55 @@
+//! Helpers shared by the block integration tests.
+
+#![allow(dead_code)]
+
+use std::collections::{HashMap, BTreeMap};
+
+use axiom_ext_tx::{ExternalTransaction, Signature, SchemeId, address_from_public_key};
+use ed25519_dalek::{Signer, SigningKey};
+use axiom_tx::{TransactionCell, CallData};
+use axiom_types::{Address, ObjectId};
+
+pub const CHAIN_ID: u64 = 1;
+
+// -------------------------------------------------------------
+// Helper: create a minimal valid TransactionCell
+// -------------------------------------------------------------
+pub fn make_cell(target: ObjectId) -> TransactionCell {
+    TransactionCell::new(
+        HashMap::new(),
+        BTreeMap::new(),
+        CallData {
+            target,
+            selector: vec![],
+            payload: vec![],
+        },
+    ).unwrap()
+}
+
+// -------------------------------------------------------------
+// Helper: deterministic signing key and the address it controls
+// -------------------------------------------------------------
+pub fn signing_key(seed: u8) -> SigningKey {
+    SigningKey::from_bytes(&[seed; 32])
+}
+
+pub fn address_of(key: &SigningKey) -> Address {
+    address_from_public_key(SchemeId::Ed25519, key.verifying_key().as_bytes())
+}
+
+// -------------------------------------------------------------
+// Helper: sign a transaction with the given key
+// -------------------------------------------------------------
+pub fn unsigned() -> Signature {
+    Signature { scheme: SchemeId::Ed25519, public_key: vec![], bytes: vec![] }
+}
+
+pub fn sign(key: &SigningKey, mut tx: ExternalTransaction) -> ExternalTransaction {
+    let signature = key.sign(tx.signing_hash().as_bytes());
+    tx.signature = Signature {
+        scheme: SchemeId::Ed25519,
+        public_key: key.verifying_key().to_bytes().to_vec(),
+        bytes: signature.to_bytes().to_vec(),
+    };
+    tx
+}
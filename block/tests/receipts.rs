@@ -0,0 +1,79 @@
+use axiom_block::{
+    Receipt,
+    ReceiptStatus,
+    compute_receipts_root,
+    receipt_proof,
+    verify_receipt_proof,
+};
+use axiom_types::Hash;
+
+// -------------------------------------------------------------
+// Helper: receipts for `count` transactions
+// -------------------------------------------------------------
+fn receipts(count: u32) -> Vec<Receipt> {
+    (0..count)
+        .map(|index| Receipt {
+            index,
+            tx_hash: Hash::new([index as u8; 32]),
+            status: if index % 3 == 0 { ReceiptStatus::Failure } else { ReceiptStatus::Success },
+            fee_charged: index as u64,
+            gas_used: 0,
+        })
+        .collect()
+}
+
+// -------------------------------------------------------------
+// Test: Every receipt proves against the root, for any tree shape
+// -------------------------------------------------------------
+#[test]
+fn receipt_proofs_verify() {
+    for count in 1..=9 {
+        let receipts = receipts(count);
+        let root = compute_receipts_root(&receipts);
+
+        for (index, receipt) in receipts.iter().enumerate() {
+            let proof = receipt_proof(&receipts, index).unwrap();
+            assert!(verify_receipt_proof(&root, receipt, &proof), "count {count} index {index}");
+        }
+
+        assert!(receipt_proof(&receipts, count as usize).is_none());
+    }
+}
+
+// -------------------------------------------------------------
+// Test: Altered receipts and misplaced proofs are rejected
+// -------------------------------------------------------------
+#[test]
+fn receipt_proof_rejects_tampering() {
+    let receipts = receipts(5);
+    let root = compute_receipts_root(&receipts);
+    let proof = receipt_proof(&receipts, 2).unwrap();
+
+    let mut forged = receipts[2].clone();
+    forged.status = ReceiptStatus::Failure;
+    assert!(!verify_receipt_proof(&root, &forged, &proof));
+
+    let mut forged = receipts[2].clone();
+    forged.fee_charged += 1;
+    assert!(!verify_receipt_proof(&root, &forged, &proof));
+
+    // Proof for one position cannot vouch for another receipt
+    assert!(!verify_receipt_proof(&root, &receipts[3], &proof));
+
+    // Truncated proof
+    let mut truncated = proof.clone();
+    truncated.siblings.pop();
+    assert!(!verify_receipt_proof(&root, &receipts[2], &truncated));
+}
+
+// -------------------------------------------------------------
+// Test: Root commits to the number of receipts
+// -------------------------------------------------------------
+#[test]
+fn receipts_root_commits_to_count() {
+    let three = receipts(3);
+    let four = receipts(4);
+
+    assert_ne!(compute_receipts_root(&three), compute_receipts_root(&four));
+    assert_ne!(compute_receipts_root(&[]), compute_receipts_root(&three[..1]));
+}
@@ -0,0 +1,77 @@
+use axiom_block::BlockError;
+use axiom_state::StateError;
+use axiom_types::{Address, Hash};
+
+/// Errors returned by the chain store.
+#[derive(Debug)]
+pub enum ChainError {
+    /// Block is already stored.
+    AlreadyKnown { hash: Hash },
+
+    /// Parent is not stored (None for a second genesis block).
+    UnknownParent { parent: Option<Hash> },
+
+    /// Block header does not link to its parent.
+    InvalidHeader(BlockError),
+
+    /// Side branch block at `height` competes with a finalized block.
+    BelowFinalized { height: u64, finalized: u64 },
+
+    /// Block failed validation against its parent state.
+    InvalidBlock { hash: Hash, error: BlockError },
+
+    /// Genesis parameters are for another chain than the config.
+    ChainIdMismatch { genesis: u64, config: u64 },
+
+    /// Genesis fee market, gas schedule or gas limits differ from the config.
+    ParametersMismatch,
+
+    /// Genesis could not be built.
+    Genesis(GenesisError),
+
+    /// State store failed.
+    State(StateError),
+}
+
+/// Errors returned when loading or building a genesis spec.
+#[derive(Debug)]
+pub enum GenesisError {
+    /// Spec file could not be read.
+    Io(std::io::Error),
+
+    /// Spec is not valid TOML or is missing fields.
+    Parse(toml::de::Error),
+
+    /// Address is not 32 hex-encoded bytes.
+    InvalidAddress { value: String },
+
+    /// Fee market parameter `name` is zero, but the base fee update divides by it.
+    ZeroParameter { name: &'static str },
+
+    /// More than all of the base fee would be burned.
+    InvalidBurnShare { basis_points: u64 },
+
+    /// Initial base fee is below the floor the fee market keeps it at.
+    BaseFeeBelowMinimum { base_fee: u64, min_base_fee: u64 },
+
+    /// A single transaction may use more gas than a whole block.
+    TransactionGasAboveBlock { max_transaction_gas: u64, max_block_gas: u64 },
+
+    /// The zero address is given a balance; it must never hold one.
+    ZeroAddressBalance,
+
+    /// Address has more than one balance entry.
+    DuplicateBalance { address: Address },
+
+    /// Address appears more than once in the validator set.
+    DuplicateValidator { address: Address },
+
+    /// Validator has no stake.
+    ZeroStake { address: Address },
+
+    /// Balances add up to more than the largest representable supply.
+    SupplyOverflow,
+
+    /// Genesis state could not be built.
+    State(StateError),
+}
@@ -0,0 +1,343 @@
+// Imports and dependencies
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use serde::Deserialize;
+
+use axiom_block::{Block, compute_receipts_root};
+use axiom_execution_engine::ExecutionEngine;
+use axiom_ext_tx::SchemeRegistry;
+use axiom_protocol::{BASIS_POINTS, ChainConfig};
+use axiom_state::{
+    ProtocolParameters, StateObject, StateStore, Supply, balance_object_id, compute_state_root,
+    encode_balance, encode_stake, validator_object_id,
+};
+use axiom_types::{Address, Epoch, Hash, Slot};
+use crate::{ChainError, ChainStore, GenesisError};
+
+// -------------------------------------------------------------------------------------------------------------------------- //
+
+//----------------------------------------------- Genesis Spec -----------------------------------------------//
+
+// Genesis specification for Axiom networks.
+//
+// A spec is a TOML file:
+//
+//   chain_id = 1337
+//   slot = 0            # optional
+//   epoch = 0           # optional
+//
+//   [parameters]
+//   base_fee = 1
+//   target_gas = 5000000      # optional, as are all fields below
+//   max_transactions = 2000
+//   change_denominator = 8
+//   min_base_fee = 1
+//   burn_basis_points = 5000
+//   gas_call = 500
+//   gas_read = 200
+//   gas_write = 1000
+//   gas_byte = 10
+//   max_transaction_gas = 1000000
+//   max_block_gas = 10000000
+//
+//   [[balances]]
+//   address = "<64 hex chars>"
+//   amount = 1000
+//
+//   [[validators]]
+//   address = "<64 hex chars>"
+//   stake = 100
+//
+// Omitted parameters take the values of `ChainConfig::standard`. The fee
+// market, gas schedule and gas limits are consensus rules, so a chain started from a
+// spec runs under exactly the parameters it records.
+//
+// Balances, validators and parameters become state objects, so the
+// genesis state root (and with it the genesis hash) commits to all of
+// them. The genesis block carries no transactions.
+
+/// Initial balance of an account.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GenesisBalance {
+    pub address: Address,
+    pub amount: u64,
+}
+
+/// Initial validator and its stake.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GenesisValidator {
+    pub address: Address,
+    pub stake: u64,
+}
+
+/// Everything needed to build a network's genesis deterministically.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GenesisSpec {
+    /// Chain parameters, including the chain id.
+    pub parameters: ProtocolParameters,
+
+    /// Slot of the genesis block.
+    pub slot: Slot,
+
+    /// Epoch of the genesis block.
+    pub epoch: Epoch,
+
+    /// Initial balances.
+    pub balances: Vec<GenesisBalance>,
+
+    /// Initial validator set.
+    pub validators: Vec<GenesisValidator>,
+}
+
+/// Genesis state and block built from a spec.
+pub struct Genesis {
+    /// State staged by genesis, not yet committed.
+    pub state: StateStore,
+
+    /// The genesis block.
+    pub block: Block,
+}
+
+impl Genesis {
+    /// Genesis block hash; identifies the chain.
+    pub fn hash(&self) -> Hash {
+        self.block.hash()
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawSpec {
+    chain_id: u64,
+    #[serde(default)]
+    slot: u64,
+    #[serde(default)]
+    epoch: u64,
+    parameters: RawParameters,
+    #[serde(default)]
+    balances: Vec<RawBalance>,
+    #[serde(default)]
+    validators: Vec<RawValidator>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawParameters {
+    base_fee: u64,
+    target_gas: Option<u64>,
+    max_transactions: Option<u64>,
+    change_denominator: Option<u64>,
+    min_base_fee: Option<u64>,
+    burn_basis_points: Option<u64>,
+    gas_call: Option<u64>,
+    gas_read: Option<u64>,
+    gas_write: Option<u64>,
+    gas_byte: Option<u64>,
+    max_transaction_gas: Option<u64>,
+    max_block_gas: Option<u64>,
+}
+
+impl RawParameters {
+    /// Fill omitted fields from the standard config of `chain_id`.
+    fn resolve(&self, chain_id: u64) -> ProtocolParameters {
+        let standard = ChainConfig::standard(chain_id).parameters(self.base_fee);
+
+        ProtocolParameters {
+            chain_id,
+            base_fee: self.base_fee,
+            target_gas: self.target_gas.unwrap_or(standard.target_gas),
+            max_transactions: self.max_transactions.unwrap_or(standard.max_transactions),
+            change_denominator: self.change_denominator.unwrap_or(standard.change_denominator),
+            min_base_fee: self.min_base_fee.unwrap_or(standard.min_base_fee),
+            burn_basis_points: self.burn_basis_points.unwrap_or(standard.burn_basis_points),
+            gas_call: self.gas_call.unwrap_or(standard.gas_call),
+            gas_read: self.gas_read.unwrap_or(standard.gas_read),
+            gas_write: self.gas_write.unwrap_or(standard.gas_write),
+            gas_byte: self.gas_byte.unwrap_or(standard.gas_byte),
+            max_transaction_gas: self.max_transaction_gas.unwrap_or(standard.max_transaction_gas),
+            max_block_gas: self.max_block_gas.unwrap_or(standard.max_block_gas),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawBalance {
+    address: String,
+    amount: u64,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawValidator {
+    address: String,
+    stake: u64,
+}
+
+fn parse_address(value: &str) -> Result<Address, GenesisError> {
+    let invalid = || GenesisError::InvalidAddress { value: value.to_string() };
+
+    let bytes = hex::decode(value).map_err(|_| invalid())?;
+    let bytes: [u8; 32] = bytes.try_into().map_err(|_| invalid())?;
+    Ok(Address::new(bytes))
+}
+
+impl GenesisSpec {
+    /// Load and validate a spec from a TOML file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, GenesisError> {
+        let text = std::fs::read_to_string(path).map_err(GenesisError::Io)?;
+        Self::from_toml(&text)
+    }
+
+    /// Parse and validate a spec from TOML text.
+    pub fn from_toml(text: &str) -> Result<Self, GenesisError> {
+        let raw: RawSpec = toml::from_str(text).map_err(GenesisError::Parse)?;
+
+        let balances = raw.balances.iter()
+            .map(|balance| Ok(GenesisBalance {
+                address: parse_address(&balance.address)?,
+                amount: balance.amount,
+            }))
+            .collect::<Result<Vec<_>, GenesisError>>()?;
+
+        let validators = raw.validators.iter()
+            .map(|validator| Ok(GenesisValidator {
+                address: parse_address(&validator.address)?,
+                stake: validator.stake,
+            }))
+            .collect::<Result<Vec<_>, GenesisError>>()?;
+
+        let spec = Self {
+            parameters: raw.parameters.resolve(raw.chain_id),
+            slot: Slot::new(raw.slot),
+            epoch: Epoch::new(raw.epoch),
+            balances,
+            validators,
+        };
+
+        spec.validate()?;
+        Ok(spec)
+    }
+
+    /// Check the spec is well formed.
+    pub fn validate(&self) -> Result<(), GenesisError> {
+        self.validate_parameters()?;
+
+        let mut seen = BTreeSet::new();
+        for balance in &self.balances {
+            if balance.address == Address::zero() {
+                return Err(GenesisError::ZeroAddressBalance);
+            }
+            if !seen.insert(balance.address) {
+                return Err(GenesisError::DuplicateBalance { address: balance.address });
+            }
+        }
+
+        self.issued()?;
+
+        let mut seen = BTreeSet::new();
+        for validator in &self.validators {
+            if !seen.insert(validator.address) {
+                return Err(GenesisError::DuplicateValidator { address: validator.address });
+            }
+            if validator.stake == 0 {
+                return Err(GenesisError::ZeroStake { address: validator.address });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Check the parameters describe a fee market and gas limits that
+    /// blocks can follow.
+    fn validate_parameters(&self) -> Result<(), GenesisError> {
+        let parameters = &self.parameters;
+
+        if parameters.change_denominator == 0 {
+            return Err(GenesisError::ZeroParameter { name: "change_denominator" });
+        }
+        if parameters.target_gas == 0 {
+            return Err(GenesisError::ZeroParameter { name: "target_gas" });
+        }
+
+        if parameters.burn_basis_points > BASIS_POINTS {
+            return Err(GenesisError::InvalidBurnShare { basis_points: parameters.burn_basis_points });
+        }
+
+        if parameters.base_fee < parameters.min_base_fee {
+            return Err(GenesisError::BaseFeeBelowMinimum {
+                base_fee: parameters.base_fee,
+                min_base_fee: parameters.min_base_fee,
+            });
+        }
+
+        if parameters.max_transaction_gas > parameters.max_block_gas {
+            return Err(GenesisError::TransactionGasAboveBlock {
+                max_transaction_gas: parameters.max_transaction_gas,
+                max_block_gas: parameters.max_block_gas,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Total supply minted at genesis (the sum of all balances).
+    pub fn issued(&self) -> Result<u64, GenesisError> {
+        self.balances.iter()
+            .try_fold(0u64, |total, balance| total.checked_add(balance.amount))
+            .ok_or(GenesisError::SupplyOverflow)
+    }
+
+    /// Build the genesis state and block.
+    ///
+    /// The result depends only on the spec, never on entry order.
+    pub fn build(&self) -> Result<Genesis, GenesisError> {
+        self.validate()?;
+
+        let mut state = StateStore::new();
+
+        state.insert(self.parameters.to_object()).map_err(GenesisError::State)?;
+
+        // Every genesis balance is minted
+        let supply = Supply { issued: self.issued()?, burned: 0 };
+        state.insert(supply.to_object()).map_err(GenesisError::State)?;
+
+        for balance in &self.balances {
+            let id = balance_object_id(balance.address);
+            state.insert(StateObject::new(id, balance.address, encode_balance(balance.amount)))
+                .map_err(GenesisError::State)?;
+        }
+
+        for validator in &self.validators {
+            let id = validator_object_id(validator.address);
+            state.insert(StateObject::new(id, validator.address, encode_stake(validator.stake)))
+                .map_err(GenesisError::State)?;
+        }
+
+        let block = Block {
+            parent_hash: None,
+            slot: self.slot,
+            epoch: self.epoch,
+            base_fee: self.parameters.base_fee,
+            gas_used: 0,
+            proposer: Address::zero(),
+            state_root: compute_state_root(&state),
+            receipts_root: compute_receipts_root(&[]),
+            transactions: Vec::new(),
+        };
+
+        Ok(Genesis { state, block })
+    }
+}
+
+impl<E: ExecutionEngine> ChainStore<E> {
+    /// Start a chain from a genesis spec, under the spec's parameters.
+    pub fn from_genesis(spec: &GenesisSpec, engine: E, schemes: SchemeRegistry) -> Result<Self, ChainError> {
+        let genesis = spec.build().map_err(ChainError::Genesis)?;
+        let config = ChainConfig::from_parameters(&spec.parameters, schemes);
+        Self::new(genesis.state, genesis.block, engine, config)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------- //
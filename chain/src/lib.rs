@@ -0,0 +1,12 @@
+//! Axiom chain store.
+//!
+//! This crate tracks blocks by hash, maintains the canonical chain and
+//! keeps state in step with its head, reorganising on fork choice.
+
+pub mod store;
+pub mod error;
+pub mod genesis;
+
+pub use store::{ChainStore, ImportOutcome, DEFAULT_REORG_DEPTH};
+pub use error::{ChainError, GenesisError};
+pub use genesis::{Genesis, GenesisSpec, GenesisBalance, GenesisValidator};
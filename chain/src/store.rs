@@ -0,0 +1,398 @@
+// Imports and dependencies
+use std::collections::{BTreeMap, HashMap};
+
+use axiom_block::{Block, BlockHeader, validate_block, validate_header};
+use axiom_execution_engine::ExecutionEngine;
+use axiom_protocol::ChainConfig;
+use axiom_state::{ProtocolParameters, StateStore, parameters_object_id};
+use axiom_types::{Hash, Slot};
+use crate::ChainError;
+
+// -------------------------------------------------------------------------------------------------------------------------- //
+
+//----------------------------------------------- Chain Store -----------------------------------------------//
+
+// Chain store for Axiom.
+//
+// Every known block is stored by hash with its height (genesis is 0).
+// The canonical chain is indexed by height and slot, and `state` always
+// holds the post-state of the canonical head, committed at the head's
+// height.
+//
+// Fork choice picks the highest chain; on a tie the current head is kept.
+// When a side branch overtakes the head, state is reverted to the common
+// ancestor and the branch is validated block by block. If a branch block
+// is invalid, it and its descendants are discarded and the previous
+// canonical chain is restored.
+//
+// Reorganisations are at most `reorg_depth` blocks deep. Blocks at or
+// below the finalized height (`reorg_depth` below the head) can no
+// longer become canonical: side branches forking there are refused and,
+// once the head moves past them, dropped. State keeps the history needed
+// to revert to the finalized height and no more.
+
+/// Default for `ChainStore::set_reorg_depth`.
+pub const DEFAULT_REORG_DEPTH: u64 = 1024;
+
+/// A stored block with its height.
+#[derive(Debug)]
+struct StoredBlock {
+    block: Block,
+    height: u64,
+}
+
+/// How an imported block changed the chain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImportOutcome {
+    /// Block extended the canonical head.
+    Extended { height: u64 },
+
+    /// Block was stored on a side branch; the head is unchanged.
+    Stored { height: u64 },
+
+    /// Block's branch became canonical.
+    Reorganized {
+        /// Common ancestor of the old and new head.
+        ancestor: Hash,
+
+        /// Blocks removed from the canonical chain, oldest first.
+        reverted: Vec<Hash>,
+
+        /// Blocks added to the canonical chain, oldest first.
+        applied: Vec<Hash>,
+    },
+}
+
+/// Block tree with a canonical chain and its state.
+pub struct ChainStore<E: ExecutionEngine> {
+    state: StateStore,
+    engine: E,
+    config: ChainConfig,
+
+    /// All known blocks by hash.
+    blocks: HashMap<Hash, StoredBlock>,
+
+    /// Stored children of each stored block.
+    children: HashMap<Hash, Vec<Hash>>,
+
+    /// Canonical block hash at each height.
+    canonical: Vec<Hash>,
+
+    /// Canonical block hash at each occupied slot.
+    by_slot: BTreeMap<Slot, Hash>,
+
+    /// Deepest reorganisation allowed, in blocks.
+    reorg_depth: u64,
+
+    /// Side branches of canonical blocks below this height were dropped.
+    pruned_below: u64,
+}
+
+impl<E: ExecutionEngine> ChainStore<E> {
+    /// Start a chain from `genesis`.
+    ///
+    /// `state` holds the pre-genesis state, staged but not committed; the
+    /// genesis block is validated against it and committed at height 0.
+    ///
+    /// If genesis records protocol parameters, `config` must match them.
+    /// Reorganisations are limited to `DEFAULT_REORG_DEPTH` blocks, and
+    /// `state` keeps history for as many heights.
+    pub fn new(
+        mut state: StateStore,
+        genesis: Block,
+        engine: E,
+        config: ChainConfig,
+    ) -> Result<Self, ChainError> {
+        let hash = genesis.hash();
+
+        validate_block(&mut state, &genesis, None, &engine, &config)
+            .map_err(|error| ChainError::InvalidBlock { hash, error })?;
+
+        let parameters = state.get(&parameters_object_id())
+            .and_then(|object| ProtocolParameters::decode(object.data()));
+
+        if let Some(parameters) = parameters
+            && parameters.chain_id != config.chain_id
+        {
+            return Err(ChainError::ChainIdMismatch {
+                genesis: parameters.chain_id,
+                config: config.chain_id,
+            });
+        }
+
+        if let Some(parameters) = parameters
+            && !config.matches(&parameters)
+        {
+            return Err(ChainError::ParametersMismatch);
+        }
+
+        state.commit_at(0).map_err(ChainError::State)?;
+        state.set_retention(Some(DEFAULT_REORG_DEPTH));
+
+        let mut by_slot = BTreeMap::new();
+        by_slot.insert(genesis.slot, hash);
+
+        let mut blocks = HashMap::new();
+        blocks.insert(hash, StoredBlock { block: genesis, height: 0 });
+
+        Ok(Self {
+            state,
+            engine,
+            config,
+            blocks,
+            children: HashMap::new(),
+            canonical: vec![hash],
+            by_slot,
+            reorg_depth: DEFAULT_REORG_DEPTH,
+            pruned_below: 0,
+        })
+    }
+
+    /// Allow reorganisations of at most `depth` blocks.
+    ///
+    /// State history is kept for `depth` heights, and side branches
+    /// forking below the resulting finalized height are dropped.
+    pub fn set_reorg_depth(&mut self, depth: u64) {
+        self.reorg_depth = depth;
+        self.state.set_retention(Some(depth));
+        self.prune_side_branches();
+    }
+
+    /// Import a block, updating the canonical chain if it wins fork choice.
+    pub fn insert_block(&mut self, block: Block) -> Result<ImportOutcome, ChainError> {
+        let hash = block.hash();
+
+        if self.blocks.contains_key(&hash) {
+            return Err(ChainError::AlreadyKnown { hash });
+        }
+
+        let parent_hash = block.parent_hash;
+        let parent = parent_hash
+            .and_then(|parent| self.blocks.get(&parent))
+            .ok_or(ChainError::UnknownParent { parent: parent_hash })?;
+
+        validate_header(&block, Some(&parent.block.header()), &self.config).map_err(ChainError::InvalidHeader)?;
+        let height = parent.height + 1;
+
+        if parent_hash == Some(self.head_hash()) {
+            self.apply(&block, height)?;
+            self.push_canonical(hash, block.slot);
+            self.store(hash, block, height);
+            self.prune_side_branches();
+
+            return Ok(ImportOutcome::Extended { height });
+        }
+
+        // A side branch block may only compete with non-finalized blocks
+        let finalized = self.finalized_height();
+        if height <= finalized {
+            return Err(ChainError::BelowFinalized { height, finalized });
+        }
+
+        self.store(hash, block, height);
+
+        if height <= self.height() {
+            return Ok(ImportOutcome::Stored { height });
+        }
+
+        let outcome = self.reorganize(hash)?;
+        self.prune_side_branches();
+        Ok(outcome)
+    }
+
+    fn store(&mut self, hash: Hash, block: Block, height: u64) {
+        if let Some(parent) = block.parent_hash {
+            self.children.entry(parent).or_default().push(hash);
+        }
+        self.blocks.insert(hash, StoredBlock { block, height });
+    }
+
+    /// Make the branch ending at `tip` canonical.
+    fn reorganize(&mut self, tip: Hash) -> Result<ImportOutcome, ChainError> {
+        // Walk back to the canonical chain
+        let mut branch = Vec::new();
+        let mut cursor = tip;
+        while !self.is_canonical(&cursor) {
+            branch.push(cursor);
+            cursor = self.blocks[&cursor].block.parent_hash
+                .expect("only genesis has no parent and genesis is canonical");
+        }
+        branch.reverse();
+
+        let ancestor = cursor;
+        let ancestor_height = self.blocks[&ancestor].height;
+
+        let reverted = self.truncate_canonical(ancestor_height)?;
+
+        for (index, hash) in branch.iter().enumerate() {
+            let block = self.blocks[hash].block.clone();
+            let height = ancestor_height + 1 + index as u64;
+
+            if let Err(err) = self.apply(&block, height) {
+                self.discard(*hash);
+
+                // Restore the previous canonical chain; it was valid before
+                self.truncate_canonical(ancestor_height)?;
+                for hash in &reverted {
+                    let block = self.blocks[hash].block.clone();
+                    let height = self.height() + 1;
+                    self.apply(&block, height)?;
+                    self.push_canonical(*hash, block.slot);
+                }
+
+                return Err(err);
+            }
+
+            self.push_canonical(*hash, block.slot);
+        }
+
+        Ok(ImportOutcome::Reorganized {
+            ancestor,
+            reverted,
+            applied: branch,
+        })
+    }
+
+    /// Validate `block` against the head state and commit it at `height`.
+    fn apply(&mut self, block: &Block, height: u64) -> Result<(), ChainError> {
+        let hash = block.hash();
+        let parent: Option<BlockHeader> = block.parent_hash
+            .and_then(|parent| self.blocks.get(&parent))
+            .map(|parent| parent.block.header());
+
+        validate_block(&mut self.state, block, parent.as_ref(), &self.engine, &self.config)
+            .map_err(|error| ChainError::InvalidBlock { hash, error })?;
+
+        self.state.commit_at(height).map_err(ChainError::State)
+    }
+
+    fn push_canonical(&mut self, hash: Hash, slot: Slot) {
+        self.canonical.push(hash);
+        self.by_slot.insert(slot, hash);
+    }
+
+    /// Cut the canonical chain back to `height`, reverting state to it.
+    ///
+    /// Returns the removed hashes, oldest first.
+    fn truncate_canonical(&mut self, height: u64) -> Result<Vec<Hash>, ChainError> {
+        self.state.revert_to(height).map_err(ChainError::State)?;
+
+        let removed = self.canonical.split_off(height as usize + 1);
+        let head_slot = self.head().slot;
+        self.by_slot.split_off(&head_slot.next());
+
+        Ok(removed)
+    }
+
+    /// Remove `hash` and every stored descendant of it.
+    fn discard(&mut self, hash: Hash) {
+        if let Some(parent) = self.blocks.get(&hash).and_then(|stored| stored.block.parent_hash)
+            && let Some(siblings) = self.children.get_mut(&parent)
+        {
+            siblings.retain(|child| *child != hash);
+        }
+
+        let mut doomed = vec![hash];
+        while let Some(hash) = doomed.pop() {
+            self.blocks.remove(&hash);
+            doomed.extend(self.children.remove(&hash).unwrap_or_default());
+        }
+    }
+
+    /// Drop side branches that fork from canonical blocks below the
+    /// finalized height; they can never become canonical.
+    fn prune_side_branches(&mut self) {
+        let finalized = self.finalized_height();
+
+        for height in self.pruned_below..finalized {
+            let canonical = self.canonical[height as usize];
+            let next = self.canonical[height as usize + 1];
+
+            let side: Vec<Hash> = self.children.get(&canonical)
+                .map(|children| children.iter().copied().filter(|child| *child != next).collect())
+                .unwrap_or_default();
+
+            for hash in side {
+                self.discard(hash);
+            }
+        }
+
+        self.pruned_below = self.pruned_below.max(finalized);
+    }
+
+    /// Hash of the canonical head.
+    pub fn head_hash(&self) -> Hash {
+        *self.canonical.last().expect("chain always has a genesis block")
+    }
+
+    /// The canonical head block.
+    pub fn head(&self) -> &Block {
+        &self.blocks[&self.head_hash()].block
+    }
+
+    /// Height of the canonical head.
+    pub fn height(&self) -> u64 {
+        self.canonical.len() as u64 - 1
+    }
+
+    /// Height up to which the canonical chain can no longer be reorganised.
+    ///
+    /// Never decreases, even if the reorg depth is raised.
+    pub fn finalized_height(&self) -> u64 {
+        self.height().saturating_sub(self.reorg_depth).max(self.pruned_below)
+    }
+
+    /// Hash of the genesis block.
+    pub fn genesis_hash(&self) -> Hash {
+        self.canonical[0]
+    }
+
+    /// Fetch any stored block by hash.
+    pub fn block(&self, hash: &Hash) -> Option<&Block> {
+        self.blocks.get(hash).map(|stored| &stored.block)
+    }
+
+    /// Height of any stored block.
+    pub fn height_of(&self, hash: &Hash) -> Option<u64> {
+        self.blocks.get(hash).map(|stored| stored.height)
+    }
+
+    /// Return true if the block is stored.
+    pub fn contains(&self, hash: &Hash) -> bool {
+        self.blocks.contains_key(hash)
+    }
+
+    /// Return true if the block is on the canonical chain.
+    pub fn is_canonical(&self, hash: &Hash) -> bool {
+        self.height_of(hash)
+            .and_then(|height| self.canonical.get(height as usize))
+            .is_some_and(|canonical| canonical == hash)
+    }
+
+    /// Hash of the canonical block at `height`.
+    pub fn canonical_hash(&self, height: u64) -> Option<Hash> {
+        self.canonical.get(height as usize).copied()
+    }
+
+    /// Canonical block at `height`.
+    pub fn block_at_height(&self, height: u64) -> Option<&Block> {
+        self.canonical_hash(height).and_then(|hash| self.block(&hash))
+    }
+
+    /// Canonical block at `slot`, if that slot is occupied.
+    pub fn block_at_slot(&self, slot: Slot) -> Option<&Block> {
+        self.by_slot.get(&slot).and_then(|hash| self.block(hash))
+    }
+
+    /// Network settings of this chain.
+    pub fn config(&self) -> &ChainConfig {
+        &self.config
+    }
+
+    /// State after the canonical head.
+    pub fn state(&self) -> &StateStore {
+        &self.state
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------- //
@@ -0,0 +1,281 @@
+use std::collections::{HashMap, BTreeMap};
+
+use axiom_block::{Block, BlockError, execute_block};
+use axiom_chain::{ChainStore, ChainError, ImportOutcome, GenesisSpec, GenesisBalance};
+use axiom_execution_engine::{ReferenceExecutionEngine, GasSchedule};
+use axiom_state::{StateStore, compute_state_root};
+use axiom_state::balance::{balance_object_id, decode_balance};
+use axiom_ext_tx::{ExternalTransaction, Signature, SchemeId, SchemeRegistry, address_from_public_key, ValidityWindow};
+use ed25519_dalek::{Signer, SigningKey};
+use axiom_tx::{TransactionCell, CallData};
+use axiom_types::{Address, Slot, Epoch, ObjectId, Hash};
+use axiom_protocol::ChainConfig;
+
+const CHAIN_ID: u64 = 1;
+
+/// Gas limit of every test transaction, and a balance that affords many.
+const GAS_LIMIT: u64 = 1_000;
+const FUNDS: u64 = 10 * GAS_LIMIT;
+
+// -------------------------------------------------------------
+// Helper: create a minimal valid TransactionCell
+// -------------------------------------------------------------
+fn make_cell(target: ObjectId) -> TransactionCell {
+    TransactionCell::new(
+        HashMap::new(),
+        BTreeMap::new(),
+        CallData {
+            target,
+            selector: vec![],
+            payload: vec![],
+        },
+    ).unwrap()
+}
+
+// -------------------------------------------------------------
+// Helper: funded keys and signed fee-paying transactions
+// -------------------------------------------------------------
+fn key(seed: u8) -> SigningKey {
+    SigningKey::from_bytes(&[seed; 32])
+}
+
+fn address_of(key: &SigningKey) -> Address {
+    address_from_public_key(SchemeId::Ed25519, key.verifying_key().as_bytes())
+}
+
+fn signed_tx(key: &SigningKey) -> ExternalTransaction {
+    let signer = address_of(key);
+    let mut tx = ExternalTransaction {
+        chain_id: CHAIN_ID,
+        signer,
+        nonce: 0,
+        validity: ValidityWindow::unbounded(),
+        max_fee: 1,
+        priority_fee: 0,
+        gas_limit: GAS_LIMIT,
+        cells: vec![make_cell(balance_object_id(signer))],
+        signature: Signature { scheme: SchemeId::Ed25519, public_key: vec![], bytes: vec![] },
+        approvals: vec![],
+    };
+
+    let signature = key.sign(tx.signing_hash().as_bytes());
+    tx.signature = Signature {
+        scheme: SchemeId::Ed25519,
+        public_key: key.verifying_key().to_bytes().to_vec(),
+        bytes: signature.to_bytes().to_vec(),
+    };
+    tx
+}
+
+// -------------------------------------------------------------
+// Helper: genesis funding three keys, and block producers
+// -------------------------------------------------------------
+fn spec() -> GenesisSpec {
+    GenesisSpec {
+        parameters: ChainConfig::standard(CHAIN_ID).parameters(1),
+        slot: Slot::new(0),
+        epoch: Epoch::new(0),
+        balances: (1..=3)
+            .map(|seed| GenesisBalance { address: address_of(&key(seed)), amount: FUNDS })
+            .collect(),
+        validators: vec![],
+    }
+}
+
+fn genesis() -> Block {
+    spec().build().unwrap().block
+}
+
+fn new_chain() -> ChainStore<ReferenceExecutionEngine> {
+    ChainStore::from_genesis(&spec(), ReferenceExecutionEngine, SchemeRegistry::standard()).unwrap()
+}
+
+/// State of a block producer following its own branch.
+fn producer() -> StateStore {
+    let mut state = spec().build().unwrap().state;
+    state.commit().unwrap();
+    state
+}
+
+fn produce(state: &mut StateStore, parent: &Block, slot: u64, txs: Vec<ExternalTransaction>) -> Block {
+    let mut block = Block {
+        parent_hash: Some(parent.hash()),
+        slot: Slot::new(slot),
+        epoch: Epoch::new(0),
+        base_fee: 1,
+        gas_used: 0,
+        proposer: address_of(&key(9)),
+        state_root: Hash::zero(),
+        receipts_root: Hash::zero(),
+        transactions: txs,
+    };
+    execute_block(state, &mut block, &ReferenceExecutionEngine, &ChainConfig::standard(CHAIN_ID)).unwrap();
+    state.commit().unwrap();
+    block
+}
+
+// -------------------------------------------------------------
+// Test: Blocks extending the head become canonical
+// -------------------------------------------------------------
+#[test]
+fn blocks_extend_canonical_chain() {
+    let mut chain = new_chain();
+    let genesis = genesis();
+    let mut producer = producer();
+
+    let b1 = produce(&mut producer, &genesis, 1, vec![signed_tx(&key(1))]);
+    let b2 = produce(&mut producer, &b1, 3, vec![]);
+
+    assert_eq!(chain.insert_block(b1.clone()).unwrap(), ImportOutcome::Extended { height: 1 });
+    assert_eq!(chain.insert_block(b2.clone()).unwrap(), ImportOutcome::Extended { height: 2 });
+
+    assert_eq!(chain.head_hash(), b2.hash());
+    assert_eq!(chain.height(), 2);
+    assert_eq!(chain.genesis_hash(), genesis.hash());
+    assert_eq!(chain.canonical_hash(1), Some(b1.hash()));
+    assert_eq!(chain.block_at_slot(Slot::new(3)).unwrap().hash(), b2.hash());
+    assert!(chain.block_at_slot(Slot::new(2)).is_none());
+    assert_eq!(compute_state_root(chain.state()), b2.state_root);
+    assert_eq!(chain.state().height(), Some(2));
+}
+
+// -------------------------------------------------------------
+// Test: Blocks must link to a known parent with a later slot
+// -------------------------------------------------------------
+#[test]
+fn bad_linkage_is_rejected() {
+    let mut chain = new_chain();
+    let genesis = genesis();
+    let b1 = produce(&mut producer(), &genesis, 1, vec![]);
+    chain.insert_block(b1.clone()).unwrap();
+
+    assert!(matches!(chain.insert_block(b1.clone()), Err(ChainError::AlreadyKnown { .. })));
+
+    let mut orphan = b1.clone();
+    orphan.parent_hash = Some(Hash::new([9; 32]));
+    assert!(matches!(chain.insert_block(orphan), Err(ChainError::UnknownParent { .. })));
+
+    assert!(matches!(chain.insert_block(genesis.clone()), Err(ChainError::AlreadyKnown { .. })));
+
+    let mut stale = produce(&mut StateStore::new(), &b1, 1, vec![]);
+    stale.slot = b1.slot;
+    assert!(matches!(
+        chain.insert_block(stale),
+        Err(ChainError::InvalidHeader(BlockError::InvalidSlot { .. }))
+    ));
+
+    assert_eq!(chain.head_hash(), b1.hash());
+}
+
+// -------------------------------------------------------------
+// Test: A longer fork triggers a reorganisation
+// -------------------------------------------------------------
+#[test]
+fn longer_fork_reorganizes() {
+    let mut chain = new_chain();
+    let genesis = genesis();
+
+    // Branch A: one block spending from key 1
+    let a1 = produce(&mut producer(), &genesis, 1, vec![signed_tx(&key(1))]);
+    chain.insert_block(a1.clone()).unwrap();
+
+    // Branch B: two blocks spending from key 2
+    let mut branch_b = producer();
+    let b1 = produce(&mut branch_b, &genesis, 2, vec![signed_tx(&key(2))]);
+    let b2 = produce(&mut branch_b, &b1, 3, vec![]);
+
+    // A tie keeps the current head
+    assert_eq!(chain.insert_block(b1.clone()).unwrap(), ImportOutcome::Stored { height: 1 });
+    assert_eq!(chain.head_hash(), a1.hash());
+    assert!(chain.contains(&b1.hash()));
+    assert!(!chain.is_canonical(&b1.hash()));
+
+    assert_eq!(
+        chain.insert_block(b2.clone()).unwrap(),
+        ImportOutcome::Reorganized {
+            ancestor: genesis.hash(),
+            reverted: vec![a1.hash()],
+            applied: vec![b1.hash(), b2.hash()],
+        }
+    );
+
+    assert_eq!(chain.head_hash(), b2.hash());
+    assert!(!chain.is_canonical(&a1.hash()));
+    assert!(chain.block_at_slot(Slot::new(1)).is_none());
+    assert_eq!(compute_state_root(chain.state()), b2.state_root);
+
+    let balance = |seed| decode_balance(chain.state().get(&balance_object_id(address_of(&key(seed)))).unwrap());
+    assert_eq!(balance(1), FUNDS);
+    assert_eq!(balance(2), FUNDS - GasSchedule::default().call);
+}
+
+// -------------------------------------------------------------
+// Test: An invalid fork is discarded and the old head restored
+// -------------------------------------------------------------
+#[test]
+fn invalid_fork_restores_previous_head() {
+    let mut chain = new_chain();
+    let genesis = genesis();
+
+    let a1 = produce(&mut producer(), &genesis, 1, vec![signed_tx(&key(1))]);
+    chain.insert_block(a1.clone()).unwrap();
+    let root = compute_state_root(chain.state());
+
+    let mut branch_b = producer();
+    let mut b1 = produce(&mut branch_b, &genesis, 2, vec![signed_tx(&key(2))]);
+    b1.state_root = Hash::new([7; 32]);
+    let b2 = produce(&mut branch_b, &b1, 3, vec![]);
+
+    chain.insert_block(b1.clone()).unwrap();
+    let err = chain.insert_block(b2.clone()).unwrap_err();
+
+    assert!(matches!(
+        err,
+        ChainError::InvalidBlock { hash, error: BlockError::StateRootMismatch { .. } } if hash == b1.hash()
+    ));
+    assert_eq!(chain.head_hash(), a1.hash());
+    assert_eq!(compute_state_root(chain.state()), root);
+    assert!(!chain.contains(&b1.hash()));
+    assert!(!chain.contains(&b2.hash()));
+    assert_eq!(chain.block_at_slot(Slot::new(1)).unwrap().hash(), a1.hash());
+}
+
+// -------------------------------------------------------------
+// Test: Side branches cannot fork at or below the finalized height
+// -------------------------------------------------------------
+#[test]
+fn finalized_side_branches_are_pruned() {
+    let mut chain = new_chain();
+    chain.set_reorg_depth(1);
+    let genesis = genesis();
+
+    let mut branch_a = producer();
+    let a1 = produce(&mut branch_a, &genesis, 1, vec![]);
+    let a2 = produce(&mut branch_a, &a1, 3, vec![]);
+
+    let b1 = produce(&mut producer(), &genesis, 2, vec![]);
+    let c1 = produce(&mut producer(), &genesis, 4, vec![]);
+
+    let mut branch_d = producer();
+    produce(&mut branch_d, &genesis, 1, vec![]);
+    let d2 = produce(&mut branch_d, &a1, 4, vec![]);
+
+    chain.insert_block(a1.clone()).unwrap();
+    assert_eq!(chain.insert_block(b1.clone()).unwrap(), ImportOutcome::Stored { height: 1 });
+
+    // Extending the head finalizes height 1 and drops the branch competing with it
+    chain.insert_block(a2.clone()).unwrap();
+    assert_eq!(chain.finalized_height(), 1);
+    assert!(!chain.contains(&b1.hash()));
+
+    assert!(matches!(
+        chain.insert_block(c1),
+        Err(ChainError::BelowFinalized { height: 1, finalized: 1 })
+    ));
+    assert_eq!(chain.insert_block(d2.clone()).unwrap(), ImportOutcome::Stored { height: 2 });
+
+    // Raising the depth again does not undo finality
+    chain.set_reorg_depth(10);
+    assert_eq!(chain.finalized_height(), 1);
+    assert_eq!(chain.head_hash(), a2.hash());
+}
@@ -0,0 +1,218 @@
+use std::path::PathBuf;
+
+use axiom_chain::{ChainStore, ChainError, GenesisError, GenesisSpec};
+use axiom_execution_engine::ReferenceExecutionEngine;
+use axiom_ext_tx::SchemeRegistry;
+use axiom_protocol::ChainConfig;
+use axiom_state::{
+    ProtocolParameters, Supply, balance_object_id, check_supply, compute_state_root, decode_balance,
+    decode_stake, parameters_object_id, read_supply, validator_object_id,
+};
+use axiom_types::Address;
+
+// -------------------------------------------------------------
+// Helper: the devnet fixture
+// -------------------------------------------------------------
+fn devnet_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/devnet.toml")
+}
+
+fn devnet() -> GenesisSpec {
+    GenesisSpec::load(devnet_path()).unwrap()
+}
+
+// -------------------------------------------------------------
+// Test: Genesis from a file seeds balances, validators and parameters
+// -------------------------------------------------------------
+#[test]
+fn genesis_file_builds_initial_state() {
+    let spec = devnet();
+    let genesis = spec.build().unwrap();
+
+    assert!(genesis.block.parent_hash.is_none());
+    assert!(genesis.block.transactions.is_empty());
+    assert_eq!(genesis.block.state_root, compute_state_root(&genesis.state));
+
+    let state = &genesis.state;
+    let alice = Address::new([1; 32]);
+    let validator = Address::new([10; 32]);
+
+    assert_eq!(decode_balance(state.get(&balance_object_id(alice)).unwrap()), 1000);
+    assert_eq!(decode_stake(state.get(&validator_object_id(validator)).unwrap()), 100);
+    assert_eq!(
+        ProtocolParameters::decode(state.get(&parameters_object_id()).unwrap().data()),
+        Some(spec.parameters)
+    );
+    assert_eq!(spec.parameters.chain_id, 1337);
+    assert_eq!(spec.parameters.base_fee, 1);
+    assert_eq!(spec.parameters.target_gas, 4_000_000);
+    assert_eq!(spec.parameters.burn_basis_points, 2_500);
+    assert_eq!(spec.parameters.gas_byte, 20);
+}
+
+// -------------------------------------------------------------
+// Test: Omitted fee and gas parameters take the standard values
+// -------------------------------------------------------------
+#[test]
+fn omitted_parameters_are_standard() {
+    let spec = GenesisSpec::from_toml("chain_id = 7\n[parameters]\nbase_fee = 3\n").unwrap();
+    assert_eq!(spec.parameters, ChainConfig::standard(7).parameters(3));
+
+    let devnet = devnet();
+    assert_eq!(devnet.parameters.change_denominator, ChainConfig::standard(1337).fees.change_denominator);
+    assert_eq!(devnet.parameters.gas_write, ChainConfig::standard(1337).gas.write);
+}
+
+// -------------------------------------------------------------
+// Test: Genesis hash is reproducible and identifies the chain
+// -------------------------------------------------------------
+#[test]
+fn genesis_hash_identifies_chain() {
+    let spec = devnet();
+    assert_eq!(spec.build().unwrap().hash(), devnet().build().unwrap().hash());
+
+    // Entry order does not matter
+    let mut reordered = spec.clone();
+    reordered.balances.reverse();
+    reordered.validators.reverse();
+    assert_eq!(reordered.build().unwrap().hash(), spec.build().unwrap().hash());
+
+    // Every field does
+    let mut other_chain = spec.clone();
+    other_chain.parameters.chain_id = 1338;
+    assert_ne!(other_chain.build().unwrap().hash(), spec.build().unwrap().hash());
+
+    let mut other_fees = spec.clone();
+    other_fees.parameters.burn_basis_points += 1;
+    assert_ne!(other_fees.build().unwrap().hash(), spec.build().unwrap().hash());
+
+    let mut other_gas = spec.clone();
+    other_gas.parameters.gas_read += 1;
+    assert_ne!(other_gas.build().unwrap().hash(), spec.build().unwrap().hash());
+
+    let mut other_stake = spec.clone();
+    other_stake.validators[0].stake += 1;
+    assert_ne!(other_stake.build().unwrap().hash(), spec.build().unwrap().hash());
+}
+
+// -------------------------------------------------------------
+// Test: Malformed specs are rejected
+// -------------------------------------------------------------
+#[test]
+fn invalid_specs_are_rejected() {
+    let address = "01".repeat(32);
+
+    let bad_address = "chain_id = 1\n[parameters]\nbase_fee = 1\n[[balances]]\naddress = \"zz\"\namount = 1\n";
+    assert!(matches!(GenesisSpec::from_toml(bad_address), Err(GenesisError::InvalidAddress { .. })));
+
+    let duplicate = format!(
+        "chain_id = 1\n[parameters]\nbase_fee = 1\n\
+         [[balances]]\naddress = \"{address}\"\namount = 1\n\
+         [[balances]]\naddress = \"{address}\"\namount = 2\n"
+    );
+    assert!(matches!(GenesisSpec::from_toml(&duplicate), Err(GenesisError::DuplicateBalance { .. })));
+
+    let zero_stake = format!(
+        "chain_id = 1\n[parameters]\nbase_fee = 1\n[[validators]]\naddress = \"{address}\"\nstake = 0\n"
+    );
+    assert!(matches!(GenesisSpec::from_toml(&zero_stake), Err(GenesisError::ZeroStake { .. })));
+
+    let zero_address = format!(
+        "chain_id = 1\n[parameters]\nbase_fee = 1\n[[balances]]\naddress = \"{}\"\namount = 1\n",
+        "00".repeat(32)
+    );
+    assert!(matches!(GenesisSpec::from_toml(&zero_address), Err(GenesisError::ZeroAddressBalance)));
+
+    let unknown_field = "chain_id = 1\ngas_limit = 5\n[parameters]\nbase_fee = 1\n";
+    assert!(matches!(GenesisSpec::from_toml(unknown_field), Err(GenesisError::Parse(_))));
+
+    assert!(matches!(GenesisSpec::load("/nonexistent/genesis.toml"), Err(GenesisError::Io(_))));
+}
+
+// -------------------------------------------------------------
+// Test: Parameters blocks could not follow are rejected
+// -------------------------------------------------------------
+#[test]
+fn invalid_parameters_are_rejected() {
+    let with = |parameters: &str| GenesisSpec::from_toml(&format!("chain_id = 1\n[parameters]\n{parameters}\n"));
+
+    assert!(matches!(
+        with("base_fee = 1\nchange_denominator = 0"),
+        Err(GenesisError::ZeroParameter { name: "change_denominator" })
+    ));
+    assert!(matches!(
+        with("base_fee = 1\ntarget_gas = 0"),
+        Err(GenesisError::ZeroParameter { name: "target_gas" })
+    ));
+    assert!(matches!(
+        with("base_fee = 1\nburn_basis_points = 10001"),
+        Err(GenesisError::InvalidBurnShare { basis_points: 10_001 })
+    ));
+    assert!(matches!(
+        with("base_fee = 2\nmin_base_fee = 3"),
+        Err(GenesisError::BaseFeeBelowMinimum { base_fee: 2, min_base_fee: 3 })
+    ));
+    assert!(matches!(
+        with("base_fee = 1\nmax_transaction_gas = 101\nmax_block_gas = 100"),
+        Err(GenesisError::TransactionGasAboveBlock { max_transaction_gas: 101, max_block_gas: 100 })
+    ));
+
+    // Boundaries are allowed
+    with("base_fee = 3\nmin_base_fee = 3\nburn_basis_points = 10000\nmax_transaction_gas = 100\nmax_block_gas = 100")
+        .unwrap();
+}
+
+// -------------------------------------------------------------
+// Test: A chain started from a spec is rooted at its genesis hash
+// -------------------------------------------------------------
+#[test]
+fn chain_starts_from_genesis_spec() {
+    let spec = devnet();
+    let chain = ChainStore::from_genesis(&spec, ReferenceExecutionEngine, SchemeRegistry::standard()).unwrap();
+
+    assert_eq!(chain.genesis_hash(), spec.build().unwrap().hash());
+    assert_eq!(chain.head_hash(), chain.genesis_hash());
+    assert_eq!(chain.config().chain_id, 1337);
+    assert_eq!(chain.config().fees.target_gas, 4_000_000);
+    assert_eq!(chain.config().fees.burn_basis_points, 2_500);
+    assert_eq!(chain.config().gas.byte, 20);
+    assert_eq!(chain.state().height(), Some(0));
+    assert!(!chain.state().has_pending());
+}
+
+// -------------------------------------------------------------
+// Test: A config for another chain cannot start from this genesis
+// -------------------------------------------------------------
+#[test]
+fn chain_rejects_mismatched_chain_id() {
+    let genesis = devnet().build().unwrap();
+
+    let result = ChainStore::new(genesis.state, genesis.block, ReferenceExecutionEngine, ChainConfig::standard(1));
+    assert!(matches!(result, Err(ChainError::ChainIdMismatch { genesis: 1337, config: 1 })));
+}
+
+// -------------------------------------------------------------
+// Test: A config with another fee market cannot start from this genesis
+// -------------------------------------------------------------
+#[test]
+fn chain_rejects_mismatched_parameters() {
+    let genesis = devnet().build().unwrap();
+
+    let result = ChainStore::new(genesis.state, genesis.block, ReferenceExecutionEngine, ChainConfig::standard(1337));
+    assert!(matches!(result, Err(ChainError::ParametersMismatch)));
+}
+
+// -------------------------------------------------------------
+// Test: Genesis mints exactly the sum of its balances
+// -------------------------------------------------------------
+#[test]
+fn genesis_mints_supply() {
+    let genesis = devnet().build().unwrap();
+
+    assert_eq!(read_supply(&genesis.state).unwrap(), Some(Supply { issued: 1500, burned: 0 }));
+    assert_eq!(check_supply(&genesis.state).unwrap().issued, 1500);
+
+    let mut overflowing = devnet();
+    overflowing.balances[0].amount = u64::MAX;
+    assert!(matches!(overflowing.build(), Err(GenesisError::SupplyOverflow)));
+}
@@ -26,6 +26,29 @@ pub enum PlanningError {
         signer: Address,
     },
 
+    /// Write intent declared on a protocol-managed object.
+    ProtectedObject {
+        object: ObjectId,
+    },
+
+    /// Declared gas limit is above the chain's per-transaction maximum.
+    GasLimitTooHigh {
+        limit: u64,
+        max: u64,
+    },
+
+    /// Gas limit at the offered price does not fit in a u64.
+    FeeOverflow {
+        gas_limit: u64,
+        gas_price: u64,
+    },
+
+    /// Declared max fee does not cover the block's base fee.
+    FeeTooLow {
+        max_fee: u64,
+        base_fee: u64,
+    },
+
     // Insufficient balance to cover transaction fees.
     InsufficientBalance {
         available: u64,
@@ -6,5 +6,11 @@
 pub mod plan;
 pub mod error;
 
-pub use plan::{ExecutionPlan, build_execution_plan};
+pub use plan::{
+    ExecutionPlan,
+    TransactionCharge,
+    build_execution_plan,
+    charge_transaction,
+    gas_price,
+};
 pub use error::PlanningError;
\ No newline at end of file
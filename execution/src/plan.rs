@@ -1,11 +1,20 @@
 
 /// Imports and dependencies
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
-use axiom_state::{ReadSet, StateObject, StateStore, balance_object_id, decode_balance, encode_balance};
+use axiom_state::{
+    ReadSet,
+    StateObject,
+    StateStore,
+    balance_object_id,
+    decode_balance,
+    encode_balance,
+    is_balance_object,
+    is_nonce_object,
+};
 use axiom_tx::{TransactionCell, WriteIntent};
 use axiom_ext_tx::PreparedExternalTransaction;
-use axiom_types::ObjectId;
+use axiom_types::{Address, ObjectId};
 
 use crate::PlanningError;
 
@@ -14,9 +23,6 @@ use crate::PlanningError;
 
 //----------------------------------------------- Execution Plan -----------------------------------------------//
 
-/// Temporary base_fee
-const BASE_FEE: u64 = 1;
-
 /// A fully prepared, execution-ready plan derived from an
 /// authorized external transaction.
 ///
@@ -28,6 +34,15 @@ const BASE_FEE: u64 = 1;
 /// It does NOT execute code or mutate state.
 #[derive(Debug)]
 pub struct ExecutionPlan {
+    /// Address that authorized the transaction.
+    ///
+    /// Owner of any object created during execution.
+    pub signer: Address,
+
+    /// Addresses the transaction acts for: the signer and every
+    /// multisig address whose approval meets its threshold.
+    pub authorized: BTreeSet<Address>,
+
     /// All objects that must be read, with expected versions.
     ///
     /// This is the merged ReadSet of all transaction cells
@@ -47,55 +62,127 @@ pub struct ExecutionPlan {
 
     /// Transaction cells to be executed, in declared order.
     pub cells: Vec<TransactionCell>,
+
+    /// Price paid per unit of gas consumed (base fee plus tip).
+    pub gas_price: u64,
+
+    /// Most gas execution may consume, as signed by the signer.
+    pub gas_limit: u64,
 }
 
-/// Build an execution plan from a prepared external transaction.
+/// Protocol writes an authorized transaction pays for, whether or not
+/// it goes on to plan and execute successfully.
+#[derive(Debug, Clone)]
+pub struct TransactionCharge {
+    /// Price paid per unit of gas consumed (base fee plus tip).
+    pub gas_price: u64,
+
+    /// Cost of the whole gas limit at `gas_price`, deducted from the
+    /// signer's balance up front.
+    pub reserved: u64,
+
+    /// Nonce advance and reservation.
+    pub forced_writes: BTreeMap<ObjectId, StateObject>,
+}
+
+impl TransactionCharge {
+    /// Fee for consuming `gas_used`, never more than was reserved.
+    pub fn fee(&self, gas_used: u64) -> u64 {
+        gas_used.saturating_mul(self.gas_price).min(self.reserved)
+    }
+}
+
+/// Price per unit of gas a transaction pays at `base_fee`: the base fee
+/// plus as much of the tip as `max_fee` allows.
 ///
-/// This function:
-/// - merges read sets
-/// - merges write intents
-/// - injects forced protocol writes (nonce)
+/// Fails if `max_fee` does not cover the base fee.
+pub fn gas_price(max_fee: u64, priority_fee: u64, base_fee: u64) -> Result<u64, PlanningError> {
+    if max_fee < base_fee {
+        return Err(PlanningError::FeeTooLow { max_fee, base_fee });
+    }
+
+    Ok(base_fee + priority_fee.min(max_fee - base_fee))
+}
+
+/// Compute the nonce advance and fee reservation of a prepared
+/// transaction at `base_fee`.
 ///
-/// It does NOT:
-/// - execute code
-/// - mutate state
-/// - validate ownership
-pub fn build_execution_plan(
-    petx: PreparedExternalTransaction,
+/// The signer must afford its whole gas limit up front; the unused part
+/// is returned once execution reports the gas it consumed.
+///
+/// Fails if the gas limit exceeds `max_gas`, the price is below the base
+/// fee or the signer cannot pay the reservation; such a transaction
+/// cannot be charged and must not be applied at all.
+pub fn charge_transaction(
+    petx: &PreparedExternalTransaction,
     state: &StateStore,
-) -> Result<ExecutionPlan, PlanningError> {
-    let mut merged_read_set: ReadSet = ReadSet::new();
-    let mut merged_write_intents: BTreeMap<ObjectId, WriteIntent> = BTreeMap::new();
+    base_fee: u64,
+    max_gas: u64,
+) -> Result<TransactionCharge, PlanningError> {
+    let gas_limit = petx.tx.gas_limit;
+    if gas_limit > max_gas {
+        return Err(PlanningError::GasLimitTooHigh { limit: gas_limit, max: max_gas });
+    }
+
     let mut forced_writes: BTreeMap<ObjectId, StateObject> = BTreeMap::new();
-    
-    let signer = petx.tx.signer;
-    let balance_id = balance_object_id(signer);
 
     // ---------------------------------------------------------------------
     // 1️⃣ Inject forced nonce write
     // ---------------------------------------------------------------------
-    let (nonce_id, nonce_object) = petx.nonce_update;
+    let (nonce_id, nonce_object) = petx.nonce_update.clone();
     forced_writes.insert(nonce_id, nonce_object);
 
     // ---------------------------------------------------------------------
-    // Inject forced fee deduction
+    // 2️⃣ Inject forced fee reservation
     // ---------------------------------------------------------------------
+    let gas_price = gas_price(petx.tx.max_fee, petx.tx.priority_fee, base_fee)?;
+    let reserved = gas_limit.checked_mul(gas_price)
+        .ok_or(PlanningError::FeeOverflow { gas_limit, gas_price })?;
+
+    let balance_id = balance_object_id(petx.tx.signer);
     let balance_obj = state.get(&balance_id).ok_or(
         PlanningError::ObjectNotFound { object: balance_id },
     )?;
 
     let current_balance = decode_balance(balance_obj);
 
-    if current_balance < BASE_FEE {
+    if current_balance < reserved {
         return Err(PlanningError::InsufficientBalance { 
             available: current_balance, 
-            required: BASE_FEE 
+            required: reserved 
         });
     }
 
-    let new_balance = current_balance - BASE_FEE;
+    let new_balance = current_balance - reserved;
     let updated_balance = balance_obj.next_with_data(encode_balance(new_balance));
     forced_writes.insert(balance_id, updated_balance);
+
+    Ok(TransactionCharge { gas_price, reserved, forced_writes })
+}
+
+/// Build an execution plan from a prepared external transaction.
+///
+/// This function:
+/// - merges read sets
+/// - merges write intents
+/// - injects forced protocol writes (the `charge` of `charge_transaction`)
+///
+/// Writes to objects not owned by the signer are authorized only by a
+/// multisig approval whose policy derives the owner and meets its threshold,
+/// except that other accounts' balance objects may be modified (credited).
+///
+/// It does NOT:
+/// - execute code
+/// - mutate state
+pub fn build_execution_plan(
+    petx: PreparedExternalTransaction,
+    state: &StateStore,
+    charge: TransactionCharge,
+) -> Result<ExecutionPlan, PlanningError> {
+    let mut merged_read_set: ReadSet = ReadSet::new();
+    let mut merged_write_intents: BTreeMap<ObjectId, WriteIntent> = BTreeMap::new();
+
+    let signer = petx.tx.signer;
     
     // ---------------------------------------------------------------------
     // Merge cell read sets
@@ -140,10 +227,26 @@ pub fn build_execution_plan(
     }
 
 
+    // ---------------------------------------------------------------------
+    // Objects being created do not exist yet and cannot be read
+    // ---------------------------------------------------------------------
+    for (object_id, intent) in &merged_write_intents {
+        if *intent == WriteIntent::Create {
+            merged_read_set.remove(object_id);
+        }
+    }
+
     // ---------------------------------------------------------------------
     // OWNERSHIP VALIDATION
     // ---------------------------------------------------------------------
 
+    // Approvals were verified to meet their thresholds during preparation
+    let mut authorized: BTreeSet<Address> = petx.tx.approvals
+        .iter()
+        .map(|approval| approval.policy.address())
+        .collect();
+    authorized.insert(signer);
+
     for (object_id, intent) in &merged_write_intents {
         match intent {
             WriteIntent::Create => {
@@ -158,13 +261,26 @@ pub fn build_execution_plan(
                     PlanningError::ObjectNotFound { object: *object_id },
                 )?;
 
-                if object.owner() != signer {
-                    return Err(PlanningError::UnauthorizedWrite { 
-                        object: *object_id, 
-                        owner: object.owner(), 
-                        signer 
-                    });
+                // Nonces are advanced by the protocol only
+                if is_nonce_object(object) {
+                    return Err(PlanningError::ProtectedObject { object: *object_id });
+                }
+
+                if authorized.contains(&object.owner()) {
+                    continue;
+                }
+
+                // Any account may be credited; the protocol rejects
+                // execution writes that decrease a non-signer balance
+                if *intent == WriteIntent::Modify && is_balance_object(object) {
+                    continue;
                 }
+
+                return Err(PlanningError::UnauthorizedWrite {
+                    object: *object_id,
+                    owner: object.owner(),
+                    signer,
+                });
             }
         }
     }
@@ -173,10 +289,14 @@ pub fn build_execution_plan(
     // 5 Build final execution plan
     // ---------------------------------------------------------------------
     Ok(ExecutionPlan {
+        signer,
+        authorized,
         read_set: merged_read_set,
         write_intents: merged_write_intents,
-        forced_writes,
+        forced_writes: charge.forced_writes,
         cells: petx.tx.cells,
+        gas_price: charge.gas_price,
+        gas_limit: petx.tx.gas_limit,
     })
 }
 
@@ -1,8 +1,25 @@
 use axiom_types::{Slot, Epoch};
 
+use crate::gas::GasMeter;
+
 /// Immutable execution context provided by the protocol.
 #[derive(Debug, Clone, Copy)]
 pub struct ExecutionContext {
     pub slot: Slot,
     pub epoch: Epoch,
+
+    /// Base fee of the block being executed.
+    pub base_fee: u64,
+
+    /// Meter for the transaction being executed.
+    ///
+    /// Set by the protocol from the transaction's declared gas limit.
+    pub gas: GasMeter,
+}
+
+impl ExecutionContext {
+    /// Create the context of a block, with an empty gas meter.
+    pub fn new(slot: Slot, epoch: Epoch, base_fee: u64) -> Self {
+        Self { slot, epoch, base_fee, gas: GasMeter::default() }
+    }
 }
@@ -1,7 +1,7 @@
 use std::collections::BTreeMap;
 
 use axiom_types::ObjectId;
-use axiom_state::{StateObject, StateStore};
+use axiom_state::{HistoricalState, ObjectWrite, StateObject, StateStore, Version};
 use axiom_execution::ExecutionPlan;
 
 use crate::context::ExecutionContext;
@@ -18,12 +18,31 @@ pub trait StateView {
     /// - `Some(&StateObject)` if the object exists
     /// - `None` if the object does not exist
     fn get_object(&self, id: &ObjectId) -> Option<&StateObject>;
+
+    /// Fetch the tombstone version of a deleted object.
+    ///
+    /// Objects re-created at a deleted id MUST continue after it.
+    fn get_tombstone(&self, id: &ObjectId) -> Option<Version>;
 }
 
 impl StateView for StateStore {
     fn get_object(&self, id: &ObjectId) -> Option<&StateObject> {
         self.get(id)
     }
+
+    fn get_tombstone(&self, id: &ObjectId) -> Option<Version> {
+        self.tombstone(id)
+    }
+}
+
+impl StateView for HistoricalState<'_> {
+    fn get_object(&self, id: &ObjectId) -> Option<&StateObject> {
+        self.get(id)
+    }
+
+    fn get_tombstone(&self, id: &ObjectId) -> Option<Version> {
+        self.tombstone(id)
+    }
 }
 
 /// Result of executing an execution plan.
@@ -34,12 +53,15 @@ impl StateView for StateStore {
 /// - validated again before commit
 #[derive(Debug)]
 pub struct ExecutionOutcome {
-    /// Objects written by execution logic.
+    /// Objects written or deleted by execution logic.
     ///
     /// These MUST:
     /// - correspond to declared write intents
     /// - increment versions correctly
-    pub writes: BTreeMap<ObjectId, StateObject>,
+    pub writes: BTreeMap<ObjectId, ObjectWrite>,
+
+    /// Gas consumed, as metered by `ExecutionContext::gas`.
+    pub gas_used: u64,
 }
 
 /// VM-agnostic execution engine interface.
@@ -58,6 +80,8 @@ pub trait ExecutionEngine {
     /// - No state mutation occurs here
     /// - No undeclared reads or writes are allowed
     /// - Deterministic for identical inputs
+    /// - Work is charged to `context.gas`, failing with
+    ///   `ExecutionError::OutOfGas` once its limit is reached
     ///
     /// # Errors
     /// Any error MUST be deterministic and consensus-safe.
@@ -1,4 +1,20 @@
-use axiom_types::ObjectId;
+use axiom_types::{Address, ObjectId};
+
+/// Errors specific to native balance transfers.
+#[derive(Debug)]
+pub enum TransferError {
+    /// Transfer source is not the balance object of an authorized address.
+    InvalidSource { object: ObjectId },
+
+    /// Source account attempted to transfer to itself.
+    SelfTransfer,
+
+    /// Signer balance cannot cover the amount.
+    InsufficientFunds { available: u64, required: u64 },
+
+    /// Recipient balance would exceed `u64::MAX`.
+    BalanceOverflow { recipient: Address },
+}
 
 #[derive(Debug)]
 pub enum ExecutionError {
@@ -8,6 +24,24 @@ pub enum ExecutionError {
     /// Execution attempted to write an undeclared object.
     UnauthorizedWrite { object: ObjectId },
 
+    /// Execution attempted to write the same object twice.
+    DuplicateWrite { object: ObjectId },
+
+    /// Call data selector does not name a known operation.
+    UnknownSelector { selector: Vec<u8> },
+
+    /// Call data payload is malformed for its selector.
+    InvalidPayload { selector: Vec<u8> },
+
+    /// Execution consumed more than the transaction's gas limit.
+    OutOfGas { limit: u64 },
+
+    /// Engine reported more gas used than the transaction's gas limit.
+    GasOverrun { used: u64, limit: u64 },
+
+    /// Native balance transfer failed.
+    Transfer(TransferError),
+
     /// Execution logic failed deterministically.
     ExecutionFailed { reason: String },
 }
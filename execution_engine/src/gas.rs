@@ -0,0 +1,114 @@
+use axiom_state::{ObjectWrite, ProtocolParameters, StateObject};
+
+use crate::error::ExecutionError;
+
+/// Gas cost of each metered operation.
+///
+/// Every node on a chain must use the same schedule, so it is part of
+/// the chain configuration rather than chosen by an engine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GasSchedule {
+    /// Dispatching one cell's call data.
+    pub call: u64,
+
+    /// Reading one object.
+    pub read: u64,
+
+    /// Writing or deleting one object.
+    pub write: u64,
+
+    /// Each byte of object data read or written.
+    pub byte: u64,
+}
+
+impl GasSchedule {
+    /// Schedule recorded in genesis `parameters`.
+    pub fn from_parameters(parameters: &ProtocolParameters) -> Self {
+        Self {
+            call: parameters.gas_call,
+            read: parameters.gas_read,
+            write: parameters.gas_write,
+            byte: parameters.gas_byte,
+        }
+    }
+}
+
+impl Default for GasSchedule {
+    fn default() -> Self {
+        Self {
+            call: 500,
+            read: 200,
+            write: 1_000,
+            byte: 10,
+        }
+    }
+}
+
+/// Tracks the gas consumed by one transaction against its limit.
+///
+/// Every charge either fits in the remaining gas or fails with
+/// `ExecutionError::OutOfGas`, after which the meter is exhausted.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GasMeter {
+    limit: u64,
+    used: u64,
+    schedule: GasSchedule,
+}
+
+impl GasMeter {
+    /// Create a meter allowing `limit` gas, priced by `schedule`.
+    pub fn new(limit: u64, schedule: GasSchedule) -> Self {
+        Self { limit, used: 0, schedule }
+    }
+
+    /// Return the gas limit.
+    pub fn limit(&self) -> u64 {
+        self.limit
+    }
+
+    /// Return the gas consumed so far.
+    pub fn used(&self) -> u64 {
+        self.used
+    }
+
+    /// Return the gas still available.
+    pub fn remaining(&self) -> u64 {
+        self.limit - self.used
+    }
+
+    /// Return the schedule operations are priced by.
+    pub fn schedule(&self) -> &GasSchedule {
+        &self.schedule
+    }
+
+    /// Consume `amount` gas.
+    pub fn charge(&mut self, amount: u64) -> Result<(), ExecutionError> {
+        if amount > self.remaining() {
+            self.used = self.limit;
+            return Err(ExecutionError::OutOfGas { limit: self.limit });
+        }
+
+        self.used += amount;
+        Ok(())
+    }
+
+    /// Charge for dispatching one cell's call data.
+    pub fn charge_call(&mut self) -> Result<(), ExecutionError> {
+        self.charge(self.schedule.call)
+    }
+
+    /// Charge for reading `object` and its data.
+    pub fn charge_read(&mut self, object: &StateObject) -> Result<(), ExecutionError> {
+        self.charge(self.schedule.read.saturating_add(self.data_cost(object.data().len())))
+    }
+
+    /// Charge for applying `write` and the data it stores.
+    pub fn charge_write(&mut self, write: &ObjectWrite) -> Result<(), ExecutionError> {
+        let bytes = write.object().map_or(0, |object| object.data().len());
+        self.charge(self.schedule.write.saturating_add(self.data_cost(bytes)))
+    }
+
+    fn data_cost(&self, bytes: usize) -> u64 {
+        self.schedule.byte.saturating_mul(bytes as u64)
+    }
+}
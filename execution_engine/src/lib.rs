@@ -12,9 +12,13 @@
 pub mod context;
 pub mod engine;
 pub mod error;
+pub mod gas;
 pub mod reference;
+pub mod native;
 
 pub use context::ExecutionContext;
 pub use engine::{ExecutionEngine, ExecutionOutcome, StateView};
-pub use error::ExecutionError;
-pub use reference::ReferenceExecutionEngine;
\ No newline at end of file
+pub use error::{ExecutionError, TransferError};
+pub use gas::{GasMeter, GasSchedule};
+pub use reference::ReferenceExecutionEngine;
+pub use native::NativeOperation;
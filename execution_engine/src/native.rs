@@ -0,0 +1,172 @@
+use axiom_tx::CallData;
+use axiom_types::{Address, Hash, ObjectId};
+
+use crate::error::ExecutionError;
+
+/// Reserved domain for ids of objects created by native operations.
+const CREATED_OBJECT_DOMAIN: &[u8] = b"axiom::object";
+
+/// Length (in bytes) of the salt prefixing a create payload.
+pub const CREATE_SALT_LENGTH: usize = 32;
+
+/// Length (in bytes) of a transfer payload.
+pub const TRANSFER_PAYLOAD_LENGTH: usize = 40;
+
+/// Selector for creating `target` with the payload's data.
+///
+/// The payload is a 32-byte salt followed by the object data, and
+/// `target` MUST equal `created_object_id(signer, salt)`. Deriving the
+/// id from the creator keeps users from squatting protocol-reserved ids.
+pub const SELECTOR_CREATE: &[u8] = b"axiom::create";
+
+/// Selector for overwriting the data of `target` with `payload`.
+pub const SELECTOR_WRITE: &[u8] = b"axiom::write";
+
+/// Selector for deleting `target`.
+pub const SELECTOR_DELETE: &[u8] = b"axiom::delete";
+
+/// Selector for transferring ownership of `target`.
+///
+/// The payload is the 32-byte address of the new owner.
+pub const SELECTOR_TRANSFER_OWNERSHIP: &[u8] = b"axiom::transfer_ownership";
+
+/// Selector for transferring balance to a recipient.
+///
+/// `target` is the balance object of the signer or of a multisig
+/// address the transaction carries a sufficient approval for. The payload is the 32-byte
+/// recipient address followed by the amount as a little-endian u64
+/// (see `encode_transfer_payload`).
+pub const SELECTOR_TRANSFER: &[u8] = b"axiom::transfer";
+
+/// Deterministically derive the id of an object created by `creator`.
+pub fn created_object_id(creator: Address, salt: &[u8; CREATE_SALT_LENGTH]) -> ObjectId {
+    let mut bytes = Vec::new();
+
+    // domain separation (protocol rule)
+    bytes.extend_from_slice(CREATED_OBJECT_DOMAIN);
+
+    // bind to creator and salt
+    bytes.extend_from_slice(creator.as_bytes());
+    bytes.extend_from_slice(salt);
+
+    ObjectId::new(Hash::new(blake3::hash(&bytes).into()))
+}
+
+/// Encode a create payload.
+pub fn encode_create_payload(salt: &[u8; CREATE_SALT_LENGTH], data: &[u8]) -> Vec<u8> {
+    let mut payload = Vec::with_capacity(CREATE_SALT_LENGTH + data.len());
+    payload.extend_from_slice(salt);
+    payload.extend_from_slice(data);
+    payload
+}
+
+/// Encode a transfer payload.
+pub fn encode_transfer_payload(recipient: Address, amount: u64) -> Vec<u8> {
+    let mut payload = Vec::with_capacity(TRANSFER_PAYLOAD_LENGTH);
+    payload.extend_from_slice(recipient.as_bytes());
+    payload.extend_from_slice(&amount.to_le_bytes());
+    payload
+}
+
+/// A native operation decoded from call data.
+///
+/// An empty selector is a no-op: the cell only declares reads.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NativeOperation {
+    /// Do nothing.
+    Noop,
+
+    /// Create a new object owned by the signer.
+    Create { target: ObjectId, salt: [u8; CREATE_SALT_LENGTH], data: Vec<u8> },
+
+    /// Replace the data of an existing object.
+    Write { target: ObjectId, data: Vec<u8> },
+
+    /// Remove an existing object.
+    Delete { target: ObjectId },
+
+    /// Change the owner of an existing object.
+    TransferOwnership { target: ObjectId, new_owner: Address },
+
+    /// Move `amount` from the authorized balance `source` to `recipient`.
+    Transfer { source: ObjectId, recipient: Address, amount: u64 },
+}
+
+impl NativeOperation {
+    /// Decode call data into a native operation.
+    ///
+    /// Unknown selectors and malformed payloads are deterministic failures.
+    pub fn decode(call: &CallData) -> Result<Self, ExecutionError> {
+        let target = call.target;
+
+        let invalid = || ExecutionError::InvalidPayload {
+            selector: call.selector.clone(),
+        };
+
+        match call.selector.as_slice() {
+            [] => Ok(Self::Noop),
+
+            SELECTOR_CREATE => {
+                if call.payload.len() < CREATE_SALT_LENGTH {
+                    return Err(invalid());
+                }
+
+                let (salt, data) = call.payload.split_at(CREATE_SALT_LENGTH);
+
+                Ok(Self::Create {
+                    target,
+                    salt: salt.try_into().map_err(|_| invalid())?,
+                    data: data.to_vec(),
+                })
+            }
+
+            SELECTOR_WRITE => Ok(Self::Write {
+                target,
+                data: call.payload.clone(),
+            }),
+
+            SELECTOR_DELETE => {
+                if !call.payload.is_empty() {
+                    return Err(invalid());
+                }
+                Ok(Self::Delete { target })
+            }
+
+            SELECTOR_TRANSFER_OWNERSHIP => {
+                let bytes: [u8; 32] = call.payload.as_slice()
+                    .try_into()
+                    .map_err(|_| invalid())?;
+
+                // The zero address MUST NOT own objects
+                let new_owner = Address::new(bytes);
+                if new_owner == Address::zero() {
+                    return Err(invalid());
+                }
+
+                Ok(Self::TransferOwnership { target, new_owner })
+            }
+
+            SELECTOR_TRANSFER => {
+                if call.payload.len() != TRANSFER_PAYLOAD_LENGTH {
+                    return Err(invalid());
+                }
+
+                let (recipient, amount) = call.payload.split_at(32);
+
+                let recipient = Address::new(recipient.try_into().map_err(|_| invalid())?);
+                let amount = u64::from_le_bytes(amount.try_into().map_err(|_| invalid())?);
+
+                // The zero address MUST NOT hold balances
+                if recipient == Address::zero() {
+                    return Err(invalid());
+                }
+
+                Ok(Self::Transfer { source: target, recipient, amount })
+            }
+
+            _ => Err(ExecutionError::UnknownSelector {
+                selector: call.selector.clone(),
+            }),
+        }
+    }
+}
@@ -1,40 +1,178 @@
 use std::collections::BTreeMap;
 
 use axiom_execution::ExecutionPlan;
-// use axiom_state::StateObject;
-// use axiom_types::ObjectId;
+use axiom_state::{
+    ObjectWrite,
+    StateObject,
+    balance_object_id,
+    decode_balance,
+    encode_balance,
+    is_balance_object,
+    is_nonce_object,
+};
+use axiom_tx::WriteIntent;
+use axiom_types::{Address, ObjectId};
 
+use crate::native::created_object_id;
 use crate::{
     ExecutionContext,
     ExecutionEngine,
     ExecutionOutcome,
     ExecutionError,
+    NativeOperation,
     StateView,
+    TransferError,
 };
 
 /// Reference execution engine.
 ///
-/// This engine performs NO computation.
-/// It only validates that execution respects declared constraints.
+/// This engine interprets call data as a fixed set of native
+/// operations (see `NativeOperation`) and validates that every
+/// write respects the declared constraints.
+///
+/// Gas is charged for each declared read, each dispatched cell and
+/// each write, plus the object data read or written.
 #[derive(Debug, Default)]
 pub struct ReferenceExecutionEngine;
 
+/// Ensure `object` was declared with exactly `expected` intent.
+fn require_intent(
+    plan: &ExecutionPlan,
+    object: ObjectId,
+    expected: WriteIntent,
+) -> Result<(), ExecutionError> {
+    match plan.write_intents.get(&object) {
+        Some(intent) if *intent == expected => Ok(()),
+        _ => Err(ExecutionError::UnauthorizedWrite { object }),
+    }
+}
+
+/// Fetch an existing object that the plan declared as read.
+///
+/// Forced protocol writes (e.g. the fee deduction) are visible, so
+/// execution always builds on top of them.
+fn read_declared<'a>(
+    plan: &'a ExecutionPlan,
+    state: &'a dyn StateView,
+    object: ObjectId,
+) -> Result<&'a StateObject, ExecutionError> {
+    if !plan.read_set.contains_key(&object) {
+        return Err(ExecutionError::UnauthorizedRead { object });
+    }
+
+    plan.forced_writes
+        .get(&object)
+        .or_else(|| state.get_object(&object))
+        .ok_or(ExecutionError::UnauthorizedRead { object })
+}
+
+/// Fetch an existing object that generic operations may touch.
+///
+/// Balance and nonce objects are protocol state and can only be
+/// changed through dedicated operations.
+fn read_user_object<'a>(
+    plan: &'a ExecutionPlan,
+    state: &'a dyn StateView,
+    object: ObjectId,
+) -> Result<&'a StateObject, ExecutionError> {
+    let existing = read_declared(plan, state, object)?;
+
+    if is_balance_object(existing) || is_nonce_object(existing) {
+        return Err(ExecutionError::UnauthorizedWrite { object });
+    }
+
+    Ok(existing)
+}
+
+/// Build a new object, continuing after the tombstone of a deleted id.
+fn new_object(
+    state: &dyn StateView,
+    id: ObjectId,
+    owner: Address,
+    data: Vec<u8>,
+) -> StateObject {
+    match state.get_tombstone(&id) {
+        Some(tombstone) => StateObject::recreate(id, owner, data, tombstone),
+        None => StateObject::new(id, owner, data),
+    }
+}
+
+/// Execute a balance transfer, returning the debited and credited objects.
+fn transfer(
+    plan: &ExecutionPlan,
+    state: &dyn StateView,
+    source: ObjectId,
+    recipient: Address,
+    amount: u64,
+) -> Result<[StateObject; 2], ExecutionError> {
+    // Debit an account the transaction acts for
+    require_intent(plan, source, WriteIntent::Modify)?;
+    let sender = read_declared(plan, state, source)?;
+
+    if !is_balance_object(sender) || !plan.authorized.contains(&sender.owner()) {
+        return Err(ExecutionError::Transfer(TransferError::InvalidSource { object: source }));
+    }
+
+    if recipient == sender.owner() {
+        return Err(ExecutionError::Transfer(TransferError::SelfTransfer));
+    }
+
+    let available = decode_balance(sender);
+
+    let remaining = available.checked_sub(amount).ok_or(
+        ExecutionError::Transfer(TransferError::InsufficientFunds {
+            available,
+            required: amount,
+        }),
+    )?;
+
+    let debited = sender.next_with_data(encode_balance(remaining));
+
+    // Credit recipient, creating their balance object if needed
+    let recipient_id = balance_object_id(recipient);
+
+    let credited = match plan.write_intents.get(&recipient_id) {
+        Some(WriteIntent::Create) => {
+            new_object(state, recipient_id, recipient, encode_balance(amount))
+        }
+        Some(WriteIntent::Modify) => {
+            let existing = read_declared(plan, state, recipient_id)?;
+
+            if !is_balance_object(existing) {
+                return Err(ExecutionError::UnauthorizedWrite { object: recipient_id });
+            }
+
+            let credited = decode_balance(existing).checked_add(amount).ok_or(
+                ExecutionError::Transfer(TransferError::BalanceOverflow { recipient }),
+            )?;
+
+            existing.next_with_data(encode_balance(credited))
+        }
+        _ => return Err(ExecutionError::UnauthorizedWrite { object: recipient_id }),
+    };
+
+    Ok([debited, credited])
+}
+
 impl ExecutionEngine for ReferenceExecutionEngine {
     fn execute(
         &self,
         plan: &ExecutionPlan,
         state: &dyn StateView,
-        _context: ExecutionContext,
+        context: ExecutionContext,
     ) -> Result<ExecutionOutcome, ExecutionError> {
+        let mut gas = context.gas;
+
         // -------------------------------------------------------------
         // 1️⃣ Validate all declared reads are accessible
         // -------------------------------------------------------------
-        for (object_id, _) in &plan.read_set {
-            if state.get_object(object_id).is_none() {
+        for object_id in plan.read_set.keys() {
+            let Some(object) = state.get_object(object_id) else {
                 return Err(ExecutionError::UnauthorizedRead {
                     object: *object_id,
                 });
-            }
+            };
+            gas.charge_read(object)?;
         }
 
         // -------------------------------------------------------------
@@ -43,7 +181,7 @@ impl ExecutionEngine for ReferenceExecutionEngine {
         for (object_id, intent) in &plan.write_intents {
             match intent {
                 // Create: object must NOT exist yet
-                axiom_tx::WriteIntent::Create => {
+                WriteIntent::Create => {
                     if state.get_object(object_id).is_some() {
                         return Err(ExecutionError::UnauthorizedWrite {
                             object: *object_id,
@@ -52,7 +190,7 @@ impl ExecutionEngine for ReferenceExecutionEngine {
                 }
 
                 // Modify/Delete: object MUST exist
-                axiom_tx::WriteIntent::Modify | axiom_tx::WriteIntent::Delete => {
+                WriteIntent::Modify | WriteIntent::Delete => {
                     if state.get_object(object_id).is_none() {
                         return Err(ExecutionError::UnauthorizedWrite {
                             object: *object_id,
@@ -63,10 +201,61 @@ impl ExecutionEngine for ReferenceExecutionEngine {
         }
 
         // -------------------------------------------------------------
-        // 3️⃣ Produce empty execution writes
+        // 3️⃣ Interpret each cell's call data in declared order
         // -------------------------------------------------------------
-        Ok(ExecutionOutcome {
-            writes: BTreeMap::new(),
-        })
+        let mut writes: BTreeMap<ObjectId, ObjectWrite> = BTreeMap::new();
+
+        for cell in &plan.cells {
+            gas.charge_call()?;
+            let operation = NativeOperation::decode(cell.call())?;
+
+            let written: Vec<ObjectWrite> = match operation {
+                NativeOperation::Noop => continue,
+
+                NativeOperation::Create { target, salt, data } => {
+                    if target != created_object_id(plan.signer, &salt) {
+                        return Err(ExecutionError::UnauthorizedWrite { object: target });
+                    }
+                    require_intent(plan, target, WriteIntent::Create)?;
+                    vec![ObjectWrite::Put(new_object(state, target, plan.signer, data))]
+                }
+
+                NativeOperation::Write { target, data } => {
+                    require_intent(plan, target, WriteIntent::Modify)?;
+                    let existing = read_user_object(plan, state, target)?;
+                    vec![ObjectWrite::Put(existing.next_with_data(data))]
+                }
+
+                NativeOperation::TransferOwnership { target, new_owner } => {
+                    require_intent(plan, target, WriteIntent::Modify)?;
+                    let existing = read_user_object(plan, state, target)?;
+                    vec![ObjectWrite::Put(existing.next_with_owner(new_owner))]
+                }
+
+                NativeOperation::Delete { target } => {
+                    require_intent(plan, target, WriteIntent::Delete)?;
+                    let existing = read_user_object(plan, state, target)?;
+                    vec![ObjectWrite::delete(existing)]
+                }
+
+                NativeOperation::Transfer { source, recipient, amount } => {
+                    transfer(plan, state, source, recipient, amount)?
+                        .map(ObjectWrite::Put)
+                        .into()
+                }
+            };
+
+            // Each object is written at most once per transaction,
+            // so every write is exactly one version ahead of what it read
+            for write in written {
+                gas.charge_write(&write)?;
+                let object_id = write.id();
+                if writes.insert(object_id, write).is_some() {
+                    return Err(ExecutionError::DuplicateWrite { object: object_id });
+                }
+            }
+        }
+
+        Ok(ExecutionOutcome { writes, gas_used: gas.used() })
     }
 }
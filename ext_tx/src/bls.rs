@@ -0,0 +1,59 @@
+
+/// Imports and dependencies
+use blst::BLST_ERROR;
+use blst::min_pk::{PublicKey, Signature};
+
+use crate::error::SignatureError;
+use crate::scheme::{SchemeId, SignatureScheme};
+
+// -------------------------------------------------------------------------------------------------------------------------- //
+
+//----------------------------------------------- BLS12-381 -----------------------------------------------//
+
+/// Length (in bytes) of a compressed BLS12-381 G1 public key.
+pub const BLS_PUBLIC_KEY_LENGTH: usize = 48;
+
+/// Length (in bytes) of a compressed BLS12-381 G2 signature.
+pub const BLS_SIGNATURE_LENGTH: usize = 96;
+
+/// Domain separation tag for BLS signatures (basic scheme, hash-to-G2).
+pub const BLS_DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_NUL_";
+
+/// BLS signatures over BLS12-381 (minimal public key size variant).
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Bls12381Scheme;
+
+impl SignatureScheme for Bls12381Scheme {
+    fn id(&self) -> SchemeId {
+        SchemeId::Bls12381
+    }
+
+    fn verify(
+        &self,
+        public_key: &[u8],
+        message: &[u8],
+        signature: &[u8],
+    ) -> Result<(), SignatureError> {
+        if public_key.len() != BLS_PUBLIC_KEY_LENGTH {
+            return Err(SignatureError::InvalidPublicKey);
+        }
+
+        // Rejects points at infinity and points outside the subgroup
+        let public_key = PublicKey::key_validate(public_key)
+            .map_err(|_| SignatureError::InvalidPublicKey)?;
+
+        if signature.len() != BLS_SIGNATURE_LENGTH {
+            return Err(SignatureError::MalformedSignature);
+        }
+
+        let signature = Signature::sig_validate(signature, true)
+            .map_err(|_| SignatureError::MalformedSignature)?;
+
+        match signature.verify(true, message, BLS_DST, &[], &public_key, false) {
+            BLST_ERROR::BLST_SUCCESS => Ok(()),
+            _ => Err(SignatureError::VerificationFailed),
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------- //
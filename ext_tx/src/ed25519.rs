@@ -0,0 +1,51 @@
+
+/// Imports and dependencies
+use ed25519_dalek::{Signature, VerifyingKey};
+
+use crate::error::SignatureError;
+use crate::scheme::{SchemeId, SignatureScheme};
+
+// -------------------------------------------------------------------------------------------------------------------------- //
+
+//----------------------------------------------- Ed25519 -----------------------------------------------//
+
+/// Length (in bytes) of an Ed25519 public key.
+pub const ED25519_PUBLIC_KEY_LENGTH: usize = 32;
+
+/// Length (in bytes) of an Ed25519 signature.
+pub const ED25519_SIGNATURE_LENGTH: usize = 64;
+
+/// Ed25519 signature scheme.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Ed25519Scheme;
+
+impl SignatureScheme for Ed25519Scheme {
+    fn id(&self) -> SchemeId {
+        SchemeId::Ed25519
+    }
+
+    fn verify(
+        &self,
+        public_key: &[u8],
+        message: &[u8],
+        signature: &[u8],
+    ) -> Result<(), SignatureError> {
+        let public_key: [u8; ED25519_PUBLIC_KEY_LENGTH] = public_key
+            .try_into()
+            .map_err(|_| SignatureError::InvalidPublicKey)?;
+
+        let verifying_key = VerifyingKey::from_bytes(&public_key)
+            .map_err(|_| SignatureError::InvalidPublicKey)?;
+
+        let signature: [u8; ED25519_SIGNATURE_LENGTH] = signature
+            .try_into()
+            .map_err(|_| SignatureError::MalformedSignature)?;
+
+        // Strict verification rejects small-order keys and malleable signatures
+        verifying_key
+            .verify_strict(message, &Signature::from_bytes(&signature))
+            .map_err(|_| SignatureError::VerificationFailed)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------- //
@@ -0,0 +1,66 @@
+/// Imports and dependencies
+use axiom_state::NonceError;
+use axiom_types::Address;
+
+use crate::scheme::SchemeId;
+
+// -------------------------------------------------------------------------------------------------------------------------- //
+
+/// Errors that can occur during signature verification.
+#[derive(Debug)]
+pub enum SignatureError {
+    /// Signature scheme is not accepted by the registry.
+    UnsupportedScheme {
+        scheme: SchemeId,
+    },
+
+    /// Public key has the wrong length or is not a valid point.
+    InvalidPublicKey,
+
+    /// Public key does not derive the declared signer address.
+    SignerMismatch {
+        signer: Address,
+        derived: Address,
+    },
+
+    /// Signature bytes have the wrong length.
+    MalformedSignature,
+
+    /// Signature does not verify over the signing hash.
+    VerificationFailed,
+
+    /// Multisig threshold is zero, exceeds the member count,
+    /// or the member set is too large.
+    InvalidMultisigPolicy,
+
+    /// Approval signature is not from a member of the policy.
+    NotAMember {
+        member: Address,
+    },
+
+    /// The same member signed an approval more than once.
+    DuplicateApproval {
+        member: Address,
+    },
+
+    /// Approval carries fewer member signatures than its threshold.
+    ThresholdNotMet {
+        policy: Address,
+        required: u32,
+        provided: u32,
+    },
+}
+
+// -------------------------------------------------------------------------------------------------------------------------- //
+
+/// Errors returned while preparing an external transaction.
+#[derive(Debug)]
+pub enum PrepareError {
+    /// Signature verification failed
+    SignatureError(SignatureError),
+
+    /// Nonce validation failed
+    NonceError(NonceError),
+}
+
+// -------------------------------------------------------------------------------------------------------------------------- //
@@ -1,46 +1,118 @@
 //! Define external transactions
 
 /// Module for transaction-related types and utilities.
+use std::collections::BTreeSet;
+
 use axiom_tx::TransactionCell;
-use axiom_types::{Address, Hash};
+use axiom_types::{Address, Hash, Slot};
 use axiom_state::{Version};
 
+use crate::scheme::SchemeId;
+use crate::multisig::MultisigApproval;
+
 // -------------------------------------------------------------------------------------------------------------------------- //
 
 //----------------------------------------------- External Transactions -----------------------------------------------//
 
-/// Opaque transaction signature.
+/// Transaction signature.
 ///
-/// NOTE: Cryptographic verification is handled later.
-/// For now, this is just a typed container.
+/// Carries the scheme tag and the signer's public key so the protocol
+/// can check that they derive `ExternalTransaction::signer` before
+/// verifying `bytes` with the matching `SignatureScheme`.
 #[derive(Clone, Debug)]
 pub struct Signature {
+    /// Algorithm the signature was produced with.
+    pub scheme: SchemeId,
+
+    /// Public key of the signer.
+    pub public_key: Vec<u8>,
+
+    /// Signature over `ExternalTransaction::signing_hash()`.
     pub bytes: Vec<u8>,
 }
 
+/// Inclusive range of slots in which a transaction may execute.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ValidityWindow {
+    /// First slot the transaction is valid in.
+    pub from: Slot,
+
+    /// Last slot the transaction is valid in.
+    pub until: Slot,
+}
+
+impl ValidityWindow {
+    /// Create a window valid from `from` through `until`.
+    pub const fn new(from: Slot, until: Slot) -> Self {
+        Self { from, until }
+    }
+
+    /// Window valid in every slot.
+    pub const fn unbounded() -> Self {
+        Self::new(Slot::new(0), Slot::new(u64::MAX))
+    }
+
+    /// Returns true if `slot` falls inside the window.
+    pub fn contains(&self, slot: Slot) -> bool {
+        self.from <= slot && slot <= self.until
+    }
+}
+
 /// External transaction submitted by users.
 #[derive(Debug, Clone)]
 pub struct ExternalTransaction {
+    /// Chain the transaction is valid on.
+    pub chain_id: u64,
+
     /// Address authorizing the transaction.
     pub signer: Address,
 
     /// Nonce to prevent replay attacks.
     pub nonce: Version,
 
+    /// Slots in which the transaction may be included.
+    pub validity: ValidityWindow,
+
+    /// Most the signer will pay per unit of gas (base fee plus tip).
+    pub max_fee: u64,
+
+    /// Tip per unit of gas offered to the block proposer on top of the
+    /// base fee.
+    pub priority_fee: u64,
+
+    /// Most gas execution of the cells may consume.
+    pub gas_limit: u64,
+
     /// Cells involved in the transaction.
     pub cells: Vec<TransactionCell>,
 
     /// Signature verifying the transaction's authenticity.
     pub signature: Signature,
+
+    /// Multisig approvals authorizing writes to multisig-owned objects.
+    ///
+    /// Their policies are signed by the signer; their member signatures are not.
+    pub approvals: Vec<MultisigApproval>,
 }
 
 impl ExternalTransaction {
-    /// Compute the hash of the transaction payload to be signed
+    /// Compute the hash of the transaction payload to be signed.
+    ///
+    /// Commits to every field except the signatures (which sign this hash):
+    /// chain id, signer, nonce, validity window, fee limits, gas limit, each
+    /// cell's full canonical encoding in declared (execution) order, and the
+    /// addresses of the policies whose approvals the transaction carries.
+    ///
+    /// Committing the policies keeps a relayer from stripping approvals
+    /// to make a signed transaction fail and still pay its fee.
     pub fn signing_hash(&self) -> Hash {
         let mut bytes = Vec::new();
         
         // Domain separator
-        bytes.extend_from_slice(b"Axiom::ExternalTransaction::v1");
+        bytes.extend_from_slice(b"Axiom::ExternalTransaction::v8");
+
+        // Chain id (replay protection across networks)
+        bytes.extend_from_slice(&self.chain_id.to_le_bytes());
 
         // Signer
         bytes.extend_from_slice(self.signer.as_bytes());
@@ -48,12 +120,34 @@ impl ExternalTransaction {
         // Nonce
         bytes.extend_from_slice(&self.nonce.to_le_bytes());
 
-        // Cells
-        let mut cell_ids: Vec<_> = self.cells.iter().map(|c| c.id()).collect();
-        cell_ids.sort();
+        // Validity window
+        bytes.extend_from_slice(&self.validity.from.value().to_le_bytes());
+        bytes.extend_from_slice(&self.validity.until.value().to_le_bytes());
+
+        // Fees
+        bytes.extend_from_slice(&self.max_fee.to_le_bytes());
+        bytes.extend_from_slice(&self.priority_fee.to_le_bytes());
+
+        // Gas limit
+        bytes.extend_from_slice(&self.gas_limit.to_le_bytes());
+
+        // Cells (declared order; each encoding is self-delimiting)
+        bytes.extend_from_slice(&(self.cells.len() as u32).to_le_bytes());
+
+        for cell in &self.cells {
+            bytes.extend_from_slice(&cell.encode());
+        }
+
+        // Approving policies (sorted and deduplicated)
+        let policies: BTreeSet<Address> = self.approvals
+            .iter()
+            .map(|approval| approval.policy.address())
+            .collect();
+
+        bytes.extend_from_slice(&(policies.len() as u32).to_le_bytes());
 
-        for id in cell_ids {
-            bytes.extend_from_slice(id.as_bytes());
+        for policy in &policies {
+            bytes.extend_from_slice(policy.as_bytes());
         }
 
         // Compute and return the hash
@@ -2,6 +2,20 @@
 
 pub mod external_tx;
 pub mod prepare;
+pub mod signature;
+pub mod scheme;
+pub mod ed25519;
+pub mod secp256k1;
+pub mod bls;
+pub mod multisig;
+pub mod error;
 
-pub use external_tx::{ExternalTransaction, Signature};
+pub use external_tx::{ExternalTransaction, Signature, ValidityWindow};
 pub use prepare::{PreparedExternalTransaction, prepare_external_transaction};
+pub use signature::{address_from_public_key, verify_transaction_signature};
+pub use scheme::{SchemeId, SignatureScheme, SchemeRegistry};
+pub use ed25519::Ed25519Scheme;
+pub use secp256k1::Secp256k1Scheme;
+pub use bls::Bls12381Scheme;
+pub use multisig::{MultisigPolicy, MultisigApproval, verify_multisig_approvals};
+pub use error::{SignatureError, PrepareError};
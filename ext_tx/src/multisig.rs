@@ -0,0 +1,173 @@
+
+/// Imports and dependencies
+use std::collections::BTreeSet;
+
+use axiom_types::Address;
+
+use crate::error::SignatureError;
+use crate::external_tx::{ExternalTransaction, Signature};
+use crate::scheme::SchemeRegistry;
+use crate::signature::address_from_public_key;
+
+// -------------------------------------------------------------------------------------------------------------------------- //
+
+//----------------------------------------------- Multisig Policy -----------------------------------------------//
+
+/// Maximum number of members in a multisig policy.
+///
+/// Bounds the signature verification work a single approval can demand.
+pub const MAX_MULTISIG_MEMBERS: usize = 32;
+
+/// Reserved domain for deriving multisig addresses.
+const MULTISIG_ADDRESS_DOMAIN: &[u8] = b"axiom::address::multisig";
+
+/// A k-of-n multisig policy.
+///
+/// The policy is self-certifying: its `Address` is derived from the
+/// threshold and the canonical (sorted, deduplicated) member set, so
+/// no state lookup is needed to check that a policy controls an address.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MultisigPolicy {
+    threshold: u32,
+    members: Vec<Address>,
+}
+
+impl MultisigPolicy {
+    /// Create a new policy requiring `threshold` of `members`.
+    ///
+    /// Members are sorted and deduplicated. Fails if the threshold is
+    /// zero or exceeds the number of distinct members, or if there are
+    /// more than `MAX_MULTISIG_MEMBERS` members.
+    pub fn new(threshold: u32, members: Vec<Address>) -> Result<Self, SignatureError> {
+        let members: Vec<Address> = members
+            .into_iter()
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+
+        if threshold == 0
+            || threshold as usize > members.len()
+            || members.len() > MAX_MULTISIG_MEMBERS
+        {
+            return Err(SignatureError::InvalidMultisigPolicy);
+        }
+
+        Ok(Self { threshold, members })
+    }
+
+    /// Returns the number of member approvals required.
+    pub fn threshold(&self) -> u32 {
+        self.threshold
+    }
+
+    /// Returns the members in canonical order.
+    pub fn members(&self) -> &[Address] {
+        &self.members
+    }
+
+    /// Returns true if `address` is a member of this policy.
+    pub fn is_member(&self, address: &Address) -> bool {
+        self.members.binary_search(address).is_ok()
+    }
+
+    /// Deterministically derive the address controlled by this policy.
+    pub fn address(&self) -> Address {
+        let mut bytes = Vec::new();
+
+        // domain separation (protocol rule)
+        bytes.extend_from_slice(MULTISIG_ADDRESS_DOMAIN);
+
+        // bind to threshold
+        bytes.extend_from_slice(&self.threshold.to_be_bytes());
+
+        // bind to member set (canonical order)
+        bytes.extend_from_slice(&(self.members.len() as u32).to_be_bytes());
+        for member in &self.members {
+            bytes.extend_from_slice(member.as_bytes());
+        }
+
+        Address::new(blake3::hash(&bytes).into())
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------- //
+
+//----------------------------------------------- Multisig Approval -----------------------------------------------//
+
+/// Member signatures approving a transaction on behalf of a multisig.
+///
+/// Each signature is over the same `ExternalTransaction::signing_hash()`
+/// as the transaction's own signature, which in turn commits to the
+/// policy. Every approval must meet its threshold; which objects it
+/// authorizes is decided at planning time.
+#[derive(Clone, Debug)]
+pub struct MultisigApproval {
+    /// Policy whose address is being acted for.
+    pub policy: MultisigPolicy,
+
+    /// Signatures by distinct policy members.
+    pub signatures: Vec<Signature>,
+}
+
+impl MultisigApproval {
+    /// Returns true if enough members signed to meet the threshold.
+    ///
+    /// Only meaningful once the approval has been verified.
+    pub fn is_satisfied(&self) -> bool {
+        self.signatures.len() >= self.policy.threshold() as usize
+    }
+}
+
+/// Verify every multisig approval carried by an external transaction.
+///
+/// Each signature must:
+/// - be produced by a registered scheme
+/// - be from a distinct policy member
+/// - verify over `tx.signing_hash()`
+///
+/// and each approval must meet its policy's threshold, so that dropping
+/// member signatures rejects the transaction instead of failing it.
+pub fn verify_multisig_approvals(
+    tx: &ExternalTransaction,
+    schemes: &SchemeRegistry,
+) -> Result<(), SignatureError> {
+    let message = tx.signing_hash();
+
+    for approval in &tx.approvals {
+        let mut seen = BTreeSet::new();
+
+        for signature in &approval.signatures {
+            let scheme = schemes.get(signature.scheme).ok_or(
+                SignatureError::UnsupportedScheme { scheme: signature.scheme },
+            )?;
+
+            let member = address_from_public_key(signature.scheme, &signature.public_key);
+
+            if !approval.policy.is_member(&member) {
+                return Err(SignatureError::NotAMember { member });
+            }
+
+            if !seen.insert(member) {
+                return Err(SignatureError::DuplicateApproval { member });
+            }
+
+            scheme.verify(
+                &signature.public_key,
+                message.as_bytes(),
+                &signature.bytes,
+            )?;
+        }
+
+        if !approval.is_satisfied() {
+            return Err(SignatureError::ThresholdNotMet {
+                policy: approval.policy.address(),
+                required: approval.policy.threshold(),
+                provided: approval.signatures.len() as u32,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+// -------------------------------------------------------------------------------------------------------------------------- //
@@ -4,12 +4,15 @@ use axiom_state::{
     StateStore,
     StateObject,
     validate_and_prepare_nonce_update,
-    NonceError,
 };
 
 use axiom_types::ObjectId;
 
 use crate::external_tx::ExternalTransaction;
+use crate::signature::verify_transaction_signature;
+use crate::multisig::verify_multisig_approvals;
+use crate::scheme::SchemeRegistry;
+use crate::error::PrepareError;
 
 // -------------------------------------------------------------------------------------------------------------------------- //
 
@@ -29,21 +32,31 @@ pub struct PreparedExternalTransaction {
 /// Validate an external transaction and prepare it for execution.
 ///
 /// This performs authorization checks only:
+/// - signature verification against the scheme registry
+/// - multisig approval signature verification
 /// - nonce validation
 ///
 /// It does NOT:
-/// - verify signatures (yet)
 /// - execute cells
 /// - mutate state
 pub fn prepare_external_transaction(
     tx: ExternalTransaction,
     state: &StateStore,
-) -> Result<PreparedExternalTransaction, NonceError> {
+    schemes: &SchemeRegistry,
+) -> Result<PreparedExternalTransaction, PrepareError> {
+    // Signature MUST be checked before any state is consulted
+    verify_transaction_signature(&tx, schemes)
+        .map_err(PrepareError::SignatureError)?;
+
+    verify_multisig_approvals(&tx, schemes)
+        .map_err(PrepareError::SignatureError)?;
+
     let nonce_update = validate_and_prepare_nonce_update(
         tx.signer,
         tx.nonce,
         state,
-    )?;
+    )
+    .map_err(PrepareError::NonceError)?;
 
     Ok(PreparedExternalTransaction {
         tx,
@@ -0,0 +1,115 @@
+
+/// Imports and dependencies
+use std::collections::BTreeMap;
+
+use crate::error::SignatureError;
+use crate::ed25519::Ed25519Scheme;
+use crate::secp256k1::Secp256k1Scheme;
+use crate::bls::Bls12381Scheme;
+
+// -------------------------------------------------------------------------------------------------------------------------- //
+
+//----------------------------------------------- Scheme Identifier -----------------------------------------------//
+
+/// Tag identifying the signature algorithm used by a `Signature`.
+///
+/// The numeric value is part of the canonical encoding and of
+/// address derivation; it MUST NOT change once assigned.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u8)]
+pub enum SchemeId {
+    /// Ed25519 over the signing hash.
+    Ed25519 = 0,
+
+    /// ECDSA over secp256k1 with SHA-256 message digest.
+    Secp256k1 = 1,
+
+    /// BLS over BLS12-381, public keys in G1, signatures in G2.
+    Bls12381 = 2,
+}
+
+impl SchemeId {
+    /// Returns the canonical tag byte.
+    pub const fn as_u8(self) -> u8 {
+        self as u8
+    }
+
+    /// Parses a canonical tag byte.
+    pub const fn from_u8(tag: u8) -> Option<Self> {
+        match tag {
+            0 => Some(Self::Ed25519),
+            1 => Some(Self::Secp256k1),
+            2 => Some(Self::Bls12381),
+            _ => None,
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------- //
+
+//----------------------------------------------- Signature Scheme -----------------------------------------------//
+
+/// A signature algorithm the protocol can verify.
+///
+/// Implementations MUST be deterministic: the same inputs always
+/// verify, or always fail, on every node.
+pub trait SignatureScheme: Send + Sync {
+    /// Tag this scheme is registered under.
+    fn id(&self) -> SchemeId;
+
+    /// Verify `signature` by `public_key` over `message`.
+    fn verify(
+        &self,
+        public_key: &[u8],
+        message: &[u8],
+        signature: &[u8],
+    ) -> Result<(), SignatureError>;
+}
+
+// -------------------------------------------------------------------------------------------------------------------------- //
+
+//----------------------------------------------- Scheme Registry -----------------------------------------------//
+
+/// Set of signature schemes accepted by the protocol.
+///
+/// Signatures tagged with a scheme that is not registered are rejected.
+#[derive(Default)]
+pub struct SchemeRegistry {
+    schemes: BTreeMap<SchemeId, Box<dyn SignatureScheme>>,
+}
+
+impl SchemeRegistry {
+    /// Create an empty registry that accepts no signatures.
+    pub fn new() -> Self {
+        Self {
+            schemes: BTreeMap::new(),
+        }
+    }
+
+    /// Create a registry containing every built-in scheme.
+    pub fn standard() -> Self {
+        let mut registry = Self::new();
+        registry.register(Ed25519Scheme);
+        registry.register(Secp256k1Scheme);
+        registry.register(Bls12381Scheme);
+        registry
+    }
+
+    /// Register a scheme, replacing any scheme with the same tag.
+    pub fn register<S: SignatureScheme + 'static>(&mut self, scheme: S) {
+        self.schemes.insert(scheme.id(), Box::new(scheme));
+    }
+
+    /// Retrieve the scheme registered under `id`.
+    pub fn get(&self, id: SchemeId) -> Option<&dyn SignatureScheme> {
+        self.schemes.get(&id).map(|scheme| scheme.as_ref())
+    }
+}
+
+impl core::fmt::Debug for SchemeRegistry {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_set().entries(self.schemes.keys()).finish()
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------- //
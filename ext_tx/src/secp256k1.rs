@@ -0,0 +1,61 @@
+
+/// Imports and dependencies
+use k256::ecdsa::{Signature, VerifyingKey, signature::Verifier};
+
+use crate::error::SignatureError;
+use crate::scheme::{SchemeId, SignatureScheme};
+
+// -------------------------------------------------------------------------------------------------------------------------- //
+
+//----------------------------------------------- Secp256k1 ECDSA -----------------------------------------------//
+
+/// Length (in bytes) of a compressed SEC1 secp256k1 public key.
+pub const SECP256K1_PUBLIC_KEY_LENGTH: usize = 33;
+
+/// Length (in bytes) of a fixed-width `r || s` ECDSA signature.
+pub const SECP256K1_SIGNATURE_LENGTH: usize = 64;
+
+/// ECDSA over secp256k1.
+///
+/// Public keys MUST be compressed, and signatures MUST be in low-S
+/// form, so that every key and signature has exactly one encoding.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Secp256k1Scheme;
+
+impl SignatureScheme for Secp256k1Scheme {
+    fn id(&self) -> SchemeId {
+        SchemeId::Secp256k1
+    }
+
+    fn verify(
+        &self,
+        public_key: &[u8],
+        message: &[u8],
+        signature: &[u8],
+    ) -> Result<(), SignatureError> {
+        if public_key.len() != SECP256K1_PUBLIC_KEY_LENGTH {
+            return Err(SignatureError::InvalidPublicKey);
+        }
+
+        let verifying_key = VerifyingKey::from_sec1_bytes(public_key)
+            .map_err(|_| SignatureError::InvalidPublicKey)?;
+
+        if signature.len() != SECP256K1_SIGNATURE_LENGTH {
+            return Err(SignatureError::MalformedSignature);
+        }
+
+        let signature = Signature::from_slice(signature)
+            .map_err(|_| SignatureError::MalformedSignature)?;
+
+        // High-S signatures are malleable
+        if signature.normalize_s().is_some() {
+            return Err(SignatureError::MalformedSignature);
+        }
+
+        verifying_key
+            .verify(message, &signature)
+            .map_err(|_| SignatureError::VerificationFailed)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------- //
@@ -0,0 +1,67 @@
+
+/// Imports and dependencies
+use axiom_types::Address;
+
+use crate::error::SignatureError;
+use crate::external_tx::ExternalTransaction;
+use crate::scheme::{SchemeId, SchemeRegistry};
+
+// -------------------------------------------------------------------------------------------------------------------------- //
+
+//----------------------------------------------- Signature Verification -----------------------------------------------//
+
+/// Reserved domain for deriving addresses from public keys.
+const ADDRESS_DOMAIN: &[u8] = b"axiom::address";
+
+/// Deterministically derive the `Address` controlled by a public key.
+///
+/// The scheme tag is bound into the preimage, so identical key bytes
+/// under different schemes never control the same address.
+pub fn address_from_public_key(scheme: SchemeId, public_key: &[u8]) -> Address {
+    let mut bytes = Vec::new();
+
+    // domain separation (protocol rule)
+    bytes.extend_from_slice(ADDRESS_DOMAIN);
+
+    // bind to scheme
+    bytes.push(scheme.as_u8());
+
+    // bind to public key
+    bytes.extend_from_slice(public_key);
+
+    Address::new(blake3::hash(&bytes).into())
+}
+
+/// Verify the signature carried by an external transaction.
+///
+/// Checks, in order:
+/// - the signature scheme is registered
+/// - the public key derives `tx.signer`
+/// - the signature is valid over `tx.signing_hash()`
+pub fn verify_transaction_signature(
+    tx: &ExternalTransaction,
+    schemes: &SchemeRegistry,
+) -> Result<(), SignatureError> {
+    let signature = &tx.signature;
+
+    let scheme = schemes.get(signature.scheme).ok_or(
+        SignatureError::UnsupportedScheme { scheme: signature.scheme },
+    )?;
+
+    // Signer must be the address controlled by the key
+    let derived = address_from_public_key(signature.scheme, &signature.public_key);
+    if derived != tx.signer {
+        return Err(SignatureError::SignerMismatch {
+            signer: tx.signer,
+            derived,
+        });
+    }
+
+    scheme.verify(
+        &signature.public_key,
+        tx.signing_hash().as_bytes(),
+        &signature.bytes,
+    )
+}
+
+// -------------------------------------------------------------------------------------------------------------------------- //
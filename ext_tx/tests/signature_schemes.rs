@@ -0,0 +1,175 @@
+use std::collections::{HashMap, BTreeMap};
+
+use axiom_ext_tx::{
+    ExternalTransaction,
+    Signature,
+    SchemeId,
+    SchemeRegistry,
+    SignatureError,
+    Ed25519Scheme,
+    address_from_public_key,
+    verify_transaction_signature,
+    ValidityWindow,
+};
+use axiom_ext_tx::bls::BLS_DST;
+use axiom_tx::{TransactionCell, CallData};
+use axiom_types::{Hash, ObjectId};
+
+// -------------------------------------------------------------
+// Helper: create a minimal valid TransactionCell
+// -------------------------------------------------------------
+fn make_cell() -> TransactionCell {
+    TransactionCell::new(
+        HashMap::new(),
+        BTreeMap::new(),
+        CallData {
+            target: ObjectId::new(Hash::new([3u8; 32])),
+            selector: vec![],
+            payload: vec![],
+        },
+    ).unwrap()
+}
+
+// -------------------------------------------------------------
+// Helper: build a transaction signed by `sign` under `scheme`
+// -------------------------------------------------------------
+fn signed_tx(
+    scheme: SchemeId,
+    public_key: Vec<u8>,
+    sign: impl Fn(&[u8]) -> Vec<u8>,
+) -> ExternalTransaction {
+    let mut tx = ExternalTransaction {
+        chain_id: 1,
+        signer: address_from_public_key(scheme, &public_key),
+        nonce: 0,
+        validity: ValidityWindow::unbounded(),
+        max_fee: 1,
+        priority_fee: 0,
+        gas_limit: 100_000,
+        cells: vec![make_cell()],
+        signature: Signature { scheme, public_key, bytes: vec![] },
+        approvals: vec![],
+    };
+
+    tx.signature.bytes = sign(tx.signing_hash().as_bytes());
+    tx
+}
+
+fn ed25519_tx(seed: u8) -> ExternalTransaction {
+    use ed25519_dalek::{Signer, SigningKey};
+
+    let key = SigningKey::from_bytes(&[seed; 32]);
+    signed_tx(
+        SchemeId::Ed25519,
+        key.verifying_key().to_bytes().to_vec(),
+        |msg| key.sign(msg).to_bytes().to_vec(),
+    )
+}
+
+fn secp256k1_tx(seed: u8) -> ExternalTransaction {
+    use k256::ecdsa::{Signature as EcdsaSignature, SigningKey, signature::Signer};
+
+    let key = SigningKey::from_slice(&[seed; 32]).unwrap();
+    signed_tx(
+        SchemeId::Secp256k1,
+        key.verifying_key().to_sec1_bytes().to_vec(),
+        |msg| {
+            let sig: EcdsaSignature = key.sign(msg);
+            sig.to_bytes().to_vec()
+        },
+    )
+}
+
+fn bls_tx(seed: u8) -> ExternalTransaction {
+    use blst::min_pk::SecretKey;
+
+    let key = SecretKey::key_gen(&[seed; 32], &[]).unwrap();
+    signed_tx(
+        SchemeId::Bls12381,
+        key.sk_to_pk().to_bytes().to_vec(),
+        |msg| key.sign(msg, BLS_DST, &[]).to_bytes().to_vec(),
+    )
+}
+
+// -------------------------------------------------------------
+// Test: Every built-in scheme verifies its own signatures
+// -------------------------------------------------------------
+#[test]
+fn standard_registry_verifies_all_schemes() {
+    let schemes = SchemeRegistry::standard();
+
+    for tx in [ed25519_tx(1), secp256k1_tx(2), bls_tx(3)] {
+        verify_transaction_signature(&tx, &schemes).unwrap();
+    }
+}
+
+// -------------------------------------------------------------
+// Test: Modifying a signed transaction breaks every scheme
+// -------------------------------------------------------------
+#[test]
+fn tampered_transaction_fails_all_schemes() {
+    let schemes = SchemeRegistry::standard();
+
+    for mut tx in [ed25519_tx(4), secp256k1_tx(5), bls_tx(6)] {
+        tx.nonce += 1;
+
+        assert!(matches!(
+            verify_transaction_signature(&tx, &schemes),
+            Err(SignatureError::VerificationFailed)
+        ));
+    }
+}
+
+// -------------------------------------------------------------
+// Test: Unregistered schemes are rejected
+// -------------------------------------------------------------
+#[test]
+fn unregistered_scheme_is_rejected() {
+    let mut schemes = SchemeRegistry::new();
+    schemes.register(Ed25519Scheme);
+
+    verify_transaction_signature(&ed25519_tx(7), &schemes).unwrap();
+
+    assert!(matches!(
+        verify_transaction_signature(&secp256k1_tx(8), &schemes),
+        Err(SignatureError::UnsupportedScheme { scheme: SchemeId::Secp256k1 })
+    ));
+}
+
+// -------------------------------------------------------------
+// Test: Re-tagging a signature with another scheme is rejected
+// -------------------------------------------------------------
+#[test]
+fn scheme_tag_is_bound_to_signer() {
+    let schemes = SchemeRegistry::standard();
+
+    let mut tx = ed25519_tx(9);
+    tx.signature.scheme = SchemeId::Secp256k1;
+
+    assert!(matches!(
+        verify_transaction_signature(&tx, &schemes),
+        Err(SignatureError::SignerMismatch { .. })
+    ));
+}
+
+// -------------------------------------------------------------
+// Test: High-S secp256k1 signatures are rejected as malleable
+// -------------------------------------------------------------
+#[test]
+fn secp256k1_rejects_high_s() {
+    use k256::ecdsa::Signature as EcdsaSignature;
+
+    let schemes = SchemeRegistry::standard();
+    let mut tx = secp256k1_tx(10);
+
+    // (r, s) and (r, n - s) both verify; only low-S is canonical
+    let sig = EcdsaSignature::from_slice(&tx.signature.bytes).unwrap();
+    let (r, s) = sig.split_scalars();
+    let high = EcdsaSignature::from_scalars(r, -*s).unwrap();
+    tx.signature.bytes = high.to_bytes().to_vec();
+
+    assert!(matches!(
+        verify_transaction_signature(&tx, &schemes),
+        Err(SignatureError::MalformedSignature)
+    ));
+}
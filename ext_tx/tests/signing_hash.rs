@@ -0,0 +1,143 @@
+use std::collections::{HashMap, BTreeMap};
+
+use axiom_ext_tx::{ExternalTransaction, Signature, SchemeId, ValidityWindow, MultisigPolicy, MultisigApproval};
+use axiom_tx::{TransactionCell, CallData, WriteIntent};
+use axiom_types::{Address, Hash, ObjectId};
+
+// -------------------------------------------------------------
+// Helper: cells with explicit call data
+// -------------------------------------------------------------
+fn target() -> ObjectId {
+    ObjectId::new(Hash::new([9u8; 32]))
+}
+
+fn cell(selector: &[u8], payload: &[u8]) -> TransactionCell {
+    TransactionCell::new(
+        HashMap::new(),
+        BTreeMap::new(),
+        CallData {
+            target: target(),
+            selector: selector.to_vec(),
+            payload: payload.to_vec(),
+        },
+    ).unwrap()
+}
+
+fn tx(cells: Vec<TransactionCell>) -> ExternalTransaction {
+    ExternalTransaction {
+        chain_id: 1,
+        signer: Address::new([1u8; 32]),
+        nonce: 0,
+        validity: ValidityWindow::unbounded(),
+        max_fee: 1,
+        priority_fee: 0,
+        gas_limit: 100_000,
+        cells,
+        signature: Signature { scheme: SchemeId::Ed25519, public_key: vec![], bytes: vec![] },
+        approvals: vec![],
+    }
+}
+
+// -------------------------------------------------------------
+// Test: Selector/payload boundaries cannot be shifted
+// -------------------------------------------------------------
+#[test]
+fn call_data_boundaries_are_committed() {
+    let a = cell(b"\xab", b"\x0c");
+    let b = cell(b"", b"\xab\x0c");
+
+    assert_ne!(a.encode(), b.encode());
+    assert_ne!(a.id(), b.id());
+    assert_ne!(tx(vec![a]).signing_hash(), tx(vec![b]).signing_hash());
+}
+
+// -------------------------------------------------------------
+// Test: Read versions and write intents are committed
+// -------------------------------------------------------------
+#[test]
+fn full_cell_contents_are_committed() {
+    let with_reads = |version, intent| {
+        let id = target();
+        TransactionCell::new(
+            HashMap::from([(id, version)]),
+            BTreeMap::from([(id, intent)]),
+            CallData { target: id, selector: b"op".to_vec(), payload: vec![] },
+        ).unwrap()
+    };
+
+    let modify_v1 = tx(vec![with_reads(1, WriteIntent::Modify)]).signing_hash();
+    assert_ne!(modify_v1, tx(vec![with_reads(2, WriteIntent::Modify)]).signing_hash());
+    assert_ne!(modify_v1, tx(vec![with_reads(1, WriteIntent::Delete)]).signing_hash());
+}
+
+// -------------------------------------------------------------
+// Test: Cell order (execution order) is committed
+// -------------------------------------------------------------
+#[test]
+fn cell_order_is_committed() {
+    let first = cell(b"a", b"");
+    let second = cell(b"b", b"");
+
+    assert_ne!(
+        tx(vec![first.clone(), second.clone()]).signing_hash(),
+        tx(vec![second, first]).signing_hash()
+    );
+}
+
+// -------------------------------------------------------------
+// Test: Signatures are outside the signed payload
+// -------------------------------------------------------------
+#[test]
+fn signature_is_not_committed() {
+    let unsigned = tx(vec![cell(b"op", b"")]);
+    let mut signed = unsigned.clone();
+    signed.signature.bytes = vec![7u8; 64];
+
+    assert_eq!(unsigned.signing_hash(), signed.signing_hash());
+}
+
+// -------------------------------------------------------------
+// Test: Approving policies are committed, member signatures are not
+// -------------------------------------------------------------
+#[test]
+fn approval_policies_are_committed() {
+    let members = vec![Address::new([2u8; 32]), Address::new([3u8; 32])];
+    let approval = |threshold| MultisigApproval {
+        policy: MultisigPolicy::new(threshold, members.clone()).unwrap(),
+        signatures: vec![],
+    };
+
+    let base = tx(vec![cell(b"op", b"")]);
+
+    let mut approved = base.clone();
+    approved.approvals = vec![approval(1)];
+    assert_ne!(base.signing_hash(), approved.signing_hash());
+
+    let mut other_policy = base.clone();
+    other_policy.approvals = vec![approval(2)];
+    assert_ne!(approved.signing_hash(), other_policy.signing_hash());
+
+    let mut cosigned = approved.clone();
+    cosigned.approvals[0].signatures.push(Signature {
+        scheme: SchemeId::Ed25519,
+        public_key: vec![],
+        bytes: vec![7u8; 64],
+    });
+    assert_eq!(approved.signing_hash(), cosigned.signing_hash());
+}
+
+// -------------------------------------------------------------
+// Test: Fee and gas limits are committed
+// -------------------------------------------------------------
+#[test]
+fn limits_are_committed() {
+    let base = tx(vec![cell(b"op", b"")]);
+
+    let mut more_gas = base.clone();
+    more_gas.gas_limit += 1;
+    assert_ne!(base.signing_hash(), more_gas.signing_hash());
+
+    let mut higher_tip = base.clone();
+    higher_tip.priority_fee += 1;
+    assert_ne!(base.signing_hash(), higher_tip.signing_hash());
+}
@@ -0,0 +1,84 @@
+use axiom_ext_tx::SchemeRegistry;
+use axiom_execution_engine::GasSchedule;
+use axiom_state::ProtocolParameters;
+
+use crate::fees::FeeParameters;
+
+/// Network-wide settings every node on a chain must agree on.
+#[derive(Debug)]
+pub struct ChainConfig {
+    /// Chain transactions must be signed for.
+    pub chain_id: u64,
+
+    /// Signature schemes accepted for transactions and approvals.
+    pub schemes: SchemeRegistry,
+
+    /// Base fee market parameters.
+    pub fees: FeeParameters,
+
+    /// Gas cost of each metered execution operation.
+    pub gas: GasSchedule,
+
+    /// Most gas a single transaction may declare.
+    pub max_transaction_gas: u64,
+
+    /// Most gas all transactions of a block may consume together.
+    pub max_block_gas: u64,
+}
+
+impl ChainConfig {
+    /// Create a config for `chain_id` accepting `schemes`, with the
+    /// default fee market, gas schedule and gas limits.
+    pub fn new(chain_id: u64, schemes: SchemeRegistry) -> Self {
+        Self {
+            chain_id,
+            schemes,
+            fees: FeeParameters::default(),
+            gas: GasSchedule::default(),
+            max_transaction_gas: 1_000_000,
+            max_block_gas: 10_000_000,
+        }
+    }
+
+    /// Create a config from genesis `parameters`, accepting `schemes`.
+    pub fn from_parameters(parameters: &ProtocolParameters, schemes: SchemeRegistry) -> Self {
+        Self {
+            chain_id: parameters.chain_id,
+            schemes,
+            fees: FeeParameters::from_parameters(parameters),
+            gas: GasSchedule::from_parameters(parameters),
+            max_transaction_gas: parameters.max_transaction_gas,
+            max_block_gas: parameters.max_block_gas,
+        }
+    }
+
+    /// Genesis parameters recording this config, with a genesis base fee
+    /// of `base_fee`.
+    pub fn parameters(&self, base_fee: u64) -> ProtocolParameters {
+        ProtocolParameters {
+            chain_id: self.chain_id,
+            base_fee,
+            target_gas: self.fees.target_gas,
+            max_transactions: self.fees.max_transactions,
+            change_denominator: self.fees.change_denominator,
+            min_base_fee: self.fees.min_base_fee,
+            burn_basis_points: self.fees.burn_basis_points,
+            gas_call: self.gas.call,
+            gas_read: self.gas.read,
+            gas_write: self.gas.write,
+            gas_byte: self.gas.byte,
+            max_transaction_gas: self.max_transaction_gas,
+            max_block_gas: self.max_block_gas,
+        }
+    }
+
+    /// Return true if genesis `parameters` record this config.
+    pub fn matches(&self, parameters: &ProtocolParameters) -> bool {
+        self.parameters(parameters.base_fee) == *parameters
+    }
+
+    /// Create a config for `chain_id` accepting the standard schemes.
+    pub fn standard(chain_id: u64) -> Self {
+        Self::new(chain_id, SchemeRegistry::standard())
+    }
+}
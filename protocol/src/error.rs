@@ -2,10 +2,21 @@ use axiom_execution_engine::ExecutionError;
 use axiom_state_diff::CommitError;
 use axiom_execution::PlanningError;
 use axiom_state::NonceError;
+use axiom_ext_tx::SignatureError;
 use axiom_tx::TxError;
+use axiom_types::Slot;
 
 #[derive(Debug)]
 pub enum ProtocolError {
+    /// Transaction was signed for another chain
+    WrongChain { expected: u64, found: u64 },
+
+    /// Transaction is not valid in the current slot
+    OutsideValidityWindow { slot: Slot, from: Slot, until: Slot },
+
+    /// External transaction signature was invalid
+    SignatureError(SignatureError),
+
     /// External transaction failed authorization
     NonceError(NonceError),
 
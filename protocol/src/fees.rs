@@ -0,0 +1,80 @@
+use axiom_state::ProtocolParameters;
+
+/// Denominator of `FeeParameters::burn_basis_points`.
+pub const BASIS_POINTS: u64 = 10_000;
+
+/// Base fee market parameters (EIP-1559 style).
+///
+/// Each block's base fee is derived from its parent: it rises when the
+/// parent used more than `target_gas` and falls when it used less, by at
+/// most `1 / change_denominator` per block.
+///
+/// The base fee part of the fees collected in a block is split between
+/// burning and the block proposer by `burn_basis_points`; tips go to the
+/// proposer in full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FeeParameters {
+    /// Gas per block the base fee steers towards.
+    pub target_gas: u64,
+
+    /// Most transactions a block may carry.
+    pub max_transactions: u64,
+
+    /// Inverse of the largest per-block base fee change.
+    pub change_denominator: u64,
+
+    /// Floor the base fee never falls below.
+    pub min_base_fee: u64,
+
+    /// Share of collected base fees burned, in basis points (1/10,000).
+    pub burn_basis_points: u64,
+}
+
+impl FeeParameters {
+    /// Fee market recorded in genesis `parameters`.
+    pub fn from_parameters(parameters: &ProtocolParameters) -> Self {
+        Self {
+            target_gas: parameters.target_gas,
+            max_transactions: parameters.max_transactions,
+            change_denominator: parameters.change_denominator,
+            min_base_fee: parameters.min_base_fee,
+            burn_basis_points: parameters.burn_basis_points,
+        }
+    }
+
+    /// Base fee of the block following one with `parent_base_fee`
+    /// that used `parent_gas_used`.
+    pub fn next_base_fee(&self, parent_base_fee: u64, parent_gas_used: u64) -> u64 {
+        let target = self.target_gas.max(1) as u128;
+        let denominator = self.change_denominator.max(1) as u128;
+        let parent = parent_base_fee as u128;
+        let used = parent_gas_used as u128;
+
+        let next = if used > target {
+            let delta = (parent * (used - target) / target / denominator).max(1);
+            parent.saturating_add(delta).min(u64::MAX as u128)
+        } else {
+            parent - parent * (target - used) / target / denominator
+        };
+
+        (next as u64).max(self.min_base_fee)
+    }
+
+    /// Portion of `base_fees` burned; the rest goes to the proposer.
+    pub fn burned(&self, base_fees: u64) -> u64 {
+        let basis_points = self.burn_basis_points.min(BASIS_POINTS) as u128;
+        (base_fees as u128 * basis_points / BASIS_POINTS as u128) as u64
+    }
+}
+
+impl Default for FeeParameters {
+    fn default() -> Self {
+        Self {
+            target_gas: 5_000_000,
+            max_transactions: 2_000,
+            change_denominator: 8,
+            min_base_fee: 1,
+            burn_basis_points: 5_000,
+        }
+    }
+}
@@ -4,6 +4,10 @@
 
 pub mod process;
 pub mod error;
+pub mod config;
+pub mod fees;
 
-pub use process::process_external_transaction;
+pub use process::{ProcessOutcome, process_external_transaction};
 pub use error::ProtocolError;
+pub use config::ChainConfig;
+pub use fees::{BASIS_POINTS, FeeParameters};
@@ -1,57 +1,240 @@
-use axiom_ext_tx::{ExternalTransaction, prepare_external_transaction};
-use axiom_execution::{build_execution_plan};
+use std::collections::BTreeMap;
+
+use axiom_ext_tx::{
+    ExternalTransaction,
+    PrepareError,
+    PreparedExternalTransaction,
+    prepare_external_transaction,
+};
+use axiom_execution::{TransactionCharge, build_execution_plan, charge_transaction};
 use axiom_execution_engine::{
     ExecutionEngine,
     ExecutionContext,
+    ExecutionError,
+    GasMeter,
+    TransferError,
+};
+use axiom_state::{
+    ObjectWrite,
+    ReadSet,
+    StateObject,
+    StateStore,
+    balance_object_id,
+    decode_balance,
+    encode_balance,
+    is_balance_object,
 };
-use axiom_state::{StateStore};
+use axiom_types::ObjectId;
 use axiom_state_diff::{StateDiff, commit_state_diff};
 
+use crate::config::ChainConfig;
 use crate::error::ProtocolError;
 
+/// Result of a transaction that was charged.
+#[derive(Debug)]
+pub struct ProcessOutcome {
+    /// Fee deducted from the signer's balance.
+    pub fee_charged: u64,
+
+    /// Gas consumed by execution.
+    pub gas_used: u64,
+
+    /// Why planning, execution or commit failed, if it did.
+    ///
+    /// A failed transaction consumes its whole gas limit, pays for it and
+    /// advances its nonce, but none of its own writes are applied.
+    pub failure: Option<ProtocolError>,
+}
+
+impl ProcessOutcome {
+    /// Return whether the transaction's writes were applied.
+    pub fn is_success(&self) -> bool {
+        self.failure.is_none()
+    }
+}
+
 /// Process a single external transaction against the current state.
 ///
+/// Commits in two phases. A transaction that fails replay protection,
+/// authorization or cannot pay its fee is rejected with an error and
+/// leaves `state` untouched. Once charged, the fee and nonce update
+/// are committed even if the rest of the transaction fails, which is
+/// reported through `ProcessOutcome::failure`.
+///
+/// Execution is metered against the transaction's declared gas limit,
+/// replacing any meter already in `context`, and gas is priced at
+/// `context.base_fee` plus the tip. The signer must afford the whole
+/// limit up front and pays only for the gas used. Limits above
+/// `config.max_transaction_gas` are rejected.
+///
 /// This is the canonical state transition pipeline.
 pub fn process_external_transaction<E: ExecutionEngine>(
     state: &mut StateStore,
     tx: ExternalTransaction,
     engine: &E,
     context: ExecutionContext,
-) -> Result<(), ProtocolError> {
+    config: &ChainConfig,
+) -> Result<ProcessOutcome, ProtocolError> {
+    // -------------------------------------------------------------
+    // 0️⃣ Replay protection (chain id, validity window)
     // -------------------------------------------------------------
-    // 1️⃣ Authorization (nonce)
+    if tx.chain_id != config.chain_id {
+        return Err(ProtocolError::WrongChain {
+            expected: config.chain_id,
+            found: tx.chain_id,
+        });
+    }
+
+    if !tx.validity.contains(context.slot) {
+        return Err(ProtocolError::OutsideValidityWindow {
+            slot: context.slot,
+            from: tx.validity.from,
+            until: tx.validity.until,
+        });
+    }
+
     // -------------------------------------------------------------
-    let prepared = prepare_external_transaction(tx, state)
-        .map_err(ProtocolError::NonceError)?;
+    // 1️⃣ Authorization (signature, nonce)
+    // -------------------------------------------------------------
+    let prepared = prepare_external_transaction(tx, state, &config.schemes)
+        .map_err(|err| match err {
+            PrepareError::SignatureError(err) => ProtocolError::SignatureError(err),
+            PrepareError::NonceError(err) => ProtocolError::NonceError(err),
+        })?;
+
+    // -------------------------------------------------------------
+    // 2️⃣ Charge (nonce, fee)
+    // -------------------------------------------------------------
+    let charge = charge_transaction(&prepared, state, context.base_fee, config.max_transaction_gas)
+        .map_err(ProtocolError::PlanningError)?;
 
+    let gas_limit = prepared.tx.gas_limit;
+
+    match execute_charged(state, prepared, charge.clone(), engine, context, config) {
+        Ok(gas_used) => Ok(ProcessOutcome {
+            fee_charged: charge.fee(gas_used),
+            gas_used,
+            failure: None,
+        }),
+        Err(failure) => {
+            // -------------------------------------------------------------
+            // 7️⃣ Fall back to committing the charge alone, which spends
+            //    the whole reservation
+            // -------------------------------------------------------------
+            let diff = StateDiff {
+                read_set: ReadSet::new(),
+                writes: charge.forced_writes
+                    .into_iter()
+                    .map(|(id, obj)| (id, ObjectWrite::Put(obj)))
+                    .collect(),
+            };
+
+            commit_state_diff(state, diff)
+                .map_err(ProtocolError::CommitError)?;
+
+            Ok(ProcessOutcome {
+                fee_charged: charge.reserved,
+                gas_used: gas_limit,
+                failure: Some(failure),
+            })
+        }
+    }
+}
+
+/// Plan, execute and commit a charged transaction, returning the gas used.
+///
+/// The part of the reservation the gas used does not cost is returned to
+/// the signer in the same commit. On error nothing has been committed.
+fn execute_charged<E: ExecutionEngine>(
+    state: &mut StateStore,
+    prepared: PreparedExternalTransaction,
+    charge: TransactionCharge,
+    engine: &E,
+    context: ExecutionContext,
+    config: &ChainConfig,
+) -> Result<u64, ProtocolError> {
     // -------------------------------------------------------------
-    // 2️⃣ Execution planning (ownership, fees, intents)
+    // 3️⃣ Execution planning (ownership, intents)
     // -------------------------------------------------------------
-    let plan = build_execution_plan(prepared, state)
+    let plan = build_execution_plan(prepared, state, charge)
         .map_err(ProtocolError::PlanningError)?;
 
     // -------------------------------------------------------------
-    // 3️⃣ Execute plan (VM / reference engine)
+    // 4️⃣ Execute plan (VM / reference engine), metered
     // -------------------------------------------------------------
+    let context = ExecutionContext {
+        gas: GasMeter::new(plan.gas_limit, config.gas),
+        ..context
+    };
+
     let outcome = engine
         .execute(&plan, state, context)
         .map_err(ProtocolError::ExecutionError)?;
 
     // -------------------------------------------------------------
-    // 4️⃣ Build StateDiff
+    // 5️⃣ Build StateDiff
     // -------------------------------------------------------------
-    let mut writes = plan.forced_writes.clone();
-
-    for (id, obj) in outcome.writes {
-        if writes.contains_key(&id) {
-            // Execution attempted to override protocol write
-            return Err(ProtocolError::ExecutionError(
-                axiom_execution_engine::ExecutionError::UnauthorizedWrite {
-                    object: id,
-                },
-            ));
+    let mut writes: BTreeMap<ObjectId, ObjectWrite> = plan.forced_writes
+        .iter()
+        .map(|(id, obj)| (*id, ObjectWrite::Put(obj.clone())))
+        .collect();
+
+    for (id, write) in outcome.writes {
+        let unauthorized = || ProtocolError::ExecutionError(
+            ExecutionError::UnauthorizedWrite {
+                object: id,
+            },
+        );
+
+        // Execution may build on a protocol write, never replace or delete it
+        let previous = match plan.forced_writes.get(&id) {
+            Some(forced) => {
+                if write.object().is_none() || write.version() != forced.version() + 1 {
+                    return Err(unauthorized());
+                }
+                Some(forced)
+            }
+            None => state.get(&id),
+        };
+
+        // Balances of accounts the transaction does not act for may only be credited
+        if let Some(previous) = previous
+            && is_balance_object(previous)
+            && !plan.authorized.contains(&previous.owner())
+        {
+            match write.object() {
+                Some(obj) if obj.owner() == previous.owner()
+                    && decode_balance(obj) >= decode_balance(previous) => {}
+                _ => return Err(unauthorized()),
+            }
+        }
+
+        writes.insert(id, write);
+    }
+
+    // Return the unused reservation on the signer's final balance write
+    let overrun = || ProtocolError::ExecutionError(
+        ExecutionError::GasOverrun {
+            used: outcome.gas_used,
+            limit: plan.gas_limit,
+        },
+    );
+
+    let refund = plan.gas_limit
+        .checked_sub(outcome.gas_used)
+        .and_then(|unused| unused.checked_mul(plan.gas_price))
+        .ok_or_else(overrun)?;
+
+    if refund > 0 {
+        let balance_id = balance_object_id(plan.signer);
+        if let Some(ObjectWrite::Put(balance)) = writes.get_mut(&balance_id) {
+            let refunded = decode_balance(balance)
+                .checked_add(refund)
+                .ok_or(ProtocolError::ExecutionError(
+                    ExecutionError::Transfer(TransferError::BalanceOverflow { recipient: plan.signer }),
+                ))?;
+            *balance = StateObject::from_parts(balance_id, balance.owner(), balance.version(), encode_balance(refunded));
         }
-        writes.insert(id, obj);
     }
 
     let diff = StateDiff {
@@ -60,10 +243,10 @@ pub fn process_external_transaction<E: ExecutionEngine>(
     };
 
     // -------------------------------------------------------------
-    // 5️⃣ Commit atomically
+    // 6️⃣ Commit atomically
     // -------------------------------------------------------------
     commit_state_diff(state, diff)
         .map_err(ProtocolError::CommitError)?;
 
-    Ok(())
+    Ok(outcome.gas_used)
 }
@@ -0,0 +1,110 @@
+//! Helpers shared by the protocol integration tests.
+
+#![allow(dead_code)]
+
+use axiom_protocol::{process_external_transaction, ProcessOutcome, ProtocolError, ChainConfig};
+use axiom_execution_engine::{ReferenceExecutionEngine, ExecutionContext};
+use axiom_state::StateStore;
+use axiom_state::balance::{balance_object_id, decode_balance};
+use axiom_state::nonce::nonce_object_id;
+use axiom_ext_tx::{
+    ExternalTransaction,
+    Signature,
+    SchemeId,
+    MultisigPolicy,
+    MultisigApproval,
+    address_from_public_key,
+    ValidityWindow,
+};
+use ed25519_dalek::{Signer, SigningKey};
+use axiom_tx::TransactionCell;
+use axiom_types::{Address, Slot, Epoch};
+
+pub const CHAIN_ID: u64 = 1;
+
+// -------------------------------------------------------------
+// Helper: deterministic signing key and the address it controls
+// -------------------------------------------------------------
+pub fn signing_key(seed: u8) -> SigningKey {
+    SigningKey::from_bytes(&[seed; 32])
+}
+
+pub fn address_of(key: &SigningKey) -> Address {
+    address_from_public_key(SchemeId::Ed25519, key.verifying_key().as_bytes())
+}
+
+pub fn signature_by(key: &SigningKey, tx: &ExternalTransaction) -> Signature {
+    Signature {
+        scheme: SchemeId::Ed25519,
+        public_key: key.verifying_key().to_bytes().to_vec(),
+        bytes: key.sign(tx.signing_hash().as_bytes()).to_bytes().to_vec(),
+    }
+}
+
+// -------------------------------------------------------------
+// Helper: unsigned transaction paying at most 1 per unit of gas
+// -------------------------------------------------------------
+pub fn transaction(key: &SigningKey, nonce: u64, gas_limit: u64, cells: Vec<TransactionCell>) -> ExternalTransaction {
+    ExternalTransaction {
+        chain_id: CHAIN_ID,
+        signer: address_of(key),
+        nonce,
+        validity: ValidityWindow::unbounded(),
+        max_fee: 1,
+        priority_fee: 0,
+        gas_limit,
+        cells,
+        signature: Signature { scheme: SchemeId::Ed25519, public_key: vec![], bytes: vec![] },
+        approvals: vec![],
+    }
+}
+
+// -------------------------------------------------------------
+// Helper: sign as `key`, with `approvers` approving for `policy`
+// -------------------------------------------------------------
+pub fn sign(
+    key: &SigningKey,
+    mut tx: ExternalTransaction,
+    policy: Option<&MultisigPolicy>,
+    approvers: &[&SigningKey],
+) -> ExternalTransaction {
+    // The signed payload commits to the policy, not its signatures
+    if let Some(policy) = policy {
+        tx.approvals = vec![MultisigApproval { policy: policy.clone(), signatures: vec![] }];
+    }
+
+    tx.signature = signature_by(key, &tx);
+
+    if policy.is_some() {
+        tx.approvals[0].signatures = approvers.iter().map(|approver| signature_by(approver, &tx)).collect();
+    }
+
+    tx
+}
+
+// -------------------------------------------------------------
+// Helper: process with the reference engine at slot 1, base fee 1
+// -------------------------------------------------------------
+
+/// `Err` is a rejection that left `state` untouched; a charged failure
+/// is returned as an outcome with `failure` set.
+pub fn process(
+    state: &mut StateStore,
+    tx: ExternalTransaction,
+    config: &ChainConfig,
+) -> Result<ProcessOutcome, ProtocolError> {
+    let ctx = ExecutionContext::new(Slot::new(1), Epoch::new(0), 1);
+
+    process_external_transaction(state, tx, &ReferenceExecutionEngine, ctx, config)
+}
+
+// -------------------------------------------------------------
+// Helper: balance and nonce version of an account
+// -------------------------------------------------------------
+pub fn balance_of(state: &StateStore, address: Address) -> u64 {
+    decode_balance(state.get(&balance_object_id(address)).unwrap())
+}
+
+pub fn nonce_version(state: &StateStore, address: Address) -> Option<u64> {
+    state.get(&nonce_object_id(address)).map(|nonce| nonce.version())
+}
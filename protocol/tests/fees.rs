@@ -0,0 +1,208 @@
+use std::collections::{HashMap, BTreeMap};
+
+use axiom_protocol::{process_external_transaction, ChainConfig, FeeParameters, ProcessOutcome, ProtocolError};
+use axiom_execution::PlanningError;
+use axiom_execution_engine::{ReferenceExecutionEngine, ExecutionContext, GasSchedule};
+use axiom_state::{StateStore, StateObject};
+use axiom_state::balance::{balance_object_id, encode_balance, decode_balance};
+use axiom_ext_tx::{ExternalTransaction, Signature, SchemeId, address_from_public_key, ValidityWindow};
+use ed25519_dalek::{Signer, SigningKey};
+use axiom_tx::{TransactionCell, CallData};
+use axiom_types::{Address, Slot, Epoch, ObjectId};
+
+const CHAIN_ID: u64 = 1;
+
+/// Gas limit of every test transaction.
+const GAS_LIMIT: u64 = 1_000;
+
+/// Gas used by a transaction running one `make_cell`.
+fn call_gas() -> u64 {
+    GasSchedule::default().call
+}
+
+// -------------------------------------------------------------
+// Helper: minimal cell, signing key and signed transaction
+// -------------------------------------------------------------
+fn make_cell(target: ObjectId) -> TransactionCell {
+    TransactionCell::new(
+        HashMap::new(),
+        BTreeMap::new(),
+        CallData {
+            target,
+            selector: vec![],
+            payload: vec![],
+        },
+    ).unwrap()
+}
+
+fn signing_key(seed: u8) -> SigningKey {
+    SigningKey::from_bytes(&[seed; 32])
+}
+
+fn address_of(key: &SigningKey) -> Address {
+    address_from_public_key(SchemeId::Ed25519, key.verifying_key().as_bytes())
+}
+
+fn make_tx(key: &SigningKey, max_fee: u64, priority_fee: u64) -> ExternalTransaction {
+    let signer = address_of(key);
+
+    let mut tx = ExternalTransaction {
+        chain_id: CHAIN_ID,
+        signer,
+        nonce: 0,
+        validity: ValidityWindow::unbounded(),
+        max_fee,
+        priority_fee,
+        gas_limit: GAS_LIMIT,
+        cells: vec![make_cell(balance_object_id(signer))],
+        signature: Signature { scheme: SchemeId::Ed25519, public_key: vec![], bytes: vec![] },
+        approvals: vec![],
+    };
+
+    let signature = key.sign(tx.signing_hash().as_bytes());
+    tx.signature = Signature {
+        scheme: SchemeId::Ed25519,
+        public_key: key.verifying_key().to_bytes().to_vec(),
+        bytes: signature.to_bytes().to_vec(),
+    };
+    tx
+}
+
+// -------------------------------------------------------------
+// Helper: fund the signer and process one transaction at `base_fee`
+// -------------------------------------------------------------
+fn run(
+    state: &mut StateStore,
+    tx: ExternalTransaction,
+    base_fee: u64,
+) -> Result<ProcessOutcome, ProtocolError> {
+    let engine = ReferenceExecutionEngine;
+    let ctx = ExecutionContext::new(Slot::new(1), Epoch::new(0), base_fee);
+    let config = ChainConfig::standard(CHAIN_ID);
+
+    process_external_transaction(state, tx, &engine, ctx, &config)
+}
+
+fn funded(key: &SigningKey, amount: u64) -> StateStore {
+    let signer = address_of(key);
+    let mut state = StateStore::new();
+    state.insert(StateObject::new(balance_object_id(signer), signer, encode_balance(amount))).unwrap();
+    state
+}
+
+fn balance(state: &StateStore, key: &SigningKey) -> u64 {
+    decode_balance(state.get(&balance_object_id(address_of(key))).unwrap())
+}
+
+// -------------------------------------------------------------
+// Test: Signer pays the gas used at the base fee plus the full tip
+// -------------------------------------------------------------
+#[test]
+fn fee_is_base_fee_plus_tip() {
+    let key = signing_key(1);
+    let mut state = funded(&key, 100_000);
+
+    let outcome = run(&mut state, make_tx(&key, 20, 3), 5).unwrap();
+
+    // The unused part of the gas limit is not paid for
+    assert_eq!(outcome.gas_used, call_gas());
+    assert_eq!(outcome.fee_charged, call_gas() * 8);
+    assert_eq!(balance(&state, &key), 100_000 - call_gas() * 8);
+}
+
+// -------------------------------------------------------------
+// Test: Tip is capped so the fee never exceeds the max fee
+// -------------------------------------------------------------
+#[test]
+fn fee_is_capped_at_max_fee() {
+    let key = signing_key(2);
+    let mut state = funded(&key, 100_000);
+
+    let outcome = run(&mut state, make_tx(&key, 7, 10), 5).unwrap();
+
+    assert_eq!(outcome.fee_charged, call_gas() * 7);
+    assert_eq!(balance(&state, &key), 100_000 - call_gas() * 7);
+}
+
+// -------------------------------------------------------------
+// Test: Max fee below the base fee is rejected without charge
+// -------------------------------------------------------------
+#[test]
+fn fee_below_base_fee_is_rejected() {
+    let key = signing_key(3);
+    let mut state = funded(&key, 100_000);
+
+    let result = run(&mut state, make_tx(&key, 4, 0), 5);
+
+    assert!(matches!(
+        result,
+        Err(ProtocolError::PlanningError(PlanningError::FeeTooLow { max_fee: 4, base_fee: 5 }))
+    ));
+    assert_eq!(balance(&state, &key), 100_000);
+}
+
+// -------------------------------------------------------------
+// Test: Signer must afford its whole gas limit up front
+// -------------------------------------------------------------
+#[test]
+fn fee_requires_sufficient_balance() {
+    let key = signing_key(4);
+    let mut state = funded(&key, GAS_LIMIT * 8 - 1);
+
+    let result = run(&mut state, make_tx(&key, 20, 3), 5);
+
+    assert!(matches!(
+        result,
+        Err(ProtocolError::PlanningError(PlanningError::InsufficientBalance { available, required }))
+            if available == GAS_LIMIT * 8 - 1 && required == GAS_LIMIT * 8
+    ));
+    assert_eq!(balance(&state, &key), GAS_LIMIT * 8 - 1);
+}
+
+// -------------------------------------------------------------
+// Test: Base fee tracks gas used around the target
+// -------------------------------------------------------------
+#[test]
+fn base_fee_adjusts_with_gas_used() {
+    let fees = FeeParameters {
+        target_gas: 10_000,
+        max_transactions: 20,
+        change_denominator: 8,
+        min_base_fee: 1,
+        burn_basis_points: 0,
+    };
+
+    // At target: unchanged
+    assert_eq!(fees.next_base_fee(800, 10_000), 800);
+
+    // Twice the target: +1/8
+    assert_eq!(fees.next_base_fee(800, 20_000), 900);
+
+    // Empty block: -1/8
+    assert_eq!(fees.next_base_fee(800, 0), 700);
+
+    // Above target always raises the fee, even when small
+    assert_eq!(fees.next_base_fee(1, 10_001), 2);
+
+    // Never below the floor
+    assert_eq!(fees.next_base_fee(1, 0), 1);
+}
+
+// -------------------------------------------------------------
+// Test: Burned share follows the basis points, rounding down
+// -------------------------------------------------------------
+#[test]
+fn burned_share_follows_basis_points() {
+    let mut fees = FeeParameters { burn_basis_points: 5_000, ..FeeParameters::default() };
+    assert_eq!(fees.burned(9), 4);
+
+    fees.burn_basis_points = 10_000;
+    assert_eq!(fees.burned(9), 9);
+
+    fees.burn_basis_points = 0;
+    assert_eq!(fees.burned(9), 0);
+
+    // Never more than the total
+    fees.burn_basis_points = 20_000;
+    assert_eq!(fees.burned(u64::MAX), u64::MAX);
+}
@@ -0,0 +1,210 @@
+mod common;
+
+use std::collections::{HashMap, BTreeMap};
+
+use axiom_protocol::{ProcessOutcome, ProtocolError, ChainConfig, process_external_transaction};
+use axiom_execution::{ExecutionPlan, PlanningError};
+use axiom_execution_engine::{
+    ExecutionContext,
+    ExecutionEngine,
+    ExecutionError,
+    ExecutionOutcome,
+    GasMeter,
+    GasSchedule,
+    ReferenceExecutionEngine,
+    StateView,
+};
+use axiom_execution_engine::native::{SELECTOR_CREATE, created_object_id, encode_create_payload};
+use axiom_state::{StateStore, StateObject};
+use axiom_state::balance::{balance_object_id, encode_balance};
+use ed25519_dalek::SigningKey;
+use axiom_tx::{TransactionCell, CallData, WriteIntent};
+use axiom_types::{Address, ObjectId, Slot, Epoch};
+
+use common::{CHAIN_ID, signing_key, address_of, balance_of, nonce_version};
+
+// -------------------------------------------------------------
+// Helper: cells without reads, and a cell creating an object
+// -------------------------------------------------------------
+fn noop_cell(target: ObjectId) -> TransactionCell {
+    TransactionCell::new(
+        HashMap::new(),
+        BTreeMap::new(),
+        CallData { target, selector: vec![], payload: vec![] },
+    ).unwrap()
+}
+
+fn create_cell(signer: Address, data: &[u8]) -> TransactionCell {
+    let target = created_object_id(signer, &[1u8; 32]);
+
+    TransactionCell::new(
+        HashMap::from([(target, 0)]),
+        BTreeMap::from([(target, WriteIntent::Create)]),
+        CallData {
+            target,
+            selector: SELECTOR_CREATE.to_vec(),
+            payload: encode_create_payload(&[1u8; 32], data),
+        },
+    ).unwrap()
+}
+
+// -------------------------------------------------------------
+// Helper: sign and process a transaction under `schedule`
+// -------------------------------------------------------------
+fn run(
+    state: &mut StateStore,
+    key: &SigningKey,
+    gas_limit: u64,
+    schedule: GasSchedule,
+    cells: Vec<TransactionCell>,
+) -> Result<ProcessOutcome, ProtocolError> {
+    let tx = common::sign(key, common::transaction(key, 0, gas_limit, cells), None, &[]);
+
+    let mut config = ChainConfig::standard(CHAIN_ID);
+    config.gas = schedule;
+
+    common::process(state, tx, &config)
+}
+
+fn funded_state(key: &SigningKey) -> StateStore {
+    let mut state = StateStore::new();
+    let signer = address_of(key);
+    state.insert(StateObject::new(balance_object_id(signer), signer, encode_balance(10_000))).unwrap();
+    state
+}
+
+// -------------------------------------------------------------
+// Test: Meter charges up to its limit, then is exhausted
+// -------------------------------------------------------------
+#[test]
+fn gas_meter_stops_at_limit() {
+    let mut meter = GasMeter::new(10, GasSchedule::default());
+
+    meter.charge(4).unwrap();
+    assert_eq!(meter.used(), 4);
+    assert_eq!(meter.remaining(), 6);
+
+    assert!(matches!(meter.charge(7), Err(ExecutionError::OutOfGas { limit: 10 })));
+    assert_eq!(meter.used(), 10);
+    assert!(meter.charge(1).is_err());
+}
+
+// -------------------------------------------------------------
+// Test: Each dispatched cell is charged
+// -------------------------------------------------------------
+#[test]
+fn gas_charges_call_dispatch() {
+    let key = signing_key(1);
+    let mut state = funded_state(&key);
+    let target = balance_object_id(address_of(&key));
+
+    let schedule = GasSchedule { call: 7, read: 0, write: 0, byte: 0 };
+    let cells = vec![noop_cell(target), noop_cell(target), noop_cell(target)];
+
+    let outcome = run(&mut state, &key, 1_000, schedule, cells).unwrap();
+    assert!(outcome.is_success());
+    assert_eq!(outcome.gas_used, 21);
+    assert_eq!(outcome.fee_charged, 21);
+}
+
+// -------------------------------------------------------------
+// Test: Writes are charged per object and per byte of data
+// -------------------------------------------------------------
+#[test]
+fn gas_charges_written_bytes() {
+    let key = signing_key(2);
+    let mut state = funded_state(&key);
+    let signer = address_of(&key);
+
+    let schedule = GasSchedule { call: 0, read: 0, write: 100, byte: 1 };
+    let outcome = run(&mut state, &key, 1_000, schedule, vec![create_cell(signer, b"hello")]).unwrap();
+
+    assert!(outcome.is_success());
+    assert_eq!(outcome.gas_used, 105);
+    assert_eq!(balance_of(&state, signer), 10_000 - 105);
+}
+
+// -------------------------------------------------------------
+// Test: Exceeding the gas limit aborts execution but is charged
+// -------------------------------------------------------------
+#[test]
+fn gas_limit_is_enforced() {
+    let key = signing_key(3);
+    let mut state = funded_state(&key);
+    let signer = address_of(&key);
+
+    let schedule = GasSchedule { call: 0, read: 0, write: 100, byte: 1 };
+    let outcome = run(&mut state, &key, 104, schedule, vec![create_cell(signer, b"hello")]).unwrap();
+
+    assert!(matches!(
+        outcome.failure,
+        Some(ProtocolError::ExecutionError(ExecutionError::OutOfGas { limit: 104 }))
+    ));
+    assert_eq!(outcome.gas_used, 104);
+    assert_eq!(outcome.fee_charged, 104);
+    assert!(state.get(&created_object_id(signer, &[1u8; 32])).is_none());
+    assert_eq!(balance_of(&state, signer), 10_000 - 104);
+    assert_eq!(nonce_version(&state, signer), Some(0));
+}
+
+// -------------------------------------------------------------
+// Test: Gas limits above the chain maximum are rejected uncharged
+// -------------------------------------------------------------
+#[test]
+fn gas_limit_above_maximum_is_rejected() {
+    let key = signing_key(4);
+    let mut state = funded_state(&key);
+    let signer = address_of(&key);
+    let max = ChainConfig::standard(CHAIN_ID).max_transaction_gas;
+
+    let result = run(&mut state, &key, max + 1, GasSchedule::default(), vec![noop_cell(balance_object_id(signer))]);
+
+    assert!(matches!(
+        result,
+        Err(ProtocolError::PlanningError(PlanningError::GasLimitTooHigh { limit, max: found }))
+            if limit == max + 1 && found == max
+    ));
+    assert_eq!(balance_of(&state, signer), 10_000);
+    assert_eq!(nonce_version(&state, signer), None);
+}
+
+// -------------------------------------------------------------
+// Helper: engine that reports more gas than the limit allows
+// -------------------------------------------------------------
+struct OverrunEngine;
+
+impl ExecutionEngine for OverrunEngine {
+    fn execute(
+        &self,
+        plan: &ExecutionPlan,
+        state: &dyn StateView,
+        context: ExecutionContext,
+    ) -> Result<ExecutionOutcome, ExecutionError> {
+        let mut outcome = ReferenceExecutionEngine.execute(plan, state, context)?;
+        outcome.gas_used = plan.gas_limit + 1;
+        Ok(outcome)
+    }
+}
+
+// -------------------------------------------------------------
+// Test: Gas used beyond the limit fails the transaction
+// -------------------------------------------------------------
+#[test]
+fn gas_overrun_is_rejected() {
+    let key = signing_key(5);
+    let mut state = funded_state(&key);
+    let signer = address_of(&key);
+
+    let tx = common::sign(&key, common::transaction(&key, 0, 5_000, vec![noop_cell(balance_object_id(signer))]), None, &[]);
+    let ctx = ExecutionContext::new(Slot::new(1), Epoch::new(0), 1);
+
+    let outcome = process_external_transaction(&mut state, tx, &OverrunEngine, ctx, &ChainConfig::standard(CHAIN_ID))
+        .unwrap();
+
+    assert!(matches!(
+        outcome.failure,
+        Some(ProtocolError::ExecutionError(ExecutionError::GasOverrun { used: 5_001, limit: 5_000 }))
+    ));
+    assert_eq!(outcome.gas_used, 5_000);
+    assert_eq!(balance_of(&state, signer), 10_000 - 5_000);
+}
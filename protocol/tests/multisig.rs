@@ -0,0 +1,198 @@
+mod common;
+
+use std::collections::{HashMap, BTreeMap};
+
+use axiom_protocol::{ProcessOutcome, ProtocolError, ChainConfig};
+use axiom_execution::PlanningError;
+use axiom_state::{StateStore, StateObject};
+use axiom_state::balance::{balance_object_id, encode_balance};
+use axiom_ext_tx::{ExternalTransaction, SignatureError, MultisigPolicy};
+use ed25519_dalek::SigningKey;
+use axiom_tx::{TransactionCell, CallData, WriteIntent};
+use axiom_types::{ObjectId, Hash};
+
+use common::{CHAIN_ID, signing_key, address_of, balance_of, nonce_version};
+
+/// Gas limit of every test transaction, and each member's balance.
+const GAS_LIMIT: u64 = 10_000;
+const FUNDS: u64 = 10 * GAS_LIMIT;
+
+// -------------------------------------------------------------
+// Helper: 2-of-3 treasury fixture
+// -------------------------------------------------------------
+struct Treasury {
+    state: StateStore,
+    members: [SigningKey; 3],
+    policy: MultisigPolicy,
+    object: ObjectId,
+}
+
+fn setup_treasury() -> Treasury {
+    let members = [signing_key(21), signing_key(22), signing_key(23)];
+    let policy = MultisigPolicy::new(
+        2,
+        members.iter().map(address_of).collect(),
+    ).unwrap();
+
+    let mut state = StateStore::new();
+
+    // Treasury object owned by the multisig address
+    let object = ObjectId::new(Hash::new([77u8; 32]));
+    state.insert(StateObject::new(object, policy.address(), vec![1, 2, 3])).unwrap();
+
+    // Every member can pay fees
+    for key in &members {
+        let address = address_of(key);
+        state.insert(StateObject::new(balance_object_id(address), address, encode_balance(FUNDS))).unwrap();
+    }
+
+    Treasury { state, members, policy, object }
+}
+
+// -------------------------------------------------------------
+// Helper: transaction by `submitter` modifying the treasury
+// -------------------------------------------------------------
+fn treasury_tx(
+    submitter: &SigningKey,
+    object: ObjectId,
+    policy: Option<&MultisigPolicy>,
+    approvers: &[&SigningKey],
+) -> ExternalTransaction {
+    let cell = TransactionCell::new(
+        HashMap::from([(object, 0)]),
+        BTreeMap::from([(object, WriteIntent::Modify)]),
+        CallData { target: object, selector: vec![], payload: vec![] },
+    ).unwrap();
+
+    common::sign(submitter, common::transaction(submitter, 0, GAS_LIMIT, vec![cell]), policy, approvers)
+}
+
+fn run(state: &mut StateStore, tx: ExternalTransaction) -> Result<ProcessOutcome, ProtocolError> {
+    common::process(state, tx, &ChainConfig::standard(CHAIN_ID))
+}
+
+// -------------------------------------------------------------
+// Test: Multisig address is independent of member order
+// -------------------------------------------------------------
+#[test]
+fn multisig_address_is_canonical() {
+    let a = address_of(&signing_key(1));
+    let b = address_of(&signing_key(2));
+
+    let p1 = MultisigPolicy::new(1, vec![a, b]).unwrap();
+    let p2 = MultisigPolicy::new(1, vec![b, a, b]).unwrap();
+    let p3 = MultisigPolicy::new(2, vec![a, b]).unwrap();
+
+    assert_eq!(p1.address(), p2.address());
+    assert_ne!(p1.address(), p3.address());
+    assert!(MultisigPolicy::new(0, vec![a, b]).is_err());
+    assert!(MultisigPolicy::new(3, vec![a, b]).is_err());
+}
+
+// -------------------------------------------------------------
+// Test: Threshold approvals authorize writes to treasury
+// -------------------------------------------------------------
+#[test]
+fn multisig_threshold_authorizes_write() {
+    let Treasury { mut state, members, policy, object } = setup_treasury();
+
+    let tx = treasury_tx(&members[0], object, Some(&policy), &[&members[0], &members[2]]);
+
+    let outcome = run(&mut state, tx).unwrap();
+    assert!(outcome.is_success());
+    assert_eq!(balance_of(&state, address_of(&members[0])), FUNDS - outcome.fee_charged);
+}
+
+// -------------------------------------------------------------
+// Test: Below-threshold approvals are rejected uncharged
+// -------------------------------------------------------------
+#[test]
+fn multisig_below_threshold_is_rejected() {
+    let Treasury { mut state, members, policy, object } = setup_treasury();
+    let submitter = address_of(&members[0]);
+
+    let tx = treasury_tx(&members[0], object, Some(&policy), &[&members[0]]);
+
+    assert!(matches!(
+        run(&mut state, tx),
+        Err(ProtocolError::SignatureError(SignatureError::ThresholdNotMet {
+            required: 2,
+            provided: 1,
+            ..
+        }))
+    ));
+    assert_eq!(balance_of(&state, submitter), FUNDS);
+    assert_eq!(nonce_version(&state, submitter), None);
+}
+
+// -------------------------------------------------------------
+// Test: Stripping approvals invalidates the submitter's signature
+// -------------------------------------------------------------
+#[test]
+fn multisig_stripped_approvals_are_rejected() {
+    let Treasury { mut state, members, policy, object } = setup_treasury();
+    let submitter = address_of(&members[0]);
+
+    let mut tx = treasury_tx(&members[0], object, Some(&policy), &[&members[0], &members[2]]);
+    tx.approvals.clear();
+
+    assert!(matches!(
+        run(&mut state, tx),
+        Err(ProtocolError::SignatureError(SignatureError::VerificationFailed))
+    ));
+    assert_eq!(balance_of(&state, submitter), FUNDS);
+    assert_eq!(nonce_version(&state, submitter), None);
+}
+
+// -------------------------------------------------------------
+// Test: Single member cannot write without any approval
+// -------------------------------------------------------------
+#[test]
+fn multisig_member_alone_is_unauthorized() {
+    let Treasury { mut state, members, object, .. } = setup_treasury();
+    let submitter = address_of(&members[1]);
+
+    let tx = treasury_tx(&members[1], object, None, &[]);
+    let outcome = run(&mut state, tx).unwrap();
+
+    // Signed without the policy, so the submitter pays for the failure
+    assert!(matches!(
+        outcome.failure,
+        Some(ProtocolError::PlanningError(PlanningError::UnauthorizedWrite { .. }))
+    ));
+    assert_eq!(outcome.fee_charged, GAS_LIMIT);
+    assert_eq!(balance_of(&state, submitter), FUNDS - GAS_LIMIT);
+    assert_eq!(nonce_version(&state, submitter), Some(0));
+    assert_eq!(state.get(&object).unwrap().version(), 0);
+}
+
+// -------------------------------------------------------------
+// Test: Approvals from non-members are rejected
+// -------------------------------------------------------------
+#[test]
+fn multisig_rejects_non_member_approval() {
+    let Treasury { mut state, members, policy, object } = setup_treasury();
+    let outsider = signing_key(99);
+
+    let tx = treasury_tx(&members[0], object, Some(&policy), &[&members[0], &outsider]);
+
+    assert!(matches!(
+        run(&mut state, tx),
+        Err(ProtocolError::SignatureError(SignatureError::NotAMember { .. }))
+    ));
+}
+
+// -------------------------------------------------------------
+// Test: The same member cannot approve twice
+// -------------------------------------------------------------
+#[test]
+fn multisig_rejects_duplicate_approval() {
+    let Treasury { mut state, members, policy, object } = setup_treasury();
+
+    let tx = treasury_tx(&members[0], object, Some(&policy), &[&members[1], &members[1]]);
+
+    assert!(matches!(
+        run(&mut state, tx),
+        Err(ProtocolError::SignatureError(SignatureError::DuplicateApproval { .. }))
+    ));
+}
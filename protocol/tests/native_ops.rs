@@ -0,0 +1,353 @@
+mod common;
+
+use std::collections::{HashMap, BTreeMap};
+
+use axiom_protocol::{ProcessOutcome, ProtocolError, ChainConfig};
+use axiom_execution::PlanningError;
+use axiom_execution_engine::ExecutionError;
+use axiom_execution_engine::native::{
+    SELECTOR_CREATE,
+    SELECTOR_DELETE,
+    SELECTOR_WRITE,
+    SELECTOR_TRANSFER_OWNERSHIP,
+    created_object_id,
+    encode_create_payload,
+};
+use axiom_state::{StateStore, StateObject, compute_state_root};
+use axiom_state::balance::{balance_object_id, encode_balance};
+use ed25519_dalek::SigningKey;
+use axiom_tx::{TransactionCell, CallData, WriteIntent};
+use axiom_types::{ObjectId, Hash};
+
+use common::{CHAIN_ID, signing_key, address_of, balance_of, nonce_version};
+
+/// Gas limit of every test transaction, and a balance that affords ten.
+const GAS_LIMIT: u64 = 10_000;
+const FUNDS: u64 = 10 * GAS_LIMIT;
+
+// -------------------------------------------------------------
+// Helper: cell calling `selector` on `target` with one intent
+// -------------------------------------------------------------
+fn call_cell(
+    target: ObjectId,
+    intent: Option<(WriteIntent, u64)>,
+    selector: &[u8],
+    payload: Vec<u8>,
+) -> TransactionCell {
+    let mut read_set = HashMap::new();
+    let mut write_set = BTreeMap::new();
+
+    if let Some((intent, version)) = intent {
+        read_set.insert(target, version);
+        write_set.insert(target, intent);
+    }
+
+    TransactionCell::new(
+        read_set,
+        write_set,
+        CallData { target, selector: selector.to_vec(), payload },
+    ).unwrap()
+}
+
+// -------------------------------------------------------------
+// Helper: sign and process a transaction
+// -------------------------------------------------------------
+fn run(
+    state: &mut StateStore,
+    key: &SigningKey,
+    nonce: u64,
+    cells: Vec<TransactionCell>,
+) -> Result<ProcessOutcome, ProtocolError> {
+    let tx = common::sign(key, common::transaction(key, nonce, GAS_LIMIT, cells), None, &[]);
+
+    common::process(state, tx, &ChainConfig::standard(CHAIN_ID))
+}
+
+fn funded_state(key: &SigningKey) -> StateStore {
+    let mut state = StateStore::new();
+    let signer = address_of(key);
+    state.insert(StateObject::new(balance_object_id(signer), signer, encode_balance(FUNDS))).unwrap();
+    state
+}
+
+// -------------------------------------------------------------
+// Test: Create, write and transfer an object end to end
+// -------------------------------------------------------------
+#[test]
+fn native_object_lifecycle() {
+    let key = signing_key(1);
+    let other = signing_key(2);
+    let signer = address_of(&key);
+    let mut state = funded_state(&key);
+
+    let object = created_object_id(signer, &[42u8; 32]);
+
+    // Create
+    assert!(run(&mut state, &key, 0, vec![
+        call_cell(
+            object,
+            Some((WriteIntent::Create, 0)),
+            SELECTOR_CREATE,
+            encode_create_payload(&[42u8; 32], b"hello"),
+        ),
+    ]).unwrap().is_success());
+
+    let created = state.get(&object).unwrap();
+    assert_eq!(created.owner(), signer);
+    assert_eq!(created.version(), 0);
+    assert_eq!(created.data(), b"hello");
+
+    // Overwrite data
+    assert!(run(&mut state, &key, 0, vec![
+        call_cell(object, Some((WriteIntent::Modify, 0)), SELECTOR_WRITE, b"world".to_vec()),
+    ]).unwrap().is_success());
+
+    let written = state.get(&object).unwrap();
+    assert_eq!(written.version(), 1);
+    assert_eq!(written.data(), b"world");
+
+    // Transfer ownership
+    assert!(run(&mut state, &key, 1, vec![
+        call_cell(
+            object,
+            Some((WriteIntent::Modify, 1)),
+            SELECTOR_TRANSFER_OWNERSHIP,
+            address_of(&other).as_bytes().to_vec(),
+        ),
+    ]).unwrap().is_success());
+
+    let transferred = state.get(&object).unwrap();
+    assert_eq!(transferred.owner(), address_of(&other));
+    assert_eq!(transferred.version(), 2);
+
+    // Previous owner can no longer write
+    let outcome = run(&mut state, &key, 2, vec![
+        call_cell(object, Some((WriteIntent::Modify, 2)), SELECTOR_WRITE, vec![]),
+    ]).unwrap();
+    assert!(matches!(outcome.failure, Some(ProtocolError::PlanningError(PlanningError::UnauthorizedWrite { .. }))));
+    assert_eq!(state.get(&object).unwrap().version(), 2);
+    assert_eq!(nonce_version(&state, signer), Some(3));
+}
+
+// -------------------------------------------------------------
+// Test: Writes without a matching intent are rejected
+// -------------------------------------------------------------
+#[test]
+fn native_write_requires_matching_intent() {
+    let key = signing_key(3);
+    let mut state = funded_state(&key);
+
+    let object = created_object_id(address_of(&key), &[43u8; 32]);
+
+    // Create without a declared Create intent
+    let outcome = run(&mut state, &key, 0, vec![
+        call_cell(object, None, SELECTOR_CREATE, encode_create_payload(&[43u8; 32], &[1])),
+    ]).unwrap();
+
+    assert!(matches!(
+        outcome.failure,
+        Some(ProtocolError::ExecutionError(ExecutionError::UnauthorizedWrite { .. }))
+    ));
+    assert!(state.get(&object).is_none());
+
+    // The failure still pays for its whole gas limit and uses its nonce
+    assert_eq!(outcome.fee_charged, GAS_LIMIT);
+    assert_eq!(balance_of(&state, address_of(&key)), FUNDS - GAS_LIMIT);
+    assert_eq!(nonce_version(&state, address_of(&key)), Some(0));
+}
+
+// -------------------------------------------------------------
+// Test: Unknown selectors fail deterministically
+// -------------------------------------------------------------
+#[test]
+fn native_rejects_unknown_selector() {
+    let key = signing_key(4);
+    let mut state = funded_state(&key);
+
+    let object = ObjectId::new(Hash::new([44u8; 32]));
+
+    let outcome = run(&mut state, &key, 0, vec![
+        call_cell(object, None, b"axiom::unknown", vec![]),
+    ]).unwrap();
+
+    assert!(matches!(
+        outcome.failure,
+        Some(ProtocolError::ExecutionError(ExecutionError::UnknownSelector { .. }))
+    ));
+}
+
+// -------------------------------------------------------------
+// Test: Writing the same object twice in one transaction fails
+// -------------------------------------------------------------
+#[test]
+fn native_rejects_duplicate_write() {
+    let key = signing_key(5);
+    let mut state = funded_state(&key);
+
+    let object = created_object_id(address_of(&key), &[45u8; 32]);
+
+    let outcome = run(&mut state, &key, 0, vec![
+        call_cell(object, Some((WriteIntent::Create, 0)), SELECTOR_CREATE, encode_create_payload(&[45u8; 32], &[1])),
+        call_cell(object, Some((WriteIntent::Create, 0)), SELECTOR_CREATE, encode_create_payload(&[45u8; 32], &[2])),
+    ]).unwrap();
+
+    assert!(matches!(
+        outcome.failure,
+        Some(ProtocolError::ExecutionError(ExecutionError::DuplicateWrite { .. }))
+    ));
+    assert!(state.get(&object).is_none());
+}
+
+// -------------------------------------------------------------
+// Test: Generic writes cannot touch nonce objects
+// -------------------------------------------------------------
+#[test]
+fn native_write_rejects_nonce_object() {
+    let key = signing_key(9);
+    let mut state = funded_state(&key);
+    let nonce_id = axiom_state::nonce_object_id(address_of(&key));
+
+    // First transaction creates the nonce object
+    assert!(run(&mut state, &key, 0, vec![]).unwrap().is_success());
+
+    let outcome = run(&mut state, &key, 0, vec![
+        call_cell(nonce_id, Some((WriteIntent::Modify, 0)), SELECTOR_WRITE, 0u64.to_le_bytes().to_vec()),
+    ]).unwrap();
+
+    assert!(matches!(
+        outcome.failure,
+        Some(ProtocolError::PlanningError(PlanningError::ProtectedObject { .. }))
+    ));
+    // Only the protocol's own nonce charge is applied
+    assert_eq!(state.get(&nonce_id).unwrap().version(), 1);
+    assert_eq!(outcome.fee_charged, GAS_LIMIT);
+}
+
+// -------------------------------------------------------------
+// Test: Objects cannot be created at ids not derived from the signer
+// -------------------------------------------------------------
+#[test]
+fn native_create_rejects_underived_id() {
+    let key = signing_key(6);
+    let victim = address_of(&signing_key(7));
+    let mut state = funded_state(&key);
+
+    // Squatting the victim's balance object would mint funds
+    let object = balance_object_id(victim);
+
+    let outcome = run(&mut state, &key, 0, vec![
+        call_cell(object, Some((WriteIntent::Create, 0)), SELECTOR_CREATE, encode_create_payload(&[0u8; 32], &encode_balance(1_000))),
+    ]).unwrap();
+
+    assert!(matches!(
+        outcome.failure,
+        Some(ProtocolError::ExecutionError(ExecutionError::UnauthorizedWrite { .. }))
+    ));
+    assert!(state.get(&object).is_none());
+}
+
+// -------------------------------------------------------------
+// Test: Generic writes cannot touch balance objects
+// -------------------------------------------------------------
+#[test]
+fn native_write_rejects_balance_object() {
+    let key = signing_key(8);
+    let mut state = funded_state(&key);
+    let balance_id = balance_object_id(address_of(&key));
+
+    let outcome = run(&mut state, &key, 0, vec![
+        call_cell(balance_id, Some((WriteIntent::Modify, 0)), SELECTOR_WRITE, encode_balance(1_000)),
+    ]).unwrap();
+
+    assert!(matches!(
+        outcome.failure,
+        Some(ProtocolError::ExecutionError(ExecutionError::UnauthorizedWrite { .. }))
+    ));
+    assert_eq!(balance_of(&state, address_of(&key)), FUNDS - GAS_LIMIT);
+}
+
+// -------------------------------------------------------------
+// Test: Deleted objects leave a tombstone and change the root
+// -------------------------------------------------------------
+#[test]
+fn native_delete_removes_object() {
+    let key = signing_key(9);
+    let signer = address_of(&key);
+    let mut state = funded_state(&key);
+
+    let object = created_object_id(signer, &[46u8; 32]);
+
+    assert!(run(&mut state, &key, 0, vec![
+        call_cell(object, Some((WriteIntent::Create, 0)), SELECTOR_CREATE, encode_create_payload(&[46u8; 32], &[1])),
+    ]).unwrap().is_success());
+
+    let root_before = compute_state_root(&state);
+
+    assert!(run(&mut state, &key, 0, vec![
+        call_cell(object, Some((WriteIntent::Delete, 0)), SELECTOR_DELETE, vec![]),
+    ]).unwrap().is_success());
+
+    assert!(state.get(&object).is_none());
+    assert_eq!(state.tombstone(&object), Some(1));
+    assert_ne!(compute_state_root(&state), root_before);
+
+    // Deleted objects can no longer be written
+    let outcome = run(&mut state, &key, 1, vec![
+        call_cell(object, Some((WriteIntent::Modify, 0)), SELECTOR_WRITE, vec![2]),
+    ]).unwrap();
+    assert!(matches!(outcome.failure, Some(ProtocolError::PlanningError(_))));
+}
+
+// -------------------------------------------------------------
+// Test: Re-created objects continue after their tombstone
+// -------------------------------------------------------------
+#[test]
+fn native_recreate_continues_after_tombstone() {
+    let key = signing_key(10);
+    let signer = address_of(&key);
+    let mut state = funded_state(&key);
+
+    let salt = [47u8; 32];
+    let object = created_object_id(signer, &salt);
+
+    assert!(run(&mut state, &key, 0, vec![
+        call_cell(object, Some((WriteIntent::Create, 0)), SELECTOR_CREATE, encode_create_payload(&salt, &[1])),
+    ]).unwrap().is_success());
+    assert!(run(&mut state, &key, 0, vec![
+        call_cell(object, Some((WriteIntent::Delete, 0)), SELECTOR_DELETE, vec![]),
+    ]).unwrap().is_success());
+    assert!(run(&mut state, &key, 1, vec![
+        call_cell(object, Some((WriteIntent::Create, 0)), SELECTOR_CREATE, encode_create_payload(&salt, &[2])),
+    ]).unwrap().is_success());
+
+    let recreated = state.get(&object).unwrap();
+    assert_eq!(recreated.version(), 2);
+    assert_eq!(recreated.data(), &[2]);
+    assert_eq!(state.tombstone(&object), None);
+
+    // Reads taken before the deletion no longer validate
+    let outcome = run(&mut state, &key, 2, vec![
+        call_cell(object, Some((WriteIntent::Modify, 0)), SELECTOR_WRITE, vec![3]),
+    ]).unwrap();
+    assert!(!outcome.is_success());
+    assert_eq!(state.get(&object).unwrap().data(), &[2]);
+}
+
+// -------------------------------------------------------------
+// Test: Deleted ids cannot be re-inserted at a stale version
+// -------------------------------------------------------------
+#[test]
+fn state_rejects_stale_recreation() {
+    let owner = address_of(&signing_key(11));
+    let object = ObjectId::new(Hash::new([48u8; 32]));
+
+    let mut state = StateStore::new();
+    state.insert(StateObject::new(object, owner, vec![1])).unwrap();
+    state.remove(&object, 1).unwrap();
+
+    assert!(state.insert(StateObject::new(object, owner, vec![1])).is_err());
+    assert!(state.insert_or_update(StateObject::recreate(object, owner, vec![1], 0)).is_err());
+
+    state.insert(StateObject::recreate(object, owner, vec![2], 1)).unwrap();
+    assert_eq!(state.get(&object).unwrap().version(), 2);
+}
@@ -1,20 +1,33 @@
 use std::collections::{HashMap, BTreeMap};
 
-use axiom_protocol::process_external_transaction;
-use axiom_execution_engine::{ReferenceExecutionEngine, ExecutionContext};
+use axiom_protocol::{process_external_transaction, ProtocolError, ChainConfig};
+use axiom_execution::PlanningError;
+use axiom_execution_engine::{ReferenceExecutionEngine, ExecutionContext, GasSchedule};
 use axiom_state::{StateStore, StateObject};
 use axiom_state::nonce::nonce_object_id;
 use axiom_state::balance::{balance_object_id, encode_balance, decode_balance};
-use axiom_ext_tx::{ExternalTransaction, Signature};
-use axiom_tx::{TransactionCell, CallData};
-use axiom_types::{Address, Slot, Epoch, ObjectId};
+use axiom_ext_tx::{ExternalTransaction, Signature, SchemeId, SignatureError, address_from_public_key, ValidityWindow};
+use ed25519_dalek::{Signer, SigningKey};
+use axiom_tx::{TransactionCell, CallData, WriteIntent};
+use axiom_types::{Address, Slot, Epoch, ObjectId, Hash};
+
+const CHAIN_ID: u64 = 1;
+
+/// Gas limit of every test transaction at a base fee of 1, and a balance
+/// that affords ten of them.
+const GAS_LIMIT: u64 = 1_000;
+const FUNDS: u64 = 10 * GAS_LIMIT;
+
+/// Gas used by a transaction running one `make_cell`.
+fn call_gas() -> u64 {
+    GasSchedule::default().call
+}
 
 // -------------------------------------------------------------
 // Helper: create a minimal valid TransactionCell
 // -------------------------------------------------------------
 fn make_cell(target: ObjectId) -> TransactionCell {
     TransactionCell::new(
-        Slot::new(1),
         HashMap::new(),
         BTreeMap::new(),
         CallData {
@@ -25,30 +38,66 @@ fn make_cell(target: ObjectId) -> TransactionCell {
     ).unwrap()
 }
 
+// -------------------------------------------------------------
+// Helper: deterministic signing key and the address it controls
+// -------------------------------------------------------------
+fn signing_key(seed: u8) -> SigningKey {
+    SigningKey::from_bytes(&[seed; 32])
+}
+
+fn address_of(key: &SigningKey) -> Address {
+    address_from_public_key(SchemeId::Ed25519, key.verifying_key().as_bytes())
+}
+
+// -------------------------------------------------------------
+// Helper: sign a transaction with the given key
+// -------------------------------------------------------------
+fn unsigned() -> Signature {
+    Signature { scheme: SchemeId::Ed25519, public_key: vec![], bytes: vec![] }
+}
+
+fn sign(key: &SigningKey, mut tx: ExternalTransaction) -> ExternalTransaction {
+    let signature = key.sign(tx.signing_hash().as_bytes());
+    tx.signature = Signature {
+        scheme: SchemeId::Ed25519,
+        public_key: key.verifying_key().to_bytes().to_vec(),
+        bytes: signature.to_bytes().to_vec(),
+    };
+    tx
+}
+
 // -------------------------------------------------------------
 // Test: Valid transaction succeeds and mutates state correctly
 // -------------------------------------------------------------
 #[test]
 fn pipeline_valid_transaction_succeeds() {
     let mut state = StateStore::new();
-    let signer = Address::new([1u8; 32]);
+    let key = signing_key(1);
+    let signer = address_of(&key);
 
     let balance_id = balance_object_id(signer);
-    state.insert(StateObject::new(balance_id, signer, encode_balance(10))).unwrap();
+    state.insert(StateObject::new(balance_id, signer, encode_balance(FUNDS))).unwrap();
 
-    let tx = ExternalTransaction {
+    let tx = sign(&key, ExternalTransaction {
+        chain_id: CHAIN_ID,
         signer,
         nonce: 0,
+        validity: ValidityWindow::unbounded(),
+        max_fee: 1,
+        priority_fee: 0,
+        gas_limit: GAS_LIMIT,
         cells: vec![make_cell(balance_id)],
-        signature: Signature { bytes: vec![] },
-    };
+        signature: unsigned(),
+        approvals: vec![],
+    });
 
-    let engine = ReferenceExecutionEngine::default();
-    let ctx = ExecutionContext { slot: Slot::new(1), epoch: Epoch::new(0) };
+    let engine = ReferenceExecutionEngine;
+    let ctx = ExecutionContext::new(Slot::new(1), Epoch::new(0), 1);
+    let config = ChainConfig::standard(CHAIN_ID);
 
-    process_external_transaction(&mut state, tx, &engine, ctx).unwrap();
+    process_external_transaction(&mut state, tx, &engine, ctx, &config).unwrap();
 
-    assert_eq!(decode_balance(state.get(&balance_id).unwrap()), 9);
+    assert_eq!(decode_balance(state.get(&balance_id).unwrap()), FUNDS - call_gas());
     assert!(state.get(&nonce_object_id(signer)).is_some());
 }
 
@@ -58,25 +107,33 @@ fn pipeline_valid_transaction_succeeds() {
 #[test]
 fn pipeline_rejects_invalid_nonce() {
     let mut state = StateStore::new();
-    let signer = Address::new([2u8; 32]);
+    let key = signing_key(2);
+    let signer = address_of(&key);
 
     let balance_id = balance_object_id(signer);
-    state.insert(StateObject::new(balance_id, signer, encode_balance(10))).unwrap();
+    state.insert(StateObject::new(balance_id, signer, encode_balance(FUNDS))).unwrap();
 
-    let tx = ExternalTransaction {
+    let tx = sign(&key, ExternalTransaction {
+        chain_id: CHAIN_ID,
         signer,
         nonce: 1, // ❌ invalid
+        validity: ValidityWindow::unbounded(),
+        max_fee: 1,
+        priority_fee: 0,
+        gas_limit: GAS_LIMIT,
         cells: vec![make_cell(balance_id)],
-        signature: Signature { bytes: vec![] },
-    };
+        signature: unsigned(),
+        approvals: vec![],
+    });
 
-    let engine = ReferenceExecutionEngine::default();
-    let ctx = ExecutionContext { slot: Slot::new(1), epoch: Epoch::new(0) };
+    let engine = ReferenceExecutionEngine;
+    let ctx = ExecutionContext::new(Slot::new(1), Epoch::new(0), 1);
+    let config = ChainConfig::standard(CHAIN_ID);
 
-    assert!(process_external_transaction(&mut state, tx, &engine, ctx).is_err());
+    assert!(process_external_transaction(&mut state, tx, &engine, ctx, &config).is_err());
 
     assert!(state.get(&nonce_object_id(signer)).is_none());
-    assert_eq!(decode_balance(state.get(&balance_id).unwrap()), 10);
+    assert_eq!(decode_balance(state.get(&balance_id).unwrap()), FUNDS);
 }
 
 // -------------------------------------------------------------
@@ -85,51 +142,74 @@ fn pipeline_rejects_invalid_nonce() {
 #[test]
 fn pipeline_rejects_stale_nonce() {
     let mut state = StateStore::new();
-    let signer = Address::new([3u8; 32]);
+    let key = signing_key(3);
+    let signer = address_of(&key);
 
     let balance_id = balance_object_id(signer);
-    state.insert(StateObject::new(balance_id, signer, encode_balance(10))).unwrap();
+    state.insert(StateObject::new(balance_id, signer, encode_balance(FUNDS))).unwrap();
 
-    let engine = ReferenceExecutionEngine::default();
-    let ctx = ExecutionContext { slot: Slot::new(1), epoch: Epoch::new(0) };
+    let engine = ReferenceExecutionEngine;
+    let ctx = ExecutionContext::new(Slot::new(1), Epoch::new(0), 1);
+    let config = ChainConfig::standard(CHAIN_ID);
 
     // tx #1 (nonce = 0) → creates nonce object
     process_external_transaction(
         &mut state,
-        ExternalTransaction {
+        sign(&key, ExternalTransaction {
+            chain_id: CHAIN_ID,
             signer,
             nonce: 0,
+            validity: ValidityWindow::unbounded(),
+            max_fee: 1,
+            priority_fee: 0,
+            gas_limit: GAS_LIMIT,
             cells: vec![make_cell(balance_id)],
-            signature: Signature { bytes: vec![] },
-        },
+            signature: unsigned(),
+            approvals: vec![],
+        }),
         &engine,
         ctx,
+        &config,
     ).unwrap();
 
     // tx #2 (nonce = 0) → increments nonce to 1
     process_external_transaction(
         &mut state,
-        ExternalTransaction {
+        sign(&key, ExternalTransaction {
+            chain_id: CHAIN_ID,
             signer,
             nonce: 0,
+            validity: ValidityWindow::unbounded(),
+            max_fee: 1,
+            priority_fee: 0,
+            gas_limit: GAS_LIMIT,
             cells: vec![make_cell(balance_id)],
-            signature: Signature { bytes: vec![] },
-        },
+            signature: unsigned(),
+            approvals: vec![],
+        }),
         &engine,
         ctx,
+        &config,
     ).unwrap();
 
     // tx #3 with stale nonce = 0 ❌
     let result = process_external_transaction(
         &mut state,
-        ExternalTransaction {
+        sign(&key, ExternalTransaction {
+            chain_id: CHAIN_ID,
             signer,
             nonce: 0, // stale
+            validity: ValidityWindow::unbounded(),
+            max_fee: 1,
+            priority_fee: 0,
+            gas_limit: GAS_LIMIT,
             cells: vec![make_cell(balance_id)],
-            signature: Signature { bytes: vec![] },
-        },
+            signature: unsigned(),
+            approvals: vec![],
+        }),
         &engine,
         ctx,
+        &config,
     );
 
     assert!(result.is_err());
@@ -142,58 +222,81 @@ fn pipeline_rejects_stale_nonce() {
 #[test]
 fn pipeline_sequential_transactions_increment_nonce() {
     let mut state = StateStore::new();
-    let signer = Address::new([4u8; 32]);
+    let key = signing_key(4);
+    let signer = address_of(&key);
 
     let balance_id = balance_object_id(signer);
-    state.insert(StateObject::new(balance_id, signer, encode_balance(10))).unwrap();
+    state.insert(StateObject::new(balance_id, signer, encode_balance(FUNDS))).unwrap();
 
-    let engine = ReferenceExecutionEngine::default();
-    let ctx = ExecutionContext { slot: Slot::new(1), epoch: Epoch::new(0) };
+    let engine = ReferenceExecutionEngine;
+    let ctx = ExecutionContext::new(Slot::new(1), Epoch::new(0), 1);
+    let config = ChainConfig::standard(CHAIN_ID);
 
     // tx #1
     process_external_transaction(
         &mut state,
-        ExternalTransaction {
+        sign(&key, ExternalTransaction {
+            chain_id: CHAIN_ID,
             signer,
             nonce: 0,
+            validity: ValidityWindow::unbounded(),
+            max_fee: 1,
+            priority_fee: 0,
+            gas_limit: GAS_LIMIT,
             cells: vec![make_cell(balance_id)],
-            signature: Signature { bytes: vec![] },
-        },
+            signature: unsigned(),
+            approvals: vec![],
+        }),
         &engine,
         ctx,
+        &config,
     ).unwrap();
 
     // tx #2
     process_external_transaction(
         &mut state,
-        ExternalTransaction {
+        sign(&key, ExternalTransaction {
+            chain_id: CHAIN_ID,
             signer,
             nonce: 0,
+            validity: ValidityWindow::unbounded(),
+            max_fee: 1,
+            priority_fee: 0,
+            gas_limit: GAS_LIMIT,
             cells: vec![make_cell(balance_id)],
-            signature: Signature { bytes: vec![] },
-        },
+            signature: unsigned(),
+            approvals: vec![],
+        }),
         &engine,
         ctx,
+        &config,
     ).unwrap();
 
     // tx #3
     process_external_transaction(
         &mut state,
-        ExternalTransaction {
+        sign(&key, ExternalTransaction {
+            chain_id: CHAIN_ID,
             signer,
             nonce: 1,
+            validity: ValidityWindow::unbounded(),
+            max_fee: 1,
+            priority_fee: 0,
+            gas_limit: GAS_LIMIT,
             cells: vec![make_cell(balance_id)],
-            signature: Signature { bytes: vec![] },
-        },
+            signature: unsigned(),
+            approvals: vec![],
+        }),
         &engine,
         ctx,
+        &config,
     ).unwrap();
 
     let nonce_obj = state.get(&nonce_object_id(signer)).unwrap();
     assert_eq!(nonce_obj.version(), 2);
 
     let balance = decode_balance(state.get(&balance_id).unwrap());
-    assert_eq!(balance, 7);
+    assert_eq!(balance, FUNDS - 3 * call_gas());
 }
 
 
@@ -203,25 +306,39 @@ fn pipeline_sequential_transactions_increment_nonce() {
 #[test]
 fn pipeline_rejects_insufficient_balance() {
     let mut state = StateStore::new();
-    let signer = Address::new([5u8; 32]);
+    let key = signing_key(5);
+    let signer = address_of(&key);
 
     let balance_id = balance_object_id(signer);
-    state.insert(StateObject::new(balance_id, signer, encode_balance(0))).unwrap();
+    // Enough for the gas the call uses, not for the whole limit
+    state.insert(StateObject::new(balance_id, signer, encode_balance(GAS_LIMIT - 1))).unwrap();
 
-    let tx = ExternalTransaction {
+    let tx = sign(&key, ExternalTransaction {
+        chain_id: CHAIN_ID,
         signer,
         nonce: 0,
+        validity: ValidityWindow::unbounded(),
+        max_fee: 1,
+        priority_fee: 0,
+        gas_limit: GAS_LIMIT,
         cells: vec![make_cell(balance_id)],
-        signature: Signature { bytes: vec![] },
-    };
+        signature: unsigned(),
+        approvals: vec![],
+    });
 
-    let engine = ReferenceExecutionEngine::default();
-    let ctx = ExecutionContext { slot: Slot::new(1), epoch: Epoch::new(0) };
+    let engine = ReferenceExecutionEngine;
+    let ctx = ExecutionContext::new(Slot::new(1), Epoch::new(0), 1);
+    let config = ChainConfig::standard(CHAIN_ID);
 
-    assert!(process_external_transaction(&mut state, tx, &engine, ctx).is_err());
+    let result = process_external_transaction(&mut state, tx, &engine, ctx, &config);
+    assert!(matches!(
+        result,
+        Err(ProtocolError::PlanningError(PlanningError::InsufficientBalance { available, required }))
+            if available == GAS_LIMIT - 1 && required == GAS_LIMIT
+    ));
 
     assert!(state.get(&nonce_object_id(signer)).is_none());
-    assert_eq!(decode_balance(state.get(&balance_id).unwrap()), 0);
+    assert_eq!(decode_balance(state.get(&balance_id).unwrap()), GAS_LIMIT - 1);
 }
 
 // -------------------------------------------------------------
@@ -230,27 +347,35 @@ fn pipeline_rejects_insufficient_balance() {
 #[test]
 fn pipeline_failure_is_atomic() {
     let mut state = StateStore::new();
-    let signer = Address::new([6u8; 32]);
+    let key = signing_key(6);
+    let signer = address_of(&key);
 
     let balance_id = balance_object_id(signer);
-    state.insert(StateObject::new(balance_id, signer, encode_balance(5))).unwrap();
+    state.insert(StateObject::new(balance_id, signer, encode_balance(FUNDS))).unwrap();
 
     // Snapshot observable state
     let balance_before = decode_balance(state.get(&balance_id).unwrap());
     let nonce_id = nonce_object_id(signer);
     let nonce_exists_before = state.get(&nonce_id).is_some();
 
-    let tx = ExternalTransaction {
+    let tx = sign(&key, ExternalTransaction {
+        chain_id: CHAIN_ID,
         signer,
         nonce: 1, // invalid
+        validity: ValidityWindow::unbounded(),
+        max_fee: 1,
+        priority_fee: 0,
+        gas_limit: GAS_LIMIT,
         cells: vec![make_cell(balance_id)],
-        signature: Signature { bytes: vec![] },
-    };
+        signature: unsigned(),
+        approvals: vec![],
+    });
 
-    let engine = ReferenceExecutionEngine::default();
-    let ctx = ExecutionContext { slot: Slot::new(1), epoch: Epoch::new(0) };
+    let engine = ReferenceExecutionEngine;
+    let ctx = ExecutionContext::new(Slot::new(1), Epoch::new(0), 1);
+    let config = ChainConfig::standard(CHAIN_ID);
 
-    let _ = process_external_transaction(&mut state, tx, &engine, ctx);
+    let _ = process_external_transaction(&mut state, tx, &engine, ctx, &config);
 
     // Assert state unchanged
     let balance_after = decode_balance(state.get(&balance_id).unwrap());
@@ -259,3 +384,305 @@ fn pipeline_failure_is_atomic() {
     assert_eq!(balance_before, balance_after);
     assert_eq!(nonce_exists_before, nonce_exists_after);
 }
+
+// -------------------------------------------------------------
+// Test: Charged failure pays the fee and consumes the nonce
+// -------------------------------------------------------------
+#[test]
+fn pipeline_charges_failed_transaction() {
+    let mut state = StateStore::new();
+    let key = signing_key(13);
+    let signer = address_of(&key);
+
+    let balance_id = balance_object_id(signer);
+    state.insert(StateObject::new(balance_id, signer, encode_balance(FUNDS))).unwrap();
+
+    // Modifying an object that does not exist fails planning
+    let missing = ObjectId::new(Hash::new([77u8; 32]));
+    let cell = TransactionCell::new(
+        HashMap::from([(missing, 0)]),
+        BTreeMap::from([(missing, WriteIntent::Modify)]),
+        CallData { target: missing, selector: vec![], payload: vec![] },
+    ).unwrap();
+
+    let tx = sign(&key, ExternalTransaction {
+        chain_id: CHAIN_ID,
+        signer,
+        nonce: 0,
+        validity: ValidityWindow::unbounded(),
+        max_fee: 1,
+        priority_fee: 0,
+        gas_limit: GAS_LIMIT,
+        cells: vec![cell],
+        signature: unsigned(),
+        approvals: vec![],
+    });
+
+    let engine = ReferenceExecutionEngine;
+    let ctx = ExecutionContext::new(Slot::new(1), Epoch::new(0), 1);
+    let config = ChainConfig::standard(CHAIN_ID);
+
+    let outcome = process_external_transaction(&mut state, tx, &engine, ctx, &config).unwrap();
+
+    assert!(!outcome.is_success());
+    assert!(matches!(
+        outcome.failure,
+        Some(ProtocolError::PlanningError(PlanningError::ObjectNotFound { object })) if object == missing
+    ));
+    // A failure consumes the whole gas limit
+    assert_eq!(outcome.gas_used, GAS_LIMIT);
+    assert_eq!(outcome.fee_charged, GAS_LIMIT);
+    assert_eq!(decode_balance(state.get(&balance_id).unwrap()), FUNDS - GAS_LIMIT);
+    assert!(state.get(&nonce_object_id(signer)).is_some());
+}
+
+// -------------------------------------------------------------
+// Test: Transaction signed by a different key is rejected
+// -------------------------------------------------------------
+#[test]
+fn pipeline_rejects_forged_signer() {
+    let mut state = StateStore::new();
+    let key = signing_key(7);
+    let signer = address_of(&key);
+    let attacker = signing_key(8);
+
+    let balance_id = balance_object_id(signer);
+    state.insert(StateObject::new(balance_id, signer, encode_balance(FUNDS))).unwrap();
+
+    // Attacker claims to be `signer` but signs with their own key
+    let tx = sign(&attacker, ExternalTransaction {
+        chain_id: CHAIN_ID,
+        signer,
+        nonce: 0,
+        validity: ValidityWindow::unbounded(),
+        max_fee: 1,
+        priority_fee: 0,
+        gas_limit: GAS_LIMIT,
+        cells: vec![make_cell(balance_id)],
+        signature: unsigned(),
+        approvals: vec![],
+    });
+
+    let engine = ReferenceExecutionEngine;
+    let ctx = ExecutionContext::new(Slot::new(1), Epoch::new(0), 1);
+    let config = ChainConfig::standard(CHAIN_ID);
+
+    let result = process_external_transaction(&mut state, tx, &engine, ctx, &config);
+
+    assert!(matches!(
+        result,
+        Err(ProtocolError::SignatureError(SignatureError::SignerMismatch { .. }))
+    ));
+    assert!(state.get(&nonce_object_id(signer)).is_none());
+    assert_eq!(decode_balance(state.get(&balance_id).unwrap()), FUNDS);
+}
+
+// -------------------------------------------------------------
+// Test: Tampering with a signed transaction invalidates it
+// -------------------------------------------------------------
+#[test]
+fn pipeline_rejects_tampered_transaction() {
+    let mut state = StateStore::new();
+    let key = signing_key(9);
+    let signer = address_of(&key);
+
+    let balance_id = balance_object_id(signer);
+    state.insert(StateObject::new(balance_id, signer, encode_balance(FUNDS))).unwrap();
+
+    let mut tx = sign(&key, ExternalTransaction {
+        chain_id: CHAIN_ID,
+        signer,
+        nonce: 0,
+        validity: ValidityWindow::unbounded(),
+        max_fee: 1,
+        priority_fee: 0,
+        gas_limit: GAS_LIMIT,
+        cells: vec![make_cell(balance_id)],
+        signature: unsigned(),
+        approvals: vec![],
+    });
+
+    // Nonce changed after signing
+    tx.nonce = 1;
+
+    let engine = ReferenceExecutionEngine;
+    let ctx = ExecutionContext::new(Slot::new(1), Epoch::new(0), 1);
+    let config = ChainConfig::standard(CHAIN_ID);
+
+    let result = process_external_transaction(&mut state, tx, &engine, ctx, &config);
+
+    assert!(matches!(
+        result,
+        Err(ProtocolError::SignatureError(SignatureError::VerificationFailed))
+    ));
+}
+
+// -------------------------------------------------------------
+// Test: Unsigned transaction is rejected before nonce validation
+// -------------------------------------------------------------
+#[test]
+fn pipeline_rejects_missing_signature() {
+    let mut state = StateStore::new();
+    let key = signing_key(10);
+    let signer = address_of(&key);
+
+    let balance_id = balance_object_id(signer);
+    state.insert(StateObject::new(balance_id, signer, encode_balance(FUNDS))).unwrap();
+
+    // Nonce is also invalid; signature failure must be reported first
+    let tx = ExternalTransaction {
+        chain_id: CHAIN_ID,
+        signer,
+        nonce: 3,
+        validity: ValidityWindow::unbounded(),
+        max_fee: 1,
+        priority_fee: 0,
+        gas_limit: GAS_LIMIT,
+        cells: vec![make_cell(balance_id)],
+        signature: unsigned(),
+        approvals: vec![],
+    };
+
+    let engine = ReferenceExecutionEngine;
+    let ctx = ExecutionContext::new(Slot::new(1), Epoch::new(0), 1);
+    let config = ChainConfig::standard(CHAIN_ID);
+
+    let result = process_external_transaction(&mut state, tx, &engine, ctx, &config);
+
+    assert!(matches!(
+        result,
+        Err(ProtocolError::SignatureError(SignatureError::SignerMismatch { .. }))
+    ));
+}
+
+// -------------------------------------------------------------
+// Test: Transactions signed for another chain are rejected
+// -------------------------------------------------------------
+#[test]
+fn pipeline_rejects_other_chain() {
+    let mut state = StateStore::new();
+    let key = signing_key(20);
+    let signer = address_of(&key);
+
+    let balance_id = balance_object_id(signer);
+    state.insert(StateObject::new(balance_id, signer, encode_balance(FUNDS))).unwrap();
+
+    let testnet_tx = sign(&key, ExternalTransaction {
+        chain_id: CHAIN_ID + 1,
+        signer,
+        nonce: 0,
+        validity: ValidityWindow::unbounded(),
+        max_fee: 1,
+        priority_fee: 0,
+        gas_limit: GAS_LIMIT,
+        cells: vec![make_cell(balance_id)],
+        signature: unsigned(),
+        approvals: vec![],
+    });
+
+    let engine = ReferenceExecutionEngine;
+    let ctx = ExecutionContext::new(Slot::new(1), Epoch::new(0), 1);
+    let config = ChainConfig::standard(CHAIN_ID);
+
+    let result = process_external_transaction(&mut state, testnet_tx.clone(), &engine, ctx, &config);
+    assert!(matches!(
+        result,
+        Err(ProtocolError::WrongChain { expected: CHAIN_ID, found }) if found == CHAIN_ID + 1
+    ));
+
+    // Relabelling the chain id breaks the signature
+    let mut replayed = testnet_tx;
+    replayed.chain_id = CHAIN_ID;
+    let result = process_external_transaction(&mut state, replayed, &engine, ctx, &config);
+    assert!(matches!(
+        result,
+        Err(ProtocolError::SignatureError(SignatureError::VerificationFailed))
+    ));
+
+    assert!(state.get(&nonce_object_id(signer)).is_none());
+    assert_eq!(decode_balance(state.get(&balance_id).unwrap()), FUNDS);
+}
+
+// -------------------------------------------------------------
+// Test: Transactions only execute inside their validity window
+// -------------------------------------------------------------
+#[test]
+fn pipeline_enforces_validity_window() {
+    let key = signing_key(21);
+    let signer = address_of(&key);
+    let balance_id = balance_object_id(signer);
+
+    let tx = sign(&key, ExternalTransaction {
+        chain_id: CHAIN_ID,
+        signer,
+        nonce: 0,
+        validity: ValidityWindow::new(Slot::new(10), Slot::new(20)),
+        max_fee: 1,
+        priority_fee: 0,
+        gas_limit: GAS_LIMIT,
+        cells: vec![make_cell(balance_id)],
+        signature: unsigned(),
+        approvals: vec![],
+    });
+
+    let engine = ReferenceExecutionEngine;
+    let config = ChainConfig::standard(CHAIN_ID);
+
+    let run = |slot: u64| {
+        let mut state = StateStore::new();
+        state.insert(StateObject::new(balance_id, signer, encode_balance(FUNDS))).unwrap();
+
+        let ctx = ExecutionContext::new(Slot::new(slot), Epoch::new(0), 1);
+        let result = process_external_transaction(&mut state, tx.clone(), &engine, ctx, &config);
+        (result, decode_balance(state.get(&balance_id).unwrap()))
+    };
+
+    // Bounds are inclusive
+    assert!(run(10).0.is_ok());
+    assert!(run(20).0.is_ok());
+
+    for slot in [9, 21] {
+        let (result, balance) = run(slot);
+        assert!(matches!(
+            result,
+            Err(ProtocolError::OutsideValidityWindow { slot: s, .. }) if s == Slot::new(slot)
+        ));
+        assert_eq!(balance, FUNDS);
+    }
+}
+
+// -------------------------------------------------------------
+// Test: Extending a signed validity window breaks the signature
+// -------------------------------------------------------------
+#[test]
+fn pipeline_validity_window_is_signed() {
+    let mut state = StateStore::new();
+    let key = signing_key(22);
+    let signer = address_of(&key);
+
+    let balance_id = balance_object_id(signer);
+    state.insert(StateObject::new(balance_id, signer, encode_balance(FUNDS))).unwrap();
+
+    let mut tx = sign(&key, ExternalTransaction {
+        chain_id: CHAIN_ID,
+        signer,
+        nonce: 0,
+        validity: ValidityWindow::new(Slot::new(0), Slot::new(5)),
+        max_fee: 1,
+        priority_fee: 0,
+        gas_limit: GAS_LIMIT,
+        cells: vec![make_cell(balance_id)],
+        signature: unsigned(),
+        approvals: vec![],
+    });
+    tx.validity.until = Slot::new(100);
+
+    let engine = ReferenceExecutionEngine;
+    let ctx = ExecutionContext::new(Slot::new(50), Epoch::new(0), 1);
+    let config = ChainConfig::standard(CHAIN_ID);
+
+    assert!(matches!(
+        process_external_transaction(&mut state, tx, &engine, ctx, &config),
+        Err(ProtocolError::SignatureError(SignatureError::VerificationFailed))
+    ));
+}
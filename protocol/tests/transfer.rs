@@ -0,0 +1,277 @@
+mod common;
+
+use std::collections::{HashMap, BTreeMap};
+
+use axiom_protocol::{ProcessOutcome, ProtocolError, ChainConfig};
+use axiom_execution_engine::{ExecutionError, TransferError};
+use axiom_execution_engine::native::{SELECTOR_TRANSFER, encode_transfer_payload};
+use axiom_state::{StateStore, StateObject};
+use axiom_state::balance::{balance_object_id, encode_balance};
+use axiom_ext_tx::{MultisigPolicy, SignatureError};
+use ed25519_dalek::SigningKey;
+use axiom_tx::{TransactionCell, CallData, WriteIntent};
+use axiom_types::Address;
+
+use common::{CHAIN_ID, signing_key, address_of, balance_of, nonce_version};
+
+/// Gas limit of every test transaction at a base fee of 1, and a balance
+/// that affords ten of them.
+const GAS_LIMIT: u64 = 10_000;
+const FUNDS: u64 = 10 * GAS_LIMIT;
+
+// -------------------------------------------------------------
+// Helper: transfer cell from `sender` to `recipient`
+// -------------------------------------------------------------
+fn transfer_cell(
+    sender: Address,
+    recipient: Address,
+    recipient_intent: WriteIntent,
+    amount: u64,
+) -> TransactionCell {
+    let source = balance_object_id(sender);
+    let target = balance_object_id(recipient);
+
+    let read_set = HashMap::from([(source, 0), (target, 0)]);
+    let write_set = BTreeMap::from([
+        (source, WriteIntent::Modify),
+        (target, recipient_intent),
+    ]);
+
+    TransactionCell::new(
+        read_set,
+        write_set,
+        CallData {
+            target: source,
+            selector: SELECTOR_TRANSFER.to_vec(),
+            payload: encode_transfer_payload(recipient, amount),
+        },
+    ).unwrap()
+}
+
+// -------------------------------------------------------------
+// Helper: sign and process a single-cell transaction
+// -------------------------------------------------------------
+fn run(
+    state: &mut StateStore,
+    key: &SigningKey,
+    cell: TransactionCell,
+) -> Result<ProcessOutcome, ProtocolError> {
+    run_approved(state, key, cell, None, &[])
+}
+
+// -------------------------------------------------------------
+// Helper: sign and process a transaction approved by `approvers`
+// -------------------------------------------------------------
+fn run_approved(
+    state: &mut StateStore,
+    key: &SigningKey,
+    cell: TransactionCell,
+    policy: Option<&MultisigPolicy>,
+    approvers: &[&SigningKey],
+) -> Result<ProcessOutcome, ProtocolError> {
+    let tx = common::sign(key, common::transaction(key, 0, GAS_LIMIT, vec![cell]), policy, approvers);
+
+    common::process(state, tx, &ChainConfig::standard(CHAIN_ID))
+}
+
+fn fund(state: &mut StateStore, address: Address, amount: u64) {
+    state.insert(StateObject::new(balance_object_id(address), address, encode_balance(amount))).unwrap();
+}
+
+// -------------------------------------------------------------
+// Test: Transfer creates the recipient's balance object
+// -------------------------------------------------------------
+#[test]
+fn transfer_creates_recipient_balance() {
+    let key = signing_key(1);
+    let sender = address_of(&key);
+    let recipient = address_of(&signing_key(2));
+
+    let mut state = StateStore::new();
+    fund(&mut state, sender, FUNDS);
+
+    let outcome = run(&mut state, &key, transfer_cell(sender, recipient, WriteIntent::Create, 3)).unwrap();
+
+    assert!(outcome.is_success());
+    assert_eq!(balance_of(&state, sender), FUNDS - outcome.fee_charged - 3);
+    assert_eq!(balance_of(&state, recipient), 3);
+    assert_eq!(state.get(&balance_object_id(recipient)).unwrap().owner(), recipient);
+}
+
+// -------------------------------------------------------------
+// Test: Transfer credits an existing recipient balance
+// -------------------------------------------------------------
+#[test]
+fn transfer_credits_existing_balance() {
+    let key = signing_key(3);
+    let sender = address_of(&key);
+    let recipient = address_of(&signing_key(4));
+
+    let mut state = StateStore::new();
+    fund(&mut state, sender, FUNDS);
+    fund(&mut state, recipient, 5);
+
+    let outcome = run(&mut state, &key, transfer_cell(sender, recipient, WriteIntent::Modify, 4)).unwrap();
+
+    assert!(outcome.is_success());
+    assert_eq!(balance_of(&state, sender), FUNDS - outcome.fee_charged - 4);
+    assert_eq!(balance_of(&state, recipient), 9);
+    assert_eq!(state.get(&balance_object_id(recipient)).unwrap().version(), 1);
+}
+
+// -------------------------------------------------------------
+// Test: Transfer cannot spend funds reserved for the gas limit
+// -------------------------------------------------------------
+#[test]
+fn transfer_rejects_insufficient_funds() {
+    let key = signing_key(5);
+    let sender = address_of(&key);
+    let recipient = address_of(&signing_key(6));
+
+    let mut state = StateStore::new();
+    fund(&mut state, sender, GAS_LIMIT + 9);
+
+    let outcome = run(&mut state, &key, transfer_cell(sender, recipient, WriteIntent::Create, 10)).unwrap();
+
+    assert!(matches!(
+        outcome.failure,
+        Some(ProtocolError::ExecutionError(ExecutionError::Transfer(
+            TransferError::InsufficientFunds { available: 9, required: 10 }
+        )))
+    ));
+
+    // Failed transfer still pays for its whole gas limit and uses its nonce
+    assert_eq!(outcome.fee_charged, GAS_LIMIT);
+    assert_eq!(balance_of(&state, sender), 9);
+    assert_eq!(nonce_version(&state, sender), Some(0));
+    assert!(state.get(&balance_object_id(recipient)).is_none());
+}
+
+// -------------------------------------------------------------
+// Test: Transfer rejects recipient balance overflow
+// -------------------------------------------------------------
+#[test]
+fn transfer_rejects_overflow() {
+    let key = signing_key(7);
+    let sender = address_of(&key);
+    let recipient = address_of(&signing_key(8));
+
+    let mut state = StateStore::new();
+    fund(&mut state, sender, FUNDS);
+    fund(&mut state, recipient, u64::MAX - 1);
+
+    let outcome = run(&mut state, &key, transfer_cell(sender, recipient, WriteIntent::Modify, 2)).unwrap();
+
+    assert!(matches!(
+        outcome.failure,
+        Some(ProtocolError::ExecutionError(ExecutionError::Transfer(
+            TransferError::BalanceOverflow { .. }
+        )))
+    ));
+    assert_eq!(balance_of(&state, recipient), u64::MAX - 1);
+    assert_eq!(balance_of(&state, sender), FUNDS - GAS_LIMIT);
+}
+
+// -------------------------------------------------------------
+// Test: Self transfers are rejected
+// -------------------------------------------------------------
+#[test]
+fn transfer_rejects_self_transfer() {
+    let key = signing_key(9);
+    let sender = address_of(&key);
+
+    let mut state = StateStore::new();
+    fund(&mut state, sender, FUNDS);
+
+    let source = balance_object_id(sender);
+    let cell = TransactionCell::new(
+        HashMap::from([(source, 0)]),
+        BTreeMap::from([(source, WriteIntent::Modify)]),
+        CallData {
+            target: source,
+            selector: SELECTOR_TRANSFER.to_vec(),
+            payload: encode_transfer_payload(sender, 1),
+        },
+    ).unwrap();
+
+    let outcome = run(&mut state, &key, cell).unwrap();
+
+    assert!(matches!(
+        outcome.failure,
+        Some(ProtocolError::ExecutionError(ExecutionError::Transfer(TransferError::SelfTransfer)))
+    ));
+    assert_eq!(balance_of(&state, sender), FUNDS - GAS_LIMIT);
+}
+
+// -------------------------------------------------------------
+// Test: Another account's balance cannot be used as the source
+// -------------------------------------------------------------
+#[test]
+fn transfer_rejects_foreign_source() {
+    let key = signing_key(10);
+    let sender = address_of(&key);
+    let victim = address_of(&signing_key(11));
+
+    let mut state = StateStore::new();
+    fund(&mut state, sender, FUNDS);
+    fund(&mut state, victim, 10);
+
+    // Victim's balance as the source, sender as the recipient
+    let cell = transfer_cell(victim, sender, WriteIntent::Modify, 5);
+
+    let outcome = run(&mut state, &key, cell).unwrap();
+
+    assert!(matches!(
+        outcome.failure,
+        Some(ProtocolError::ExecutionError(ExecutionError::Transfer(
+            TransferError::InvalidSource { .. }
+        )))
+    ));
+    assert_eq!(balance_of(&state, victim), 10);
+    assert_eq!(balance_of(&state, sender), FUNDS - GAS_LIMIT);
+}
+
+// -------------------------------------------------------------
+// Test: A multisig treasury can spend its balance with enough approvals
+// -------------------------------------------------------------
+#[test]
+fn transfer_from_treasury_with_approvals() {
+    let members = [signing_key(12), signing_key(13), signing_key(14)];
+    let policy = MultisigPolicy::new(2, members.iter().map(address_of).collect()).unwrap();
+    let treasury = policy.address();
+    let recipient = address_of(&signing_key(15));
+
+    let submitter = &members[0];
+    let mut state = StateStore::new();
+    fund(&mut state, address_of(submitter), FUNDS);
+    fund(&mut state, treasury, 100);
+
+    // One approval is below the threshold, which rejects the transaction
+    let result = run_approved(
+        &mut state,
+        submitter,
+        transfer_cell(treasury, recipient, WriteIntent::Create, 40),
+        Some(&policy),
+        &[&members[1]],
+    );
+    assert!(matches!(
+        result,
+        Err(ProtocolError::SignatureError(SignatureError::ThresholdNotMet { required: 2, provided: 1, .. }))
+    ));
+    assert_eq!(balance_of(&state, treasury), 100);
+    assert_eq!(balance_of(&state, address_of(submitter)), FUNDS);
+
+    // Two approvals meet it; the submitter pays only the fees
+    let outcome = run_approved(
+        &mut state,
+        submitter,
+        transfer_cell(treasury, recipient, WriteIntent::Create, 40),
+        Some(&policy),
+        &[&members[1], &members[2]],
+    ).unwrap();
+
+    assert!(outcome.is_success());
+    assert_eq!(balance_of(&state, treasury), 60);
+    assert_eq!(balance_of(&state, recipient), 40);
+    assert_eq!(balance_of(&state, address_of(submitter)), FUNDS - outcome.fee_charged);
+}
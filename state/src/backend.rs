@@ -0,0 +1,255 @@
+
+// Imports and dependencies
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use axiom_types::ObjectId;
+use crate::smt::SparseMerkleTree;
+use crate::{ObjectWrite, StateError, StateObject, Version};
+
+// -------------------------------------------------------------------------------------------------------------------------- //
+
+//----------------------------------------------- State Backend -----------------------------------------------//
+
+/// Changes committed to a backend as one atomic unit (typically one block).
+///
+/// Ordered by object id so every backend sees the same sequence.
+pub type WriteBatch = BTreeMap<ObjectId, ObjectWrite>;
+
+/// Storage behind a `StateStore`.
+///
+/// Backends hold committed state only; the store stages writes and hands
+/// them over with `commit`. A commit MUST be atomic: after a crash the
+/// backend reflects either all of a batch or none of it.
+///
+/// Each batch is committed at a block height. Backends retain earlier
+/// versions so state can be read as of any height not yet pruned.
+pub trait StateBackend: Send {
+    /// Fetch a committed object.
+    fn get(&self, id: &ObjectId) -> Option<&StateObject>;
+
+    /// Fetch the tombstone version of a committed deletion.
+    fn tombstone(&self, id: &ObjectId) -> Option<Version>;
+
+    /// Iterate over all committed objects.
+    fn objects(&self) -> Box<dyn Iterator<Item = &StateObject> + '_>;
+
+    /// Iterate over all committed tombstones.
+    fn tombstones(&self) -> Box<dyn Iterator<Item = (ObjectId, Version)> + '_>;
+
+    /// Height of the last committed batch, if any.
+    fn height(&self) -> Option<u64>;
+
+    /// Fail unless state at `height` is committed and not pruned.
+    fn check_readable(&self, height: u64) -> Result<(), StateError>;
+
+    /// Fetch the last write to `id` at or before `height`.
+    ///
+    /// Fails if `height` is above the committed height or was pruned.
+    fn get_at(&self, id: &ObjectId, height: u64) -> Result<Option<&ObjectWrite>, StateError>;
+
+    /// Ids written by batches committed above `height`.
+    ///
+    /// Fails if `height` is not readable.
+    fn changed_above(&self, height: u64) -> Result<BTreeSet<ObjectId>, StateError>;
+
+    /// Forget versions only needed to read heights below `height`.
+    fn prune(&mut self, height: u64);
+
+    /// Atomically apply `batch` as the state at `height`.
+    ///
+    /// `height` MUST be above the current height.
+    fn commit(&mut self, height: u64, batch: &WriteBatch) -> Result<(), StateError>;
+
+    /// Discard every batch above `height`, restoring the state at `height`.
+    ///
+    /// Fails if `height` is not readable.
+    fn revert(&mut self, height: u64) -> Result<(), StateError>;
+
+    /// Last tree persisted by `save_tree`, with the height it commits to.
+    ///
+    /// Lets a store reopen without rehashing every committed leaf.
+    fn saved_tree(&self) -> Option<(u64, SparseMerkleTree)> {
+        None
+    }
+
+    /// Offer `tree`, which commits to the state at `height`, for persistence.
+    ///
+    /// Best effort: backends may skip trees, and a tree that is never
+    /// persisted is rebuilt from committed state instead.
+    fn save_tree(&mut self, _height: u64, _tree: &SparseMerkleTree) {}
+}
+
+// -------------------------------------------------------------------------------------------------------------------------- //
+
+//----------------------------------------------- Memory Backend -----------------------------------------------//
+
+/// Volatile backend keeping all state in memory.
+#[derive(Default, Debug)]
+pub struct MemoryBackend {
+    objects: HashMap<ObjectId, StateObject>,
+    tombstones: HashMap<ObjectId, Version>,
+
+    /// Writes to each object, oldest first, tagged with their height.
+    history: HashMap<ObjectId, Vec<(u64, ObjectWrite)>>,
+
+    /// Ids written at each height still revertible.
+    changes: BTreeMap<u64, Vec<ObjectId>>,
+
+    /// Height of the last committed batch.
+    height: Option<u64>,
+
+    /// Lowest height that can still be read.
+    pruned_below: u64,
+}
+
+impl MemoryBackend {
+    /// Create an empty backend.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Check that `height` may follow the current height.
+    pub(crate) fn check_height(&self, height: u64) -> Result<(), StateError> {
+        match self.height {
+            Some(current) if height <= current => {
+                Err(StateError::InvalidHeight { expected_above: current, found: height })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Apply a batch in place.
+    pub(crate) fn apply_batch(&mut self, height: u64, batch: &WriteBatch) {
+        for (id, write) in batch {
+            match write {
+                ObjectWrite::Put(object) => {
+                    self.tombstones.remove(id);
+                    self.objects.insert(*id, object.clone());
+                }
+                ObjectWrite::Delete { version, .. } => {
+                    self.objects.remove(id);
+                    self.tombstones.insert(*id, *version);
+                }
+            }
+
+            self.history.entry(*id).or_default().push((height, write.clone()));
+        }
+
+        self.changes.insert(height, batch.keys().copied().collect());
+        self.height = Some(height);
+    }
+}
+
+impl StateBackend for MemoryBackend {
+    fn get(&self, id: &ObjectId) -> Option<&StateObject> {
+        self.objects.get(id)
+    }
+
+    fn tombstone(&self, id: &ObjectId) -> Option<Version> {
+        self.tombstones.get(id).copied()
+    }
+
+    fn objects(&self) -> Box<dyn Iterator<Item = &StateObject> + '_> {
+        Box::new(self.objects.values())
+    }
+
+    fn tombstones(&self) -> Box<dyn Iterator<Item = (ObjectId, Version)> + '_> {
+        Box::new(self.tombstones.iter().map(|(id, version)| (*id, *version)))
+    }
+
+    fn height(&self) -> Option<u64> {
+        self.height
+    }
+
+    fn check_readable(&self, height: u64) -> Result<(), StateError> {
+        if self.height.is_none_or(|current| height > current) {
+            return Err(StateError::UnknownHeight { height });
+        }
+
+        if height < self.pruned_below {
+            return Err(StateError::PrunedHeight { height, earliest: self.pruned_below });
+        }
+
+        Ok(())
+    }
+
+    fn get_at(&self, id: &ObjectId, height: u64) -> Result<Option<&ObjectWrite>, StateError> {
+        self.check_readable(height)?;
+
+        let Some(history) = self.history.get(id) else {
+            return Ok(None);
+        };
+
+        // Last write at or before `height`
+        let index = history.partition_point(|(at, _)| *at <= height);
+
+        Ok(index.checked_sub(1).map(|index| &history[index].1))
+    }
+
+    fn changed_above(&self, height: u64) -> Result<BTreeSet<ObjectId>, StateError> {
+        self.check_readable(height)?;
+
+        Ok(self.changes
+            .range(height.saturating_add(1)..)
+            .flat_map(|(_, ids)| ids.iter().copied())
+            .collect())
+    }
+
+    fn prune(&mut self, height: u64) {
+        if height <= self.pruned_below {
+            return;
+        }
+
+        // Heights at or below `height` can no longer be reverted past
+        self.changes = self.changes.split_off(&height.saturating_add(1));
+
+        // Keep the write visible at `height` and everything after it
+        for history in self.history.values_mut() {
+            let visible = history.partition_point(|(at, _)| *at <= height);
+            history.drain(..visible.saturating_sub(1));
+        }
+
+        self.pruned_below = height;
+    }
+
+    fn commit(&mut self, height: u64, batch: &WriteBatch) -> Result<(), StateError> {
+        self.check_height(height)?;
+        self.apply_batch(height, batch);
+        Ok(())
+    }
+
+    fn revert(&mut self, height: u64) -> Result<(), StateError> {
+        self.check_readable(height)?;
+
+        for (id, history) in self.history.iter_mut() {
+            let kept = history.partition_point(|(at, _)| *at <= height);
+            if kept == history.len() {
+                continue;
+            }
+            history.truncate(kept);
+
+            // Restore the write visible at `height`
+            match history.last() {
+                Some((_, ObjectWrite::Put(object))) => {
+                    self.tombstones.remove(id);
+                    self.objects.insert(*id, object.clone());
+                }
+                Some((_, ObjectWrite::Delete { version, .. })) => {
+                    self.objects.remove(id);
+                    self.tombstones.insert(*id, *version);
+                }
+                None => {
+                    self.objects.remove(id);
+                    self.tombstones.remove(id);
+                }
+            }
+        }
+
+        self.history.retain(|_, history| !history.is_empty());
+        self.changes.split_off(&height.saturating_add(1));
+        self.height = Some(height);
+        Ok(())
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------- //
@@ -12,6 +12,14 @@ pub fn balance_object_id(address: Address) -> ObjectId {
     ObjectId::new(hash)
 }
 
+/// Returns true if `obj` is the balance object of its owner.
+///
+/// Ownership is part of a balance object's identity: an object stored
+/// under another address's balance id is NOT that address's balance.
+pub fn is_balance_object(obj: &StateObject) -> bool {
+    obj.id() == balance_object_id(obj.owner())
+}
+
 /// Decode balance from state object data
 pub fn decode_balance(obj: &StateObject) -> u64 {
     let bytes = obj.data();
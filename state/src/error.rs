@@ -1,5 +1,6 @@
 
-/// Imports and dependencies
+// Imports and dependencies
+use axiom_types::Hash;
 
 // -------------------------------------------------------------------------------------------------------------------------- //
 
@@ -10,6 +11,15 @@ pub enum StateError {
     ObjectNotFound,
     StaleRead { expected: u64, found: u64 },
     InvalidVersion { expected: u64, found: u64 },
+    StaleRecreation { deleted: u64, found: u64 },
+    CorruptedLog { offset: u64 },
+    PoisonedLog,
+    OpenCheckpoint,
+    InvalidHeight { expected_above: u64, found: u64 },
+    UnknownHeight { height: u64 },
+    PrunedHeight { height: u64, earliest: u64 },
+    UnknownCheckpoint,
+    Io(std::io::Error),
 }
 
 // -------------------------------------------------------------------------------------------------------------------------- //
@@ -23,3 +33,26 @@ pub enum NonceError {
     },
     DecodeError,
 }
+
+/// Errors that can occur while exporting or importing snapshots.
+#[derive(Debug)]
+pub enum SnapshotError {
+    PendingWrites,
+    UnsupportedVersion { version: u8 },
+    UnexpectedChunk { index: usize },
+    ChunkHashMismatch { index: usize },
+    MalformedChunk { index: usize },
+    MissingChunks { expected: usize, received: usize },
+    RootMismatch { expected: Hash, found: Hash },
+    State(StateError),
+}
+
+/// Errors returned when checking supply conservation.
+#[derive(Debug)]
+pub enum SupplyError {
+    MissingSupply,
+    MalformedSupply,
+    NotConserved { issued: u64, burned: u64, balances: u128 },
+}
+
+// -------------------------------------------------------------------------------------------------------------------------- //
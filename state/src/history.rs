@@ -0,0 +1,41 @@
+
+// Imports and dependencies
+use axiom_types::ObjectId;
+use crate::{StateObject, StateStore, Version};
+
+// -------------------------------------------------------------------------------------------------------------------------- //
+
+//----------------------------------------------- Historical State -----------------------------------------------//
+
+/// Read-only view of committed state pinned to a past block height.
+///
+/// Created with `StateStore::at`, which checks that the height is
+/// committed and not pruned.
+#[derive(Clone, Copy)]
+pub struct HistoricalState<'a> {
+    store: &'a StateStore,
+    height: u64,
+}
+
+impl<'a> HistoricalState<'a> {
+    pub(crate) fn new(store: &'a StateStore, height: u64) -> Self {
+        Self { store, height }
+    }
+
+    /// Return the height this view is pinned to.
+    pub fn height(&self) -> u64 {
+        self.height
+    }
+
+    /// Retrieve a state object as of this height.
+    pub fn get(&self, id: &ObjectId) -> Option<&'a StateObject> {
+        self.store.get_at(id, self.height).ok().flatten()
+    }
+
+    /// Return the tombstone version of a deleted object as of this height.
+    pub fn tombstone(&self, id: &ObjectId) -> Option<Version> {
+        self.store.tombstone_at(id, self.height).ok().flatten()
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------- //
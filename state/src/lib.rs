@@ -8,14 +8,32 @@ pub mod object;
 pub mod nonce;
 pub mod error;
 pub mod balance;
+pub mod validator;
+pub mod parameters;
+pub mod supply;
 pub mod state_root;
+pub mod write;
+pub mod smt;
+pub mod backend;
+pub mod log_backend;
+pub mod history;
+pub mod snapshot;
 
-pub use store::{StateStore, ReadSet, WriteSet};
+pub use store::{StateStore, Checkpoint, ReadSet, WriteSet};
 pub use object::{StateObject, Version};
-pub use nonce::{nonce_object_id, validate_and_prepare_nonce_update};
-pub use error::{StateError, NonceError};
-pub use balance::{balance_object_id, decode_balance, encode_balance};
-pub use state_root::compute_state_root;
+pub use nonce::{nonce_object_id, is_nonce_object, validate_and_prepare_nonce_update};
+pub use error::{StateError, NonceError, SnapshotError, SupplyError};
+pub use balance::{balance_object_id, is_balance_object, decode_balance, encode_balance};
+pub use validator::{validator_object_id, is_validator_object, decode_stake, encode_stake};
+pub use parameters::{ProtocolParameters, parameters_object_id};
+pub use supply::{Supply, check_supply, read_supply, supply_object_id};
+pub use state_root::{compute_state_root, verify_proof};
+pub use smt::{MerkleProof, SparseMerkleTree};
+pub use backend::{StateBackend, MemoryBackend, WriteBatch};
+pub use log_backend::{LogBackend, LogFile, TREE_SNAPSHOT_INTERVAL};
+pub use history::HistoricalState;
+pub use snapshot::{SnapshotManifest, SnapshotImporter, SNAPSHOT_VERSION, export_snapshot};
+pub use write::ObjectWrite;
 
 
 
@@ -0,0 +1,445 @@
+
+// Imports and dependencies
+use std::collections::BTreeSet;
+use std::fmt::Debug;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+
+use axiom_types::{Address, Hash, ObjectId};
+use crate::backend::{MemoryBackend, StateBackend, WriteBatch};
+use crate::smt::SparseMerkleTree;
+use crate::{ObjectWrite, StateError, StateObject, Version};
+
+// -------------------------------------------------------------------------------------------------------------------------- //
+
+//----------------------------------------------- Log Backend -----------------------------------------------//
+
+// Persistent backend built on an append-only log.
+//
+// Each committed batch is appended as one record and synced before
+// `commit` returns:
+//
+//   u32 payload length | payload | blake3(payload)
+//
+// The payload is the batch's block height followed by its writes in id
+// order. If the append fails, the partial record is cut off again before
+// `commit` returns; a log that cannot be cut back is poisoned and refuses
+// further writes.
+//
+// On open the log is replayed into an in-memory index. A final record that is incomplete or fails its checksum
+// can only come from an interrupted commit, so it is truncated away: every
+// batch is applied entirely or not at all. A damaged record with more log
+// after it is corruption of committed history and fails the open.
+//
+// Reverting to an earlier height truncates the log after the last record
+// at or below that height.
+//
+// Every `TREE_SNAPSHOT_INTERVAL` heights the state tree is written to a
+// sidecar file (`<log>.tree`) as `u64 height | tree | blake3`, replaced
+// atomically. It only saves rehashing on open: a missing, stale or
+// corrupted snapshot is ignored and the tree rebuilt from the log.
+
+const CHECKSUM_LENGTH: usize = 32;
+
+/// Heights between persisted tree snapshots.
+pub const TREE_SNAPSHOT_INTERVAL: u64 = 64;
+
+const TAG_PUT: u8 = 0;
+const TAG_DELETE: u8 = 1;
+
+/// File operations the log relies on.
+///
+/// Implemented for `File`; other implementations can simulate storage faults.
+pub trait LogFile: Read + Write + Seek + Send + Debug {
+    /// Truncate or extend the file to `len` bytes.
+    fn set_len(&mut self, len: u64) -> io::Result<()>;
+
+    /// Flush written data to durable storage.
+    fn sync_data(&mut self) -> io::Result<()>;
+}
+
+impl LogFile for File {
+    fn set_len(&mut self, len: u64) -> io::Result<()> {
+        File::set_len(self, len)
+    }
+
+    fn sync_data(&mut self) -> io::Result<()> {
+        File::sync_data(self)
+    }
+}
+
+/// Durable backend persisting batches to an append-only log file.
+#[derive(Debug)]
+pub struct LogBackend<F: LogFile = File> {
+    path: PathBuf,
+    file: F,
+    index: MemoryBackend,
+
+    /// Height and starting offset of each record, in log order.
+    records: Vec<(u64, u64)>,
+
+    /// Length of the valid log.
+    len: u64,
+
+    /// Height of the persisted tree snapshot, if any.
+    tree_height: Option<u64>,
+
+    /// Set when the file could not be restored after a failed write,
+    /// so its contents past `len` are unknown.
+    poisoned: bool,
+}
+
+impl LogBackend {
+    /// Open (or create) the log at `path` and replay committed batches.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, StateError> {
+        let file = OpenOptions::new()
+            .read(true)
+            .append(true)
+            .create(true)
+            .open(path.as_ref())
+            .map_err(StateError::Io)?;
+
+        Self::from_file(path, file)
+    }
+}
+
+impl<F: LogFile> LogBackend<F> {
+    /// Replay the log in `file`, which is stored at `path`.
+    ///
+    /// Writes to `file` MUST append.
+    pub fn from_file(path: impl AsRef<Path>, mut file: F) -> Result<Self, StateError> {
+        let path = path.as_ref().to_path_buf();
+
+        file.seek(SeekFrom::Start(0)).map_err(StateError::Io)?;
+
+        let mut bytes = Vec::new();
+        file.read_to_end(&mut bytes).map_err(StateError::Io)?;
+
+        let mut index = MemoryBackend::new();
+        let mut records = Vec::new();
+        let mut offset = 0;
+
+        while let Some((payload, next)) = next_record(&bytes, offset)? {
+            let corrupted = || StateError::CorruptedLog { offset: offset as u64 };
+
+            let (height, batch) = decode_batch(payload).ok_or_else(corrupted)?;
+            index.check_height(height).map_err(|_| corrupted())?;
+            index.apply_batch(height, &batch);
+            records.push((height, offset as u64));
+            offset = next;
+        }
+
+        // Drop the torn tail of an interrupted commit
+        if offset < bytes.len() {
+            file.set_len(offset as u64).map_err(StateError::Io)?;
+            file.sync_data().map_err(StateError::Io)?;
+        }
+
+        // A snapshot ahead of the log belongs to discarded history
+        let tree_height = match (read_tree_height(&path), index.height()) {
+            (Some(saved), Some(current)) if saved <= current => Some(saved),
+            (None, _) => None,
+            _ => {
+                remove_tree_snapshot(&path)?;
+                None
+            }
+        };
+
+        Ok(Self {
+            path,
+            file,
+            index,
+            records,
+            len: offset as u64,
+            tree_height,
+            poisoned: false,
+        })
+    }
+
+    /// Return the path of the log file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Return the path of the tree snapshot kept beside the log.
+    pub fn tree_path(&self) -> PathBuf {
+        tree_path(&self.path)
+    }
+
+    /// Set the file length to `len` and make it durable.
+    ///
+    /// On failure the length on disk is unknown, so the log is poisoned
+    /// and refuses further writes.
+    fn truncate(&mut self, len: u64) -> Result<(), StateError> {
+        let result = self.file.set_len(len)
+            .and_then(|_| self.file.seek(SeekFrom::Start(len)))
+            .and_then(|_| self.file.sync_data());
+
+        result.map_err(|err| {
+            self.poisoned = true;
+            StateError::Io(err)
+        })
+    }
+
+    fn check_writable(&self) -> Result<(), StateError> {
+        if self.poisoned {
+            return Err(StateError::PoisonedLog);
+        }
+        Ok(())
+    }
+}
+
+impl<F: LogFile> StateBackend for LogBackend<F> {
+    fn get(&self, id: &ObjectId) -> Option<&StateObject> {
+        self.index.get(id)
+    }
+
+    fn tombstone(&self, id: &ObjectId) -> Option<Version> {
+        self.index.tombstone(id)
+    }
+
+    fn objects(&self) -> Box<dyn Iterator<Item = &StateObject> + '_> {
+        self.index.objects()
+    }
+
+    fn tombstones(&self) -> Box<dyn Iterator<Item = (ObjectId, Version)> + '_> {
+        self.index.tombstones()
+    }
+
+    fn height(&self) -> Option<u64> {
+        self.index.height()
+    }
+
+    fn check_readable(&self, height: u64) -> Result<(), StateError> {
+        self.index.check_readable(height)
+    }
+
+    fn get_at(&self, id: &ObjectId, height: u64) -> Result<Option<&ObjectWrite>, StateError> {
+        self.index.get_at(id, height)
+    }
+
+    fn changed_above(&self, height: u64) -> Result<BTreeSet<ObjectId>, StateError> {
+        self.index.changed_above(height)
+    }
+
+    /// Prunes the in-memory index only; the log keeps every batch.
+    fn prune(&mut self, height: u64) {
+        self.index.prune(height)
+    }
+
+    fn commit(&mut self, height: u64, batch: &WriteBatch) -> Result<(), StateError> {
+        self.check_writable()?;
+        self.index.check_height(height)?;
+
+        let payload = encode_batch(height, batch);
+
+        let mut record = Vec::with_capacity(4 + payload.len() + CHECKSUM_LENGTH);
+        record.extend_from_slice(&(payload.len() as u32).to_be_bytes());
+        record.extend_from_slice(&payload);
+        record.extend_from_slice(blake3::hash(&payload).as_bytes());
+
+        // Durable before it becomes visible. A failed append may have
+        // left part of the record behind; cut it off so the next commit
+        // (or a retry of this one) starts at the end of the valid log.
+        let appended = self.file.write_all(&record).and_then(|_| self.file.sync_data());
+        if let Err(err) = appended {
+            self.truncate(self.len)?;
+            return Err(StateError::Io(err));
+        }
+
+        self.index.apply_batch(height, batch);
+        self.records.push((height, self.len));
+        self.len += record.len() as u64;
+        Ok(())
+    }
+
+    fn revert(&mut self, height: u64) -> Result<(), StateError> {
+        self.check_writable()?;
+        self.index.check_readable(height)?;
+
+        // Forget the snapshot first, so it can never describe a replaced fork
+        if self.tree_height.is_some_and(|saved| saved > height) {
+            remove_tree_snapshot(&self.path)?;
+            self.tree_height = None;
+        }
+
+        let kept = self.records.partition_point(|(at, _)| *at <= height);
+        if let Some(&(_, offset)) = self.records.get(kept) {
+            // Durable before it becomes visible
+            self.truncate(offset)?;
+
+            self.records.truncate(kept);
+            self.len = offset;
+        }
+
+        self.index.revert(height)
+    }
+
+    fn saved_tree(&self) -> Option<(u64, SparseMerkleTree)> {
+        self.tree_height?;
+        read_tree_snapshot(&self.path)
+    }
+
+    fn save_tree(&mut self, height: u64, tree: &SparseMerkleTree) {
+        if self.tree_height.is_some_and(|saved| height < saved + TREE_SNAPSHOT_INTERVAL) {
+            return;
+        }
+
+        if write_tree_snapshot(&self.path, height, tree).is_ok() {
+            self.tree_height = Some(height);
+        }
+    }
+}
+
+fn tree_path(path: &Path) -> PathBuf {
+    let mut tree = path.as_os_str().to_owned();
+    tree.push(".tree");
+    PathBuf::from(tree)
+}
+
+/// Read the height of the tree snapshot beside the log at `path`, if any.
+///
+/// The snapshot itself is only checked when it is read.
+fn read_tree_height(path: &Path) -> Option<u64> {
+    let mut height = [0u8; 8];
+    File::open(tree_path(path)).ok()?.read_exact(&mut height).ok()?;
+    Some(u64::from_be_bytes(height))
+}
+
+/// Read the tree snapshot beside the log at `path`, if present and intact.
+fn read_tree_snapshot(path: &Path) -> Option<(u64, SparseMerkleTree)> {
+    let bytes = fs::read(tree_path(path)).ok()?;
+
+    let body_len = bytes.len().checked_sub(CHECKSUM_LENGTH)?;
+    let (body, checksum) = bytes.split_at(body_len);
+    if blake3::hash(body).as_bytes() != checksum || body.len() < 8 {
+        return None;
+    }
+
+    let (height, tree) = body.split_at(8);
+    let height = u64::from_be_bytes(height.try_into().ok()?);
+
+    Some((height, SparseMerkleTree::decode(tree)?))
+}
+
+/// Atomically replace the tree snapshot beside the log at `path`.
+fn write_tree_snapshot(path: &Path, height: u64, tree: &SparseMerkleTree) -> std::io::Result<()> {
+    let mut body = height.to_be_bytes().to_vec();
+    body.extend_from_slice(&tree.encode());
+
+    let mut temporary = tree_path(path).into_os_string();
+    temporary.push(".tmp");
+
+    let mut file = File::create(&temporary)?;
+    file.write_all(&body)?;
+    file.write_all(blake3::hash(&body).as_bytes())?;
+    file.sync_data()?;
+
+    fs::rename(&temporary, tree_path(path))
+}
+
+fn remove_tree_snapshot(path: &Path) -> Result<(), StateError> {
+    match fs::remove_file(tree_path(path)) {
+        Err(err) if err.kind() != std::io::ErrorKind::NotFound => Err(StateError::Io(err)),
+        _ => Ok(()),
+    }
+}
+
+/// Return the payload of the record at `offset` and the next offset.
+///
+/// Returns `None` at the end of the log and for a torn final record: one
+/// that is incomplete, or fails its checksum and ends exactly at the end
+/// of the file. A damaged record followed by more data cannot come from
+/// an interrupted append, so it is reported as corruption.
+fn next_record(bytes: &[u8], offset: usize) -> Result<Option<(&[u8], usize)>, StateError> {
+    let rest = &bytes[offset..];
+
+    let Some(header) = rest.get(..4) else {
+        return Ok(None);
+    };
+    let len = u32::from_be_bytes([header[0], header[1], header[2], header[3]]) as usize;
+
+    let end = 4 + len + CHECKSUM_LENGTH;
+    if end > rest.len() {
+        return Ok(None);
+    }
+
+    let payload = &rest[4..4 + len];
+    let checksum = &rest[4 + len..end];
+
+    if blake3::hash(payload).as_bytes() != checksum {
+        if end == rest.len() {
+            return Ok(None);
+        }
+        return Err(StateError::CorruptedLog { offset: offset as u64 });
+    }
+
+    Ok(Some((payload, offset + end)))
+}
+
+fn encode_batch(height: u64, batch: &WriteBatch) -> Vec<u8> {
+    let mut buf = Vec::new();
+    buf.extend_from_slice(&height.to_be_bytes());
+    buf.extend_from_slice(&(batch.len() as u32).to_be_bytes());
+
+    for (id, write) in batch {
+        match write {
+            ObjectWrite::Put(object) => {
+                buf.push(TAG_PUT);
+                buf.extend_from_slice(id.as_hash().as_bytes());
+                buf.extend_from_slice(&object.version().to_be_bytes());
+                buf.extend_from_slice(object.owner().as_bytes());
+                buf.extend_from_slice(&(object.data().len() as u32).to_be_bytes());
+                buf.extend_from_slice(object.data());
+            }
+            ObjectWrite::Delete { version, .. } => {
+                buf.push(TAG_DELETE);
+                buf.extend_from_slice(id.as_hash().as_bytes());
+                buf.extend_from_slice(&version.to_be_bytes());
+            }
+        }
+    }
+
+    buf
+}
+
+fn decode_batch(payload: &[u8]) -> Option<(u64, WriteBatch)> {
+    let mut cursor = payload;
+
+    let mut take = |len: usize| -> Option<&[u8]> {
+        if len > cursor.len() {
+            return None;
+        }
+        let (head, tail) = cursor.split_at(len);
+        cursor = tail;
+        Some(head)
+    };
+
+    let height = u64::from_be_bytes(take(8)?.try_into().ok()?);
+    let count = u32::from_be_bytes(take(4)?.try_into().ok()?);
+    let mut batch = WriteBatch::new();
+
+    for _ in 0..count {
+        let tag = take(1)?[0];
+        let id = ObjectId::new(Hash::new(take(32)?.try_into().ok()?));
+        let version = u64::from_be_bytes(take(8)?.try_into().ok()?);
+
+        let write = match tag {
+            TAG_PUT => {
+                let owner = Address::new(take(32)?.try_into().ok()?);
+                let len = u32::from_be_bytes(take(4)?.try_into().ok()?) as usize;
+                let data = take(len)?.to_vec();
+                ObjectWrite::Put(StateObject::from_parts(id, owner, version, data))
+            }
+            TAG_DELETE => ObjectWrite::Delete { id, version },
+            _ => return None,
+        };
+
+        batch.insert(id, write);
+    }
+
+    cursor.is_empty().then_some((height, batch))
+}
+
+// -------------------------------------------------------------------------------------------------------------------------- //
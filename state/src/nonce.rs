@@ -26,6 +26,11 @@ pub fn nonce_object_id(address: Address) -> ObjectId {
     ObjectId::new(hash)
 }
 
+/// Returns true if `obj` is the nonce object of its owner.
+pub fn is_nonce_object(obj: &StateObject) -> bool {
+    obj.id() == nonce_object_id(obj.owner())
+}
+
 /// Validate nonce and prepare nonce object update.
 ///
 /// Nonce is enforced via StateObject versioning.
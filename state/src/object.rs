@@ -33,6 +33,28 @@ impl StateObject {
         }
     }
 
+    /// Rebuild an object from its stored parts
+    /// Used when loading persisted state; versions are not checked
+    pub fn from_parts(id: ObjectId, owner: Address, version: Version, data: Vec<u8>) -> Self {
+        Self {
+            id,
+            owner,
+            version,
+            data,
+        }
+    }
+
+    /// Re-create a deleted object
+    /// The object continues directly after its tombstone version
+    pub fn recreate(id: ObjectId, owner: Address, data: Vec<u8>, tombstone: Version) -> Self {
+        Self {
+            id,
+            owner,
+            version: tombstone + 1,
+            data,
+        }
+    }
+
     // Return the object identifier
     pub fn id(&self) -> ObjectId {
         self.id
@@ -73,6 +95,17 @@ impl StateObject {
             data: new_data,
         }
     }
+
+    // Produce a new version of the object with a new owner
+    // The original object remains unchanged
+    pub fn next_with_owner(&self, new_owner: Address) -> Self {
+        Self {
+            id: self.id,
+            owner: new_owner,
+            version: self.version + 1,
+            data: self.data.clone(),
+        }
+    }
 }
 
 // -------------------------------------------------------------------------------------------------------------------------- //
@@ -0,0 +1,123 @@
+use axiom_types::{Address, ObjectId, Hash};
+use crate::StateObject;
+
+const PARAMETERS_DOMAIN: &[u8] = b"axiom::parameters";
+
+/// Number of u64 fields in the encoded parameters.
+const FIELD_COUNT: usize = 13;
+
+/// Chain-wide parameters fixed at genesis.
+///
+/// Stored in a single system object so they are committed by the state root.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProtocolParameters {
+    /// Identifies the network.
+    pub chain_id: u64,
+
+    /// Base fee of the genesis block; later blocks derive theirs from
+    /// their parent.
+    pub base_fee: u64,
+
+    /// Gas per block the base fee steers towards.
+    pub target_gas: u64,
+
+    /// Most transactions a block may carry.
+    pub max_transactions: u64,
+
+    /// Inverse of the largest per-block base fee change.
+    pub change_denominator: u64,
+
+    /// Floor the base fee never falls below.
+    pub min_base_fee: u64,
+
+    /// Share of collected fees burned, in basis points (1/10,000).
+    pub burn_basis_points: u64,
+
+    /// Gas charged for dispatching one cell's call data.
+    pub gas_call: u64,
+
+    /// Gas charged for reading one object.
+    pub gas_read: u64,
+
+    /// Gas charged for writing or deleting one object.
+    pub gas_write: u64,
+
+    /// Gas charged per byte of object data read or written.
+    pub gas_byte: u64,
+
+    /// Most gas a single transaction may declare.
+    pub max_transaction_gas: u64,
+
+    /// Most gas all transactions of a block may consume together.
+    pub max_block_gas: u64,
+}
+
+impl ProtocolParameters {
+    /// Fields in encoding order.
+    fn fields(&self) -> [u64; FIELD_COUNT] {
+        [
+            self.chain_id,
+            self.base_fee,
+            self.target_gas,
+            self.max_transactions,
+            self.change_denominator,
+            self.min_base_fee,
+            self.burn_basis_points,
+            self.gas_call,
+            self.gas_read,
+            self.gas_write,
+            self.gas_byte,
+            self.max_transaction_gas,
+            self.max_block_gas,
+        ]
+    }
+
+    /// Encode parameters into object data.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(FIELD_COUNT * 8);
+        for field in self.fields() {
+            bytes.extend_from_slice(&field.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Decode parameters from object data.
+    pub fn decode(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != FIELD_COUNT * 8 {
+            return None;
+        }
+
+        let mut fields = bytes.chunks_exact(8)
+            .map(|chunk| u64::from_le_bytes(chunk.try_into().expect("chunk is 8 bytes")));
+        let mut next = || fields.next().expect("length checked above");
+
+        Some(Self {
+            chain_id: next(),
+            base_fee: next(),
+            target_gas: next(),
+            max_transactions: next(),
+            change_denominator: next(),
+            min_base_fee: next(),
+            burn_basis_points: next(),
+            gas_call: next(),
+            gas_read: next(),
+            gas_write: next(),
+            gas_byte: next(),
+            max_transaction_gas: next(),
+            max_block_gas: next(),
+        })
+    }
+
+    /// Build the parameters system object.
+    pub fn to_object(&self) -> StateObject {
+        StateObject::new(parameters_object_id(), Address::zero(), self.encode())
+    }
+}
+
+/// ObjectId of the protocol parameters object.
+///
+/// There is exactly ONE parameters object per chain.
+pub fn parameters_object_id() -> ObjectId {
+    let hash = Hash::new(blake3::hash(PARAMETERS_DOMAIN).into());
+    ObjectId::new(hash)
+}
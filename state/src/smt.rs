@@ -0,0 +1,502 @@
+
+// Imports and dependencies
+use std::sync::OnceLock;
+
+use axiom_types::{Hash, ObjectId};
+use crate::{StateObject, Version};
+
+// -------------------------------------------------------------------------------------------------------------------------- //
+
+//----------------------------------------------- Sparse Merkle Tree -----------------------------------------------//
+
+// Sparse Merkle tree over the 256-bit object id space.
+//
+// Every possible `ObjectId` has a leaf at depth 256; the path to it is the
+// id's bits, most significant first (0 = left). Leaves are empty, a live
+// object, or a tombstone. Only subtrees containing a non-empty leaf are
+// stored, with single-child runs collapsed, so each update touches one
+// root-to-leaf path of stored nodes.
+
+/// Depth of the tree (bits in an object id).
+pub const TREE_DEPTH: usize = 256;
+
+const LEAF_DOMAIN: &[u8] = b"Axiom::SMT::leaf";
+const TOMBSTONE_DOMAIN: &[u8] = b"Axiom::SMT::tombstone";
+const NODE_DOMAIN: &[u8] = b"Axiom::SMT::node";
+
+/// Hash of a live object leaf.
+pub fn object_leaf_hash(object: &StateObject) -> Hash {
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(LEAF_DOMAIN);
+    bytes.extend_from_slice(object.id().as_hash().as_bytes());
+    bytes.extend_from_slice(object.owner().as_bytes());
+    bytes.extend_from_slice(&object.version().to_be_bytes());
+    bytes.extend_from_slice(blake3::hash(object.data()).as_bytes());
+
+    Hash::new(blake3::hash(&bytes).into())
+}
+
+/// Hash of a tombstone leaf.
+pub fn tombstone_leaf_hash(id: &ObjectId, version: Version) -> Hash {
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(TOMBSTONE_DOMAIN);
+    bytes.extend_from_slice(id.as_hash().as_bytes());
+    bytes.extend_from_slice(&version.to_be_bytes());
+
+    Hash::new(blake3::hash(&bytes).into())
+}
+
+fn node_hash(left: &Hash, right: &Hash) -> Hash {
+    let mut bytes = Vec::with_capacity(NODE_DOMAIN.len() + 64);
+    bytes.extend_from_slice(NODE_DOMAIN);
+    bytes.extend_from_slice(left.as_bytes());
+    bytes.extend_from_slice(right.as_bytes());
+
+    Hash::new(blake3::hash(&bytes).into())
+}
+
+/// Root hashes of empty subtrees, indexed by depth (256 = empty leaf).
+fn empty_hashes() -> &'static [Hash; TREE_DEPTH + 1] {
+    static EMPTY: OnceLock<[Hash; TREE_DEPTH + 1]> = OnceLock::new();
+
+    EMPTY.get_or_init(|| {
+        let mut hashes = [Hash::zero(); TREE_DEPTH + 1];
+        let mut child = Hash::zero();
+        for hash in hashes.iter_mut().rev().skip(1) {
+            child = node_hash(&child, &child);
+            *hash = child;
+        }
+        hashes
+    })
+}
+
+/// Bit of `key` selecting the child below `depth`.
+fn bit(key: &[u8; 32], depth: usize) -> bool {
+    (key[depth / 8] >> (7 - depth % 8)) & 1 == 1
+}
+
+/// `key` truncated to its first `depth` bits.
+fn prefix(key: &[u8; 32], depth: usize) -> [u8; 32] {
+    let mut out = [0u8; 32];
+    let full = depth / 8;
+    out[..full].copy_from_slice(&key[..full]);
+    if !depth.is_multiple_of(8) {
+        out[full] = key[full] & (0xffu8 << (8 - depth % 8));
+    }
+    out
+}
+
+/// Length of the common prefix of `a` and `b`, capped at `limit` bits.
+fn common_prefix(a: &[u8; 32], b: &[u8; 32], limit: usize) -> usize {
+    for (index, (x, y)) in a.iter().zip(b).enumerate() {
+        let diff = x ^ y;
+        if diff != 0 {
+            return (index * 8 + diff.leading_zeros() as usize).min(limit);
+        }
+    }
+    limit
+}
+
+/// Stored node of the path-compressed tree.
+///
+/// Runs of single-child nodes are not stored: a subtree holding one leaf
+/// is the leaf itself, and a branch is only stored where both of its
+/// children are non-empty. Hashes are unchanged by the compression; a
+/// collapsed run is re-expanded by hashing against empty siblings.
+#[derive(Clone, Debug)]
+enum Node {
+    /// Non-empty leaf at depth `TREE_DEPTH`.
+    Leaf { key: [u8; 32], hash: Hash },
+
+    /// Node at `depth` with two non-empty children.
+    Branch {
+        depth: u16,
+        path: [u8; 32],
+        hash: Hash,
+
+        /// Hashes of the children as seen from depth `depth + 1`.
+        lifted: [Hash; 2],
+        children: Box<[Node; 2]>,
+    },
+}
+
+impl Node {
+    fn depth(&self) -> usize {
+        match self {
+            Node::Leaf { .. } => TREE_DEPTH,
+            Node::Branch { depth, .. } => *depth as usize,
+        }
+    }
+
+    fn path(&self) -> &[u8; 32] {
+        match self {
+            Node::Leaf { key, .. } => key,
+            Node::Branch { path, .. } => path,
+        }
+    }
+
+    fn hash(&self) -> Hash {
+        match self {
+            Node::Leaf { hash, .. } | Node::Branch { hash, .. } => *hash,
+        }
+    }
+
+    fn branch(depth: usize, path: [u8; 32], children: [Node; 2], lifted: [Hash; 2]) -> Self {
+        Node::Branch {
+            depth: depth as u16,
+            path,
+            hash: node_hash(&lifted[0], &lifted[1]),
+            lifted,
+            children: Box::new(children),
+        }
+    }
+
+    /// Hash of the subtree at `depth` (at or above this node) that holds
+    /// only this node.
+    fn lift(&self, depth: usize) -> Hash {
+        let empty = empty_hashes();
+        let path = self.path();
+
+        let mut current = self.hash();
+        for above in (depth..self.depth()).rev() {
+            current = if bit(path, above) {
+                node_hash(&empty[above + 1], &current)
+            } else {
+                node_hash(&current, &empty[above + 1])
+            };
+        }
+        current
+    }
+
+    /// Set the leaf at `key` in the subtree rooted at `node`.
+    fn insert(node: Option<Node>, key: [u8; 32], hash: Hash) -> Node {
+        let Some(node) = node else {
+            return Node::Leaf { key, hash };
+        };
+
+        let depth = node.depth();
+        let common = common_prefix(&key, node.path(), depth);
+
+        // `key` leaves the compressed path above `node`
+        if common < depth {
+            let leaf = Node::Leaf { key, hash };
+            let lifted = [node.lift(common + 1), leaf.lift(common + 1)];
+
+            return if bit(&key, common) {
+                Node::branch(common, prefix(&key, common), [node, leaf], lifted)
+            } else {
+                Node::branch(common, prefix(&key, common), [leaf, node], [lifted[1], lifted[0]])
+            };
+        }
+
+        match node {
+            Node::Leaf { .. } => Node::Leaf { key, hash },
+            Node::Branch { path, mut lifted, children, .. } => {
+                let side = bit(&key, depth) as usize;
+                let [left, right] = *children;
+
+                let children = if side == 1 {
+                    [left, Node::insert(Some(right), key, hash)]
+                } else {
+                    [Node::insert(Some(left), key, hash), right]
+                };
+
+                lifted[side] = children[side].lift(depth + 1);
+                Node::branch(depth, path, children, lifted)
+            }
+        }
+    }
+
+    /// Empty the leaf at `key` in the subtree rooted at `node`.
+    fn remove(node: Node, key: &[u8; 32]) -> Option<Node> {
+        match node {
+            Node::Leaf { key: existing, .. } if existing == *key => None,
+
+            Node::Branch { depth, path, mut lifted, children, .. }
+                if common_prefix(key, &path, depth as usize) == depth as usize =>
+            {
+                let depth = depth as usize;
+                let side = bit(key, depth) as usize;
+                let [left, right] = *children;
+                let (target, other) = if side == 1 { (right, left) } else { (left, right) };
+
+                // A branch left with one child collapses into it
+                let Some(target) = Node::remove(target, key) else {
+                    return Some(other);
+                };
+                lifted[side] = target.lift(depth + 1);
+
+                let children = if side == 1 { [other, target] } else { [target, other] };
+                Some(Node::branch(depth, path, children, lifted))
+            }
+
+            node => Some(node),
+        }
+    }
+
+    fn encode(&self, out: &mut Vec<u8>) {
+        match self {
+            Node::Leaf { key, hash } => {
+                out.push(0);
+                out.extend_from_slice(key);
+                out.extend_from_slice(hash.as_bytes());
+            }
+            Node::Branch { depth, path, children, .. } => {
+                out.push(1);
+                out.extend_from_slice(&depth.to_be_bytes());
+                out.extend_from_slice(path);
+                children[0].encode(out);
+                children[1].encode(out);
+            }
+        }
+    }
+
+    fn decode(cursor: &mut &[u8], above: usize) -> Option<Node> {
+        let mut take = |len: usize| -> Option<&[u8]> {
+            if len > cursor.len() {
+                return None;
+            }
+            let (head, tail) = cursor.split_at(len);
+            *cursor = tail;
+            Some(head)
+        };
+
+        let tag = take(1)?[0];
+
+        match tag {
+            0 => {
+                let key = take(32)?.try_into().ok()?;
+                let hash = Hash::new(take(32)?.try_into().ok()?);
+                Some(Node::Leaf { key, hash })
+            }
+            1 => {
+                let depth = u16::from_be_bytes(take(2)?.try_into().ok()?) as usize;
+                let path = take(32)?.try_into().ok()?;
+
+                // Branches strictly deepen and sit on their own path
+                if depth < above || depth >= TREE_DEPTH || prefix(&path, depth) != path {
+                    return None;
+                }
+
+                let left = Node::decode(cursor, depth + 1)?;
+                let right = Node::decode(cursor, depth + 1)?;
+
+                for (side, child) in [&left, &right].into_iter().enumerate() {
+                    if common_prefix(child.path(), &path, depth) != depth
+                        || bit(child.path(), depth) != (side == 1)
+                    {
+                        return None;
+                    }
+                }
+
+                // Internal hashes are recomputed, never taken from the input
+                let lifted = [left.lift(depth + 1), right.lift(depth + 1)];
+                Some(Node::branch(depth, path, [left, right], lifted))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Incrementally maintained sparse Merkle tree.
+///
+/// Stored path-compressed (see `Node`), so memory is linear in the
+/// number of non-empty leaves.
+#[derive(Clone, Debug)]
+pub struct SparseMerkleTree {
+    root: Option<Node>,
+
+    /// Cached root hash.
+    root_hash: Hash,
+}
+
+impl Default for SparseMerkleTree {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SparseMerkleTree {
+    /// Create an empty tree.
+    pub fn new() -> Self {
+        Self { root: None, root_hash: empty_hashes()[0] }
+    }
+
+    /// Return the current root.
+    pub fn root(&self) -> Hash {
+        self.root_hash
+    }
+
+    /// Return the leaf of `id`, or `None` if it is empty.
+    pub fn leaf(&self, id: &ObjectId) -> Option<Hash> {
+        let key = id.as_hash().as_bytes();
+        let mut node = self.root.as_ref()?;
+
+        loop {
+            match node {
+                Node::Leaf { key: existing, hash } => {
+                    return (existing == key).then_some(*hash);
+                }
+                Node::Branch { depth, path, children, .. } => {
+                    let depth = *depth as usize;
+                    if common_prefix(key, path, depth) < depth {
+                        return None;
+                    }
+                    node = &children[bit(key, depth) as usize];
+                }
+            }
+        }
+    }
+
+    /// Set the leaf of `id` and rehash its path (None empties the leaf).
+    pub fn update(&mut self, id: &ObjectId, leaf: Option<Hash>) {
+        let key = *id.as_hash().as_bytes();
+        let root = self.root.take();
+
+        self.root = match leaf {
+            Some(hash) => Some(Node::insert(root, key, hash)),
+            None => root.and_then(|root| Node::remove(root, &key)),
+        };
+
+        self.root_hash = self.root
+            .as_ref()
+            .map_or(empty_hashes()[0], |root| root.lift(0));
+    }
+
+    /// Build a proof for the leaf of `id`.
+    ///
+    /// Empty siblings are omitted and marked in the proof bitmap.
+    pub fn prove(&self, id: &ObjectId) -> MerkleProof {
+        let key = id.as_hash().as_bytes();
+
+        let mut bitmap = [0u8; 32];
+        let mut siblings = Vec::new();
+
+        let mut push = |depth: usize, sibling: Hash| {
+            let index = depth - 1;
+            bitmap[index / 8] |= 1 << (7 - index % 8);
+            siblings.push(sibling);
+        };
+
+        let mut node = self.root.as_ref();
+
+        while let Some(current) = node {
+            let depth = current.depth();
+            let common = common_prefix(key, current.path(), depth);
+
+            // The whole subtree hangs beside the path
+            if common < depth {
+                push(common + 1, current.lift(common + 1));
+                break;
+            }
+
+            match current {
+                Node::Leaf { .. } => break,
+                Node::Branch { lifted, children, .. } => {
+                    let side = bit(key, depth) as usize;
+                    push(depth + 1, lifted[1 - side]);
+                    node = Some(&children[side]);
+                }
+            }
+        }
+
+        MerkleProof { bitmap, siblings, tombstone: None }
+    }
+
+    /// Serialize the stored nodes with their leaf hashes.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        if let Some(root) = &self.root {
+            root.encode(&mut out);
+        }
+        out
+    }
+
+    /// Restore a tree from `encode` output without rehashing its leaves.
+    ///
+    /// Internal hashes are recomputed from the leaf hashes, so a restored
+    /// tree always commits to exactly the leaves it holds.
+    ///
+    /// Returns `None` if `bytes` is not a well-formed tree.
+    pub fn decode(bytes: &[u8]) -> Option<Self> {
+        if bytes.is_empty() {
+            return Some(Self::new());
+        }
+
+        let mut cursor = bytes;
+        let root = Node::decode(&mut cursor, 0)?;
+        if !cursor.is_empty() {
+            return None;
+        }
+
+        Some(Self { root_hash: root.lift(0), root: Some(root) })
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------- //
+
+//----------------------------------------------- Merkle Proof -----------------------------------------------//
+
+/// Inclusion or exclusion proof for a single object id.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MerkleProof {
+    /// Bit `d - 1` is set when the sibling at depth `d` is non-empty.
+    pub bitmap: [u8; 32],
+
+    /// Non-empty siblings, from the top of the tree down to the leaf.
+    pub siblings: Vec<Hash>,
+
+    /// Tombstone version when the id was deleted.
+    ///
+    /// Deleted objects are absent but their leaf is not empty.
+    pub tombstone: Option<Version>,
+}
+
+/// Verify that `object` is the value of `id` under `root`.
+///
+/// `None` proves that no object with `id` exists (never created, or deleted).
+pub fn verify_proof(
+    root: &Hash,
+    id: &ObjectId,
+    object: Option<&StateObject>,
+    proof: &MerkleProof,
+) -> bool {
+    let key = id.as_hash().as_bytes();
+    let empty = empty_hashes();
+
+    let mut current = match (object, proof.tombstone) {
+        (Some(object), None) => {
+            if object.id() != *id {
+                return false;
+            }
+            object_leaf_hash(object)
+        }
+        (None, Some(version)) => tombstone_leaf_hash(id, version),
+        (None, None) => empty[TREE_DEPTH],
+        (Some(_), Some(_)) => return false,
+    };
+
+    let mut siblings = proof.siblings.iter().rev();
+
+    for depth in (1..=TREE_DEPTH).rev() {
+        let index = depth - 1;
+        let sibling = if (proof.bitmap[index / 8] >> (7 - index % 8)) & 1 == 1 {
+            match siblings.next() {
+                Some(sibling) => *sibling,
+                None => return false,
+            }
+        } else {
+            empty[depth]
+        };
+
+        current = if bit(key, index) {
+            node_hash(&sibling, &current)
+        } else {
+            node_hash(&current, &sibling)
+        };
+    }
+
+    siblings.next().is_none() && current == *root
+}
+
+// -------------------------------------------------------------------------------------------------------------------------- //
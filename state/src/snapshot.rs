@@ -0,0 +1,273 @@
+
+// Imports and dependencies
+use axiom_types::{Address, Hash, ObjectId};
+use crate::backend::{MemoryBackend, StateBackend, WriteBatch};
+use crate::{ObjectWrite, SnapshotError, StateObject, StateStore, compute_state_root};
+
+// -------------------------------------------------------------------------------------------------------------------------- //
+
+//----------------------------------------------- State Snapshots -----------------------------------------------//
+
+// Deterministic snapshot of committed state.
+//
+// Objects and tombstones are encoded in ascending id order and packed into
+// chunks of roughly `chunk_size` bytes; an entry never spans two chunks.
+// Each chunk is:
+//
+//   u32 entry count | entries
+//
+// where an entry is `tag | id | version` followed, for objects, by
+// `owner | u32 data length | data`. The manifest lists the hash of every
+// chunk and the state root the snapshot must rebuild.
+
+/// Version of the snapshot format.
+pub const SNAPSHOT_VERSION: u8 = 1;
+
+const TAG_OBJECT: u8 = 0;
+const TAG_TOMBSTONE: u8 = 1;
+
+/// Describes a snapshot and commits to its contents.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SnapshotManifest {
+    /// Snapshot format version.
+    pub version: u8,
+
+    /// Height of the committed state captured.
+    pub height: u64,
+
+    /// State root the imported state MUST have.
+    pub state_root: Hash,
+
+    /// Hash of each chunk, in order.
+    pub chunk_hashes: Vec<Hash>,
+}
+
+impl SnapshotManifest {
+    /// Canonical hash of the manifest, for peers to agree on a snapshot.
+    pub fn hash(&self) -> Hash {
+        let mut bytes = Vec::new();
+
+        // Domain separation
+        bytes.extend_from_slice(b"Axiom::SnapshotManifest");
+
+        bytes.push(self.version);
+        bytes.extend_from_slice(&self.height.to_be_bytes());
+        bytes.extend_from_slice(self.state_root.as_bytes());
+        bytes.extend_from_slice(&(self.chunk_hashes.len() as u32).to_be_bytes());
+        for hash in &self.chunk_hashes {
+            bytes.extend_from_slice(hash.as_bytes());
+        }
+
+        Hash::new(blake3::hash(&bytes).into())
+    }
+}
+
+fn chunk_hash(chunk: &[u8]) -> Hash {
+    Hash::new(blake3::hash(chunk).into())
+}
+
+/// Export the committed state as a manifest and its chunks.
+///
+/// Fails if the store has staged writes, so the snapshot always
+/// corresponds to a committed height.
+pub fn export_snapshot(
+    state: &StateStore,
+    chunk_size: usize,
+) -> Result<(SnapshotManifest, Vec<Vec<u8>>), SnapshotError> {
+    if state.has_pending() {
+        return Err(SnapshotError::PendingWrites);
+    }
+
+    let mut entries: Vec<ObjectWrite> = state.objects_iter()
+        .cloned()
+        .map(ObjectWrite::Put)
+        .chain(state.tombstones_iter().map(|(id, version)| ObjectWrite::Delete { id, version }))
+        .collect();
+
+    // Canonical ordering
+    entries.sort_by_key(ObjectWrite::id);
+
+    let mut chunks = Vec::new();
+    let mut current: Vec<u8> = Vec::new();
+    let mut count: u32 = 0;
+
+    for entry in &entries {
+        encode_entry(&mut current, entry);
+        count += 1;
+
+        if current.len() >= chunk_size {
+            chunks.push(finish_chunk(count, &current));
+            current.clear();
+            count = 0;
+        }
+    }
+
+    if count > 0 {
+        chunks.push(finish_chunk(count, &current));
+    }
+
+    let manifest = SnapshotManifest {
+        version: SNAPSHOT_VERSION,
+        height: state.height().unwrap_or(0),
+        state_root: compute_state_root(state),
+        chunk_hashes: chunks.iter().map(|chunk| chunk_hash(chunk)).collect(),
+    };
+
+    Ok((manifest, chunks))
+}
+
+fn finish_chunk(count: u32, entries: &[u8]) -> Vec<u8> {
+    let mut chunk = Vec::with_capacity(4 + entries.len());
+    chunk.extend_from_slice(&count.to_be_bytes());
+    chunk.extend_from_slice(entries);
+    chunk
+}
+
+fn encode_entry(buf: &mut Vec<u8>, entry: &ObjectWrite) {
+    match entry {
+        ObjectWrite::Put(object) => {
+            buf.push(TAG_OBJECT);
+            buf.extend_from_slice(object.id().as_hash().as_bytes());
+            buf.extend_from_slice(&object.version().to_be_bytes());
+            buf.extend_from_slice(object.owner().as_bytes());
+            buf.extend_from_slice(&(object.data().len() as u32).to_be_bytes());
+            buf.extend_from_slice(object.data());
+        }
+        ObjectWrite::Delete { id, version } => {
+            buf.push(TAG_TOMBSTONE);
+            buf.extend_from_slice(id.as_hash().as_bytes());
+            buf.extend_from_slice(&version.to_be_bytes());
+        }
+    }
+}
+
+/// Decode one chunk, or `None` if it is malformed.
+fn decode_chunk(chunk: &[u8]) -> Option<Vec<ObjectWrite>> {
+    let mut cursor = chunk;
+
+    let mut take = |len: usize| -> Option<&[u8]> {
+        if len > cursor.len() {
+            return None;
+        }
+        let (head, tail) = cursor.split_at(len);
+        cursor = tail;
+        Some(head)
+    };
+
+    let count = u32::from_be_bytes(take(4)?.try_into().ok()?);
+    let mut entries = Vec::new();
+
+    for _ in 0..count {
+        let tag = take(1)?[0];
+        let id = ObjectId::new(Hash::new(take(32)?.try_into().ok()?));
+        let version = u64::from_be_bytes(take(8)?.try_into().ok()?);
+
+        entries.push(match tag {
+            TAG_OBJECT => {
+                let owner = Address::new(take(32)?.try_into().ok()?);
+                let len = u32::from_be_bytes(take(4)?.try_into().ok()?) as usize;
+                let data = take(len)?.to_vec();
+                ObjectWrite::Put(StateObject::from_parts(id, owner, version, data))
+            }
+            TAG_TOMBSTONE => ObjectWrite::Delete { id, version },
+            _ => return None,
+        });
+    }
+
+    cursor.is_empty().then_some(entries)
+}
+
+// -------------------------------------------------------------------------------------------------------------------------- //
+
+//----------------------------------------------- Snapshot Import -----------------------------------------------//
+
+/// Streaming snapshot import.
+///
+/// Chunks are pushed in manifest order and verified against their hash
+/// as they arrive; `finish` rebuilds the state and checks its root.
+#[derive(Debug)]
+pub struct SnapshotImporter {
+    manifest: SnapshotManifest,
+    batch: WriteBatch,
+    next_chunk: usize,
+    last_id: Option<ObjectId>,
+}
+
+impl SnapshotImporter {
+    /// Start importing the snapshot described by `manifest`.
+    pub fn new(manifest: SnapshotManifest) -> Result<Self, SnapshotError> {
+        if manifest.version != SNAPSHOT_VERSION {
+            return Err(SnapshotError::UnsupportedVersion { version: manifest.version });
+        }
+
+        Ok(Self {
+            manifest,
+            batch: WriteBatch::new(),
+            next_chunk: 0,
+            last_id: None,
+        })
+    }
+
+    /// Verify and apply the next chunk.
+    pub fn push_chunk(&mut self, chunk: &[u8]) -> Result<(), SnapshotError> {
+        let index = self.next_chunk;
+
+        let expected = self.manifest.chunk_hashes.get(index)
+            .ok_or(SnapshotError::UnexpectedChunk { index })?;
+
+        if chunk_hash(chunk) != *expected {
+            return Err(SnapshotError::ChunkHashMismatch { index });
+        }
+
+        let entries = decode_chunk(chunk).ok_or(SnapshotError::MalformedChunk { index })?;
+
+        for entry in entries {
+            let id = entry.id();
+
+            // Strictly ascending ids across the whole snapshot
+            if self.last_id.is_some_and(|last| last >= id) {
+                return Err(SnapshotError::MalformedChunk { index });
+            }
+            self.last_id = Some(id);
+
+            self.batch.insert(id, entry);
+        }
+
+        self.next_chunk += 1;
+        Ok(())
+    }
+
+    /// Finish into a new in-memory state store.
+    pub fn finish(self) -> Result<StateStore, SnapshotError> {
+        self.finish_into(Box::new(MemoryBackend::new()))
+    }
+
+    /// Finish by committing the snapshot into an empty `backend`.
+    ///
+    /// The root is checked before anything is written to the backend.
+    pub fn finish_into(self, mut backend: Box<dyn StateBackend>) -> Result<StateStore, SnapshotError> {
+        let received = self.next_chunk;
+        let expected = self.manifest.chunk_hashes.len();
+
+        if received != expected {
+            return Err(SnapshotError::MissingChunks { expected, received });
+        }
+
+        // Rebuild in memory first and check the root
+        let mut staging = MemoryBackend::new();
+        staging.commit(self.manifest.height, &self.batch).map_err(SnapshotError::State)?;
+
+        let root = compute_state_root(&StateStore::with_backend(Box::new(staging)));
+        if root != self.manifest.state_root {
+            return Err(SnapshotError::RootMismatch {
+                expected: self.manifest.state_root,
+                found: root,
+            });
+        }
+
+        backend.commit(self.manifest.height, &self.batch).map_err(SnapshotError::State)?;
+        Ok(StateStore::with_backend(backend))
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------- //
@@ -1,63 +1,348 @@
 
 /// Imports and dependencies
-use axiom_types::{ObjectId};
+use axiom_types::{Hash, ObjectId};
 use crate::StateObject;
+use crate::ObjectWrite;
 use crate::Version;
 use crate::StateError;
+use crate::smt::{MerkleProof, SparseMerkleTree, object_leaf_hash, tombstone_leaf_hash};
+use crate::backend::{MemoryBackend, StateBackend, WriteBatch};
+use crate::log_backend::LogBackend;
+use crate::history::HistoricalState;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::Path;
 
 // -------------------------------------------------------------------------------------------------------------------------- //
 
 //----------------------------------------------- State Store -----------------------------------------------//
 
-/// In-memory state store for Axiom.
-///
-/// This module provides a deterministic, version-aware state store backed
-/// by an in-memory HashMap. It enforces object isolation, version monotonicity,
-/// and atomic updates.
-
-use std::collections::HashMap;
+// State store for Axiom.
+//
+// This module provides a deterministic, version-aware state store over a
+// pluggable `StateBackend`. It enforces object isolation, version monotonicity,
+// and atomic updates. Writes are staged in memory and become durable when
+// `commit` hands them to the backend as one batch.
+//
+// Staged writes can be layered with `checkpoint`: every write lands in the
+// newest layer, which is later either released into the layer below or
+// rolled back, restoring objects and the state root exactly.
 
 /// A write set containing proposed updates to state objects.
-pub type WriteSet = HashMap<ObjectId, StateObject>;
+pub type WriteSet = HashMap<ObjectId, ObjectWrite>;
 
 /// A read set containing versions of state objects read during a transaction.
 pub type ReadSet = HashMap<ObjectId, Version>;
 
-/// In-Memory state store.
+/// Handle to a layer opened by `StateStore::checkpoint`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[must_use]
+pub struct Checkpoint {
+    depth: usize,
+}
+
+/// Writes made since a checkpoint, with what they replaced.
 #[derive(Default)]
+struct Layer {
+    writes: WriteBatch,
+
+    /// Tree leaf of each touched id before its first write in this layer.
+    previous_leaves: BTreeMap<ObjectId, Option<Hash>>,
+}
+
+/// State store.
 pub struct StateStore {
-    objects: HashMap<ObjectId, StateObject>,
+    /// Committed state.
+    backend: Box<dyn StateBackend>,
+
+    /// Writes applied since the last commit, shadowing the backend.
+    pending: WriteBatch,
+
+    /// Open checkpoint layers, oldest first, shadowing `pending`.
+    layers: Vec<Layer>,
+
+    /// Commitment to objects and tombstones, updated on every write.
+    tree: SparseMerkleTree,
+
+    /// Number of past heights kept readable (None keeps all).
+    retention: Option<u64>,
+}
+
+impl Default for StateStore {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl StateStore {
-    /// Create a new empty state store.
+    /// Create a new empty, in-memory state store.
     pub fn new() -> Self {
+        Self::with_backend(Box::new(MemoryBackend::new()))
+    }
+
+    /// Create a state store over the committed state of `backend`.
+    ///
+    /// Starts from the backend's saved tree when it has one, rehashing
+    /// only the leaves committed since.
+    pub fn with_backend(backend: Box<dyn StateBackend>) -> Self {
         Self {
-            objects: HashMap::new(),
+            tree: restored_tree(backend.as_ref())
+                .unwrap_or_else(|| committed_tree(backend.as_ref())),
+            backend,
+            pending: WriteBatch::new(),
+            layers: Vec::new(),
+            retention: None,
+        }
+    }
+
+    /// Open a persistent state store backed by the log at `path`.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, StateError> {
+        Ok(Self::with_backend(Box::new(LogBackend::open(path)?)))
+    }
+
+    /// Persist all staged writes to the backend as one atomic batch
+    /// at the next height (0 for the first commit).
+    ///
+    /// Called once per block. On failure the writes stay staged.
+    /// Fails if any checkpoint is still open.
+    pub fn commit(&mut self) -> Result<(), StateError> {
+        let height = self.height().map_or(0, |height| height + 1);
+        self.commit_at(height)
+    }
+
+    /// Persist all staged writes as the state at block `height`.
+    ///
+    /// Heights MUST strictly increase; gaps are allowed.
+    pub fn commit_at(&mut self, height: u64) -> Result<(), StateError> {
+        if !self.layers.is_empty() {
+            return Err(StateError::OpenCheckpoint);
+        }
+
+        self.backend.commit(height, &self.pending)?;
+        self.pending.clear();
+        self.backend.save_tree(height, &self.tree);
+
+        if let Some(retention) = self.retention {
+            self.backend.prune(height.saturating_sub(retention));
+        }
+
+        Ok(())
+    }
+
+    /// Discard every block committed above `height`, restoring the
+    /// state committed at `height`.
+    ///
+    /// Staged writes are discarded. Fails if a checkpoint is open or
+    /// `height` is not readable.
+    pub fn revert_to(&mut self, height: u64) -> Result<(), StateError> {
+        if !self.layers.is_empty() {
+            return Err(StateError::OpenCheckpoint);
+        }
+
+        // Only ids written since `height` can have different leaves
+        let mut touched: BTreeSet<ObjectId> = self.backend.changed_above(height)?;
+        touched.extend(self.pending.keys().copied());
+
+        self.backend.revert(height)?;
+        self.pending.clear();
+
+        for id in touched {
+            self.tree.update(&id, committed_leaf(self.backend.as_ref(), &id));
+        }
+
+        Ok(())
+    }
+
+    /// Height of the last committed block, if any.
+    pub fn height(&self) -> Option<u64> {
+        self.backend.height()
+    }
+
+    /// Keep only the last `retention` heights readable, besides the
+    /// current one (None keeps all history).
+    pub fn set_retention(&mut self, retention: Option<u64>) {
+        self.retention = retention;
+
+        if let (Some(retention), Some(height)) = (retention, self.height()) {
+            self.backend.prune(height.saturating_sub(retention));
+        }
+    }
+
+    /// Retrieve an object as it was after block `height` was committed.
+    pub fn get_at(&self, id: &ObjectId, height: u64) -> Result<Option<&StateObject>, StateError> {
+        Ok(self.backend.get_at(id, height)?.and_then(ObjectWrite::object))
+    }
+
+    /// Return the tombstone version of `id` as of block `height`, if deleted then.
+    pub fn tombstone_at(&self, id: &ObjectId, height: u64) -> Result<Option<Version>, StateError> {
+        Ok(match self.backend.get_at(id, height)? {
+            Some(ObjectWrite::Delete { version, .. }) => Some(*version),
+            _ => None,
+        })
+    }
+
+    /// Read-only view of state as of block `height`.
+    pub fn at(&self, height: u64) -> Result<HistoricalState<'_>, StateError> {
+        // Validated up front so view reads cannot fail
+        self.backend.check_readable(height)?;
+        Ok(HistoricalState::new(self, height))
+    }
+
+    /// Return true if there are writes not yet committed to the backend.
+    pub fn has_pending(&self) -> bool {
+        !self.pending.is_empty() || self.layers.iter().any(|layer| !layer.writes.is_empty())
+    }
+
+    /// Open a new layer; subsequent writes can be rolled back to here.
+    ///
+    /// Checkpoints nest and must be closed (released or rolled back)
+    /// newest first; closing an outer checkpoint closes inner ones too.
+    pub fn checkpoint(&mut self) -> Checkpoint {
+        self.layers.push(Layer::default());
+        Checkpoint { depth: self.layers.len() }
+    }
+
+    /// Discard every write made since `checkpoint`.
+    pub fn rollback(&mut self, checkpoint: Checkpoint) -> Result<(), StateError> {
+        self.check_open(checkpoint)?;
+
+        while self.layers.len() >= checkpoint.depth {
+            let Some(layer) = self.layers.pop() else { break };
+
+            for (id, leaf) in layer.previous_leaves {
+                self.tree.update(&id, leaf);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Keep every write made since `checkpoint`, merging it into the layer below.
+    pub fn release(&mut self, checkpoint: Checkpoint) -> Result<(), StateError> {
+        self.check_open(checkpoint)?;
+
+        while self.layers.len() >= checkpoint.depth {
+            let Some(layer) = self.layers.pop() else { break };
+
+            match self.layers.last_mut() {
+                Some(parent) => {
+                    for (id, leaf) in layer.previous_leaves {
+                        parent.previous_leaves.entry(id).or_insert(leaf);
+                    }
+                    parent.writes.extend(layer.writes);
+                }
+                None => self.pending.extend(layer.writes),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Ids written since `checkpoint` was opened.
+    pub fn written_since(&self, checkpoint: Checkpoint) -> Result<BTreeSet<ObjectId>, StateError> {
+        self.check_open(checkpoint)?;
+
+        Ok(self.layers[checkpoint.depth - 1..]
+            .iter()
+            .flat_map(|layer| layer.writes.keys().copied())
+            .collect())
+    }
+
+    /// Retrieve `id` as it was when `checkpoint` was opened.
+    pub fn get_before(&self, checkpoint: Checkpoint, id: &ObjectId) -> Result<Option<&StateObject>, StateError> {
+        self.check_open(checkpoint)?;
+
+        let staged = self.layers[..checkpoint.depth - 1]
+            .iter()
+            .rev()
+            .find_map(|layer| layer.writes.get(id))
+            .or_else(|| self.pending.get(id));
+
+        Ok(match staged {
+            Some(write) => write.object(),
+            None => self.backend.get(id),
+        })
+    }
+
+    fn check_open(&self, checkpoint: Checkpoint) -> Result<(), StateError> {
+        if checkpoint.depth == 0 || checkpoint.depth > self.layers.len() {
+            return Err(StateError::UnknownCheckpoint);
         }
+        Ok(())
+    }
+
+    /// Return the newest staged write to `id`, if any.
+    fn staged(&self, id: &ObjectId) -> Option<&ObjectWrite> {
+        self.layers
+            .iter()
+            .rev()
+            .find_map(|layer| layer.writes.get(id))
+            .or_else(|| self.pending.get(id))
+    }
+
+    /// Merge all staged layers into one view (newest write wins).
+    fn staged_view(&self) -> BTreeMap<ObjectId, &ObjectWrite> {
+        let mut view: BTreeMap<ObjectId, &ObjectWrite> = self.pending.iter()
+            .map(|(id, write)| (*id, write))
+            .collect();
+
+        for layer in &self.layers {
+            view.extend(layer.writes.iter().map(|(id, write)| (*id, write)));
+        }
+
+        view
     }
 
     /// Retrieve a state object by its identifier.
     pub fn get(&self, id: &ObjectId) -> Option<&StateObject> {
-        self.objects.get(id)
+        match self.staged(id) {
+            Some(write) => write.object(),
+            None => self.backend.get(id),
+        }
+    }
+
+    /// Return the tombstone version of a deleted object, if any.
+    pub fn tombstone(&self, id: &ObjectId) -> Option<Version> {
+        match self.staged(id) {
+            Some(ObjectWrite::Delete { version, .. }) => Some(*version),
+            Some(ObjectWrite::Put(_)) => None,
+            None => self.backend.tombstone(id),
+        }
     }
 
     /// Insert a new state object
-    /// Fails if the object already exists
+    /// Fails if the object already exists or was deleted at a newer version
     pub fn insert(&mut self, object: StateObject) -> Result<(), StateError> {
-        if self.objects.contains_key(&object.id()) {
+        if self.get(&object.id()).is_some() {
             return Err(StateError::ObjectAlreadyExists);
         }
-        self.objects.insert(object.id(), object);
+        self.check_recreation(&object)?;
+
+        self.put(object);
         Ok(())
     }
 
+    /// Remove an existing object, leaving a tombstone at `version`.
+    ///
+    /// `version` MUST be exactly one ahead of the removed object.
+    pub fn remove(&mut self, id: &ObjectId, version: Version) -> Result<StateObject, StateError> {
+        let existing = self.get(id).ok_or(StateError::ObjectNotFound)?;
+
+        if version != existing.version() + 1 {
+            return Err(StateError::InvalidVersion {
+                expected: existing.version() + 1,
+                found: version,
+            });
+        }
+
+        self.delete(*id, version).ok_or(StateError::ObjectNotFound)
+    }
+
     /// Apply a write set to the state store atomically.
     /// All updates must be valid; otherwise, no changes are made.
     pub fn apply(&mut self, read_set: &ReadSet, write_set: WriteSet) -> Result<(), StateError> {
         // Validate read set
         for (id, expected_version) in read_set {
-            match self.objects.get(id) {
+            match self.get(id) {
                 Some(existing) => {
                     if existing.version() != *expected_version {
                         return Err(StateError::StaleRead {
@@ -73,47 +358,181 @@ impl StateStore {
         }
         
         // Validate write set
-        for (id, new_object) in &write_set {
-            match self.objects.get(id) {
-                Some(existing_object) => {
-                    if new_object.version() != existing_object.version() + 1 {
+        for (id, write) in &write_set {
+            match (self.get(id), write) {
+                (Some(existing_object), _) => {
+                    if write.version() != existing_object.version() + 1 {
                         return Err(StateError::InvalidVersion {
                             expected: existing_object.version() + 1,
-                            found: new_object.version(),
+                            found: write.version(),
                         });
                     }
                 }
-                None => {
-                    // If the object doesn't exist, it's a new object
-                    if new_object.version() != 0 {
+                (None, ObjectWrite::Put(new_object)) => {
+                    // New objects start at version 0, or directly after
+                    // the tombstone of a deleted object
+                    let expected = self.tombstone(id).map_or(0, |version| version + 1);
+                    if new_object.version() != expected {
                         return Err(StateError::InvalidVersion {
-                            expected: 0,
+                            expected,
                             found: new_object.version(),
                         });
                     }
                 }
+                (None, ObjectWrite::Delete { .. }) => {
+                    return Err(StateError::ObjectNotFound);
+                }
             }
         }
 
         // Apply all updates
-        for (id, new_object) in write_set {
-            self.objects.insert(id, new_object);
+        for (id, write) in write_set {
+            match write {
+                ObjectWrite::Put(new_object) => {
+                    self.put(new_object);
+                }
+                ObjectWrite::Delete { version, .. } => {
+                    self.delete(id, version);
+                }
+            }
         }
 
         Ok(())
     }   
 
     /// Insert or update a state object.
+    /// Re-creating a deleted object requires a version newer than its tombstone
     pub fn insert_or_update(&mut self, object: StateObject) -> Result<(), StateError> {
-        self.objects.insert(object.id(), object);
+        if self.get(&object.id()).is_none() {
+            self.check_recreation(&object)?;
+        }
+
+        self.put(object);
         Ok(())
     }
 
     /// Iterate over all state objects (read-only).
-    pub fn objects_iter(&self) -> impl Iterator<Item = (&ObjectId, &StateObject)> {
-        self.objects.iter()
+    pub fn objects_iter(&self) -> impl Iterator<Item = &StateObject> {
+        let staged = self.staged_view();
+
+        let committed: Vec<&StateObject> = self.backend
+            .objects()
+            .filter(|object| !staged.contains_key(&object.id()))
+            .collect();
+
+        committed.into_iter().chain(staged.into_values().filter_map(ObjectWrite::object))
+    }
+
+    /// Iterate over all tombstones (read-only).
+    pub fn tombstones_iter(&self) -> impl Iterator<Item = (ObjectId, Version)> + '_ {
+        let staged = self.staged_view();
+
+        let committed: Vec<(ObjectId, Version)> = self.backend
+            .tombstones()
+            .filter(|(id, _)| !staged.contains_key(id))
+            .collect();
+
+        let deleted = staged.into_iter().filter_map(|(id, write)| match write {
+            ObjectWrite::Delete { version, .. } => Some((id, *version)),
+            ObjectWrite::Put(_) => None,
+        });
+
+        committed.into_iter().chain(deleted)
+    }
+
+    /// Return the root committing to all objects and tombstones.
+    pub fn state_root(&self) -> Hash {
+        self.tree.root()
+    }
+
+    /// Build an inclusion or exclusion proof for `id` against `state_root`.
+    pub fn prove(&self, id: &ObjectId) -> MerkleProof {
+        let mut proof = self.tree.prove(id);
+        proof.tombstone = self.tombstone(id);
+        proof
+    }
+
+    /// Stage `object`, clearing any tombstone at its id.
+    fn put(&mut self, object: StateObject) {
+        let id = object.id();
+
+        self.set_leaf(id, object_leaf_hash(&object));
+        self.stage(id, ObjectWrite::Put(object));
+    }
+
+    /// Stage removal of the object at `id`, leaving a tombstone at `version`.
+    fn delete(&mut self, id: ObjectId, version: Version) -> Option<StateObject> {
+        let removed = self.get(&id).cloned();
+
+        self.set_leaf(id, tombstone_leaf_hash(&id, version));
+        self.stage(id, ObjectWrite::Delete { id, version });
+        removed
+    }
+
+    /// Update the tree, remembering the replaced leaf for rollback.
+    fn set_leaf(&mut self, id: ObjectId, leaf: Hash) {
+        if let Some(layer) = self.layers.last_mut() {
+            let tree = &self.tree;
+            layer.previous_leaves.entry(id).or_insert_with(|| tree.leaf(&id));
+        }
+
+        self.tree.update(&id, Some(leaf));
+    }
+
+    /// Record a write in the newest layer.
+    fn stage(&mut self, id: ObjectId, write: ObjectWrite) {
+        match self.layers.last_mut() {
+            Some(layer) => layer.writes.insert(id, write),
+            None => self.pending.insert(id, write),
+        };
+    }
+
+    /// Reject objects that would re-use a deleted id at a stale version.
+    fn check_recreation(&self, object: &StateObject) -> Result<(), StateError> {
+        match self.tombstone(&object.id()) {
+            Some(deleted) if object.version() <= deleted => {
+                Err(StateError::StaleRecreation {
+                    deleted,
+                    found: object.version(),
+                })
+            }
+            _ => Ok(()),
+        }
     }
     
 }
 
 // -------------------------------------------------------------------------------------------------------------------------- //
+
+/// Leaf of `id` in the committed state of `backend`.
+fn committed_leaf(backend: &dyn StateBackend, id: &ObjectId) -> Option<Hash> {
+    match backend.get(id) {
+        Some(object) => Some(object_leaf_hash(object)),
+        None => backend.tombstone(id).map(|version| tombstone_leaf_hash(id, version)),
+    }
+}
+
+/// Bring the backend's saved tree up to its committed height, if it has one.
+fn restored_tree(backend: &dyn StateBackend) -> Option<SparseMerkleTree> {
+    let (height, mut tree) = backend.saved_tree()?;
+
+    for id in backend.changed_above(height).ok()? {
+        tree.update(&id, committed_leaf(backend, &id));
+    }
+
+    Some(tree)
+}
+
+/// Build the tree committing to the committed state of `backend`.
+fn committed_tree(backend: &dyn StateBackend) -> SparseMerkleTree {
+    let mut tree = SparseMerkleTree::new();
+
+    for object in backend.objects() {
+        tree.update(&object.id(), Some(object_leaf_hash(object)));
+    }
+    for (id, version) in backend.tombstones() {
+        tree.update(&id, Some(tombstone_leaf_hash(&id, version)));
+    }
+
+    tree
+}
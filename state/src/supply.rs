@@ -0,0 +1,86 @@
+use axiom_types::{Address, ObjectId, Hash};
+use crate::{StateObject, StateStore, SupplyError, decode_balance, is_balance_object};
+
+const SUPPLY_DOMAIN: &[u8] = b"axiom::supply";
+
+/// Token supply accounting.
+///
+/// Stored in a single system object so it is committed by the state root.
+/// Minting raises `issued` and burning raises `burned`; every other
+/// balance change moves tokens between accounts.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Supply {
+    /// Tokens ever minted.
+    pub issued: u64,
+
+    /// Tokens ever burned.
+    pub burned: u64,
+}
+
+impl Supply {
+    /// Encode supply into object data.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(16);
+        bytes.extend_from_slice(&self.issued.to_le_bytes());
+        bytes.extend_from_slice(&self.burned.to_le_bytes());
+        bytes
+    }
+
+    /// Decode supply from object data.
+    pub fn decode(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != 16 {
+            return None;
+        }
+
+        Some(Self {
+            issued: u64::from_le_bytes(bytes[..8].try_into().ok()?),
+            burned: u64::from_le_bytes(bytes[8..].try_into().ok()?),
+        })
+    }
+
+    /// Build the supply system object.
+    pub fn to_object(&self) -> StateObject {
+        StateObject::new(supply_object_id(), Address::zero(), self.encode())
+    }
+}
+
+/// ObjectId of the supply object.
+///
+/// There is exactly ONE supply object per chain.
+pub fn supply_object_id() -> ObjectId {
+    let hash = Hash::new(blake3::hash(SUPPLY_DOMAIN).into());
+    ObjectId::new(hash)
+}
+
+/// Read the supply recorded in `state`.
+///
+/// Returns `Ok(None)` if `state` does not track supply.
+pub fn read_supply(state: &StateStore) -> Result<Option<Supply>, SupplyError> {
+    match state.get(&supply_object_id()) {
+        Some(object) => Supply::decode(object.data())
+            .map(Some)
+            .ok_or(SupplyError::MalformedSupply),
+        None => Ok(None),
+    }
+}
+
+/// Check that `state` conserves supply: the sum of all balance objects
+/// plus everything burned equals everything issued.
+pub fn check_supply(state: &StateStore) -> Result<Supply, SupplyError> {
+    let supply = read_supply(state)?.ok_or(SupplyError::MissingSupply)?;
+
+    let balances: u128 = state.objects_iter()
+        .filter(|object| is_balance_object(object))
+        .map(|object| decode_balance(object) as u128)
+        .sum();
+
+    if balances + supply.burned as u128 != supply.issued as u128 {
+        return Err(SupplyError::NotConserved {
+            issued: supply.issued,
+            burned: supply.burned,
+            balances,
+        });
+    }
+
+    Ok(supply)
+}
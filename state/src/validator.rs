@@ -0,0 +1,32 @@
+use axiom_types::{Address, ObjectId, Hash};
+use crate::StateObject;
+
+const VALIDATOR_DOMAIN: &[u8] = b"axiom::validator";
+
+/// Deterministically derive the validator ObjectId for an address.
+///
+/// The object holds the validator's stake.
+pub fn validator_object_id(address: Address) -> ObjectId {
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(VALIDATOR_DOMAIN);
+    bytes.extend_from_slice(address.as_bytes());
+
+    let hash = Hash::new(blake3::hash(&bytes).into());
+    ObjectId::new(hash)
+}
+
+/// Returns true if `obj` is the validator object of its owner.
+pub fn is_validator_object(obj: &StateObject) -> bool {
+    obj.id() == validator_object_id(obj.owner())
+}
+
+/// Decode stake from validator object data
+pub fn decode_stake(obj: &StateObject) -> u64 {
+    let bytes = obj.data();
+    u64::from_le_bytes(bytes.try_into().unwrap())
+}
+
+/// Encode stake into validator object data
+pub fn encode_stake(stake: u64) -> Vec<u8> {
+    stake.to_le_bytes().to_vec()
+}
@@ -0,0 +1,58 @@
+
+// Imports and dependencies
+use axiom_types::ObjectId;
+use crate::{StateObject, Version};
+
+// -------------------------------------------------------------------------------------------------------------------------- //
+
+//----------------------------------------------- Object Write -----------------------------------------------//
+
+/// A single proposed change to a state object.
+///
+/// Deletions are recorded as tombstones that occupy a version of their
+/// own, so an id can only be re-created at a strictly newer version and
+/// reads taken before the deletion can never validate against it.
+#[derive(Clone, Debug)]
+pub enum ObjectWrite {
+    /// Create or replace the object.
+    Put(StateObject),
+
+    /// Remove the object, leaving a tombstone at `version`.
+    Delete { id: ObjectId, version: Version },
+}
+
+impl ObjectWrite {
+    /// Delete `object`, one version ahead of the one being removed.
+    pub fn delete(object: &StateObject) -> Self {
+        Self::Delete {
+            id: object.id(),
+            version: object.version() + 1,
+        }
+    }
+
+    /// Return the identifier of the written object.
+    pub fn id(&self) -> ObjectId {
+        match self {
+            Self::Put(object) => object.id(),
+            Self::Delete { id, .. } => *id,
+        }
+    }
+
+    /// Return the version the object ends up at.
+    pub fn version(&self) -> Version {
+        match self {
+            Self::Put(object) => object.version(),
+            Self::Delete { version, .. } => *version,
+        }
+    }
+
+    /// Return the written object, or `None` for a deletion.
+    pub fn object(&self) -> Option<&StateObject> {
+        match self {
+            Self::Put(object) => Some(object),
+            Self::Delete { .. } => None,
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------- //
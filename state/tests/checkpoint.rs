@@ -0,0 +1,126 @@
+use axiom_state::{StateStore, StateObject, StateError, compute_state_root};
+use axiom_types::{Address, ObjectId, Hash};
+
+// -------------------------------------------------------------
+// Helper: object with a distinct id, owner and data
+// -------------------------------------------------------------
+fn object(seed: u8, data: &[u8]) -> StateObject {
+    StateObject::new(
+        ObjectId::new(Hash::new([seed; 32])),
+        Address::new([seed; 32]),
+        data.to_vec(),
+    )
+}
+
+fn id(seed: u8) -> ObjectId {
+    ObjectId::new(Hash::new([seed; 32]))
+}
+
+// -------------------------------------------------------------
+// Test: Rollback restores objects, tombstones and the root
+// -------------------------------------------------------------
+#[test]
+fn rollback_restores_state() {
+    let mut state = StateStore::new();
+    state.insert(object(1, &[1])).unwrap();
+    state.insert(object(2, &[2])).unwrap();
+    let root = compute_state_root(&state);
+
+    let checkpoint = state.checkpoint();
+    state.insert_or_update(state.get(&id(1)).unwrap().next_with_data(vec![9])).unwrap();
+    state.remove(&id(2), 1).unwrap();
+    state.insert(object(3, &[3])).unwrap();
+    assert_ne!(compute_state_root(&state), root);
+
+    state.rollback(checkpoint).unwrap();
+
+    assert_eq!(compute_state_root(&state), root);
+    assert_eq!(state.get(&id(1)).unwrap().data(), &[1]);
+    assert!(state.get(&id(2)).is_some());
+    assert_eq!(state.tombstone(&id(2)), None);
+    assert!(state.get(&id(3)).is_none());
+}
+
+// -------------------------------------------------------------
+// Test: Inner rollback keeps outer writes; release keeps both
+// -------------------------------------------------------------
+#[test]
+fn nested_checkpoints() {
+    let mut state = StateStore::new();
+
+    let block = state.checkpoint();
+    state.insert(object(1, &[1])).unwrap();
+    let after_first = compute_state_root(&state);
+
+    let tx = state.checkpoint();
+    state.insert(object(2, &[2])).unwrap();
+    state.rollback(tx).unwrap();
+
+    assert_eq!(compute_state_root(&state), after_first);
+    assert!(state.get(&id(2)).is_none());
+
+    let tx = state.checkpoint();
+    state.insert_or_update(state.get(&id(1)).unwrap().next_with_data(vec![5])).unwrap();
+    state.release(tx).unwrap();
+    let after_release = compute_state_root(&state);
+
+    // Rolling back the block undoes the released transaction too
+    state.rollback(block).unwrap();
+    assert!(state.get(&id(1)).is_none());
+    assert_eq!(compute_state_root(&state), compute_state_root(&StateStore::new()));
+    assert_ne!(after_release, after_first);
+}
+
+// -------------------------------------------------------------
+// Test: Commit requires all checkpoints to be closed
+// -------------------------------------------------------------
+#[test]
+fn commit_requires_closed_checkpoints() {
+    let mut state = StateStore::new();
+
+    let checkpoint = state.checkpoint();
+    state.insert(object(1, &[1])).unwrap();
+
+    assert!(matches!(state.commit(), Err(StateError::OpenCheckpoint)));
+
+    state.release(checkpoint).unwrap();
+    state.commit().unwrap();
+    assert!(!state.has_pending());
+    assert!(state.get(&id(1)).is_some());
+
+    // Closed checkpoints cannot be reused
+    assert!(matches!(state.rollback(checkpoint), Err(StateError::UnknownCheckpoint)));
+}
+
+// -------------------------------------------------------------
+// Test: Writes since a checkpoint and the values they replaced
+// -------------------------------------------------------------
+#[test]
+fn checkpoint_reports_written_ids_and_prior_values() {
+    let mut state = StateStore::new();
+    state.insert(object(1, &[1])).unwrap();
+    state.insert(object(2, &[2])).unwrap();
+    state.commit().unwrap();
+
+    let outer = state.checkpoint();
+    state.insert_or_update(state.get(&id(1)).unwrap().next_with_data(vec![5])).unwrap();
+
+    let inner = state.checkpoint();
+    state.insert_or_update(state.get(&id(1)).unwrap().next_with_data(vec![6])).unwrap();
+    state.remove(&id(2), 1).unwrap();
+    state.insert(object(3, &[3])).unwrap();
+
+    assert_eq!(state.written_since(outer).unwrap().into_iter().collect::<Vec<_>>(), vec![id(1), id(2), id(3)]);
+    assert_eq!(state.written_since(inner).unwrap().len(), 3);
+
+    // Values as each checkpoint was opened
+    assert_eq!(state.get_before(outer, &id(1)).unwrap().unwrap().data(), &[1]);
+    assert_eq!(state.get_before(inner, &id(1)).unwrap().unwrap().data(), &[5]);
+    assert_eq!(state.get_before(inner, &id(2)).unwrap().unwrap().data(), &[2]);
+    assert!(state.get_before(inner, &id(3)).unwrap().is_none());
+
+    state.release(inner).unwrap();
+    assert!(matches!(state.written_since(inner), Err(StateError::UnknownCheckpoint)));
+    assert_eq!(state.written_since(outer).unwrap().len(), 3);
+    state.release(outer).unwrap();
+}
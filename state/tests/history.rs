@@ -0,0 +1,180 @@
+use axiom_state::{StateStore, StateObject, StateError, compute_state_root};
+use axiom_types::{Address, ObjectId, Hash};
+
+// -------------------------------------------------------------
+// Helper: object with a distinct id, owner and data
+// -------------------------------------------------------------
+fn object(seed: u8, data: &[u8]) -> StateObject {
+    StateObject::new(
+        ObjectId::new(Hash::new([seed; 32])),
+        Address::new([seed; 32]),
+        data.to_vec(),
+    )
+}
+
+fn id(seed: u8) -> ObjectId {
+    ObjectId::new(Hash::new([seed; 32]))
+}
+
+fn data_at(state: &StateStore, seed: u8, height: u64) -> Option<Vec<u8>> {
+    state.get_at(&id(seed), height).unwrap().map(|object| object.data().to_vec())
+}
+
+// -------------------------------------------------------------
+// Helper: three blocks touching two objects
+// -------------------------------------------------------------
+fn three_blocks() -> StateStore {
+    let mut state = StateStore::new();
+
+    // Height 0: genesis
+    state.insert(object(1, &[10])).unwrap();
+    state.commit().unwrap();
+
+    // Height 1: update object 1, create object 2
+    state.insert_or_update(state.get(&id(1)).unwrap().next_with_data(vec![11])).unwrap();
+    state.insert(object(2, &[20])).unwrap();
+    state.commit().unwrap();
+
+    // Height 2: delete object 1
+    state.remove(&id(1), 2).unwrap();
+    state.commit().unwrap();
+
+    state
+}
+
+// -------------------------------------------------------------
+// Test: Objects can be read as of any committed height
+// -------------------------------------------------------------
+#[test]
+fn get_at_returns_historical_versions() {
+    let state = three_blocks();
+
+    assert_eq!(state.height(), Some(2));
+
+    assert_eq!(data_at(&state, 1, 0), Some(vec![10]));
+    assert_eq!(data_at(&state, 1, 1), Some(vec![11]));
+    assert_eq!(data_at(&state, 1, 2), None);
+    assert_eq!(state.tombstone_at(&id(1), 2).unwrap(), Some(2));
+
+    assert_eq!(data_at(&state, 2, 0), None);
+    assert_eq!(data_at(&state, 2, 2), Some(vec![20]));
+
+    assert!(matches!(
+        state.get_at(&id(1), 3),
+        Err(StateError::UnknownHeight { height: 3 })
+    ));
+}
+
+// -------------------------------------------------------------
+// Test: Historical views are pinned to their height
+// -------------------------------------------------------------
+#[test]
+fn historical_view_is_pinned() {
+    let mut state = three_blocks();
+
+    // Uncommitted writes do not leak into history
+    state.insert(object(3, &[30])).unwrap();
+
+    let view = state.at(1).unwrap();
+    assert_eq!(view.height(), 1);
+    assert_eq!(view.get(&id(1)).unwrap().version(), 1);
+    assert!(view.get(&id(3)).is_none());
+
+    assert!(state.at(5).is_err());
+}
+
+// -------------------------------------------------------------
+// Test: Pruning drops old heights but keeps recent reads exact
+// -------------------------------------------------------------
+#[test]
+fn pruning_limits_history() {
+    let mut state = three_blocks();
+    state.set_retention(Some(1));
+
+    assert!(matches!(
+        state.get_at(&id(1), 0),
+        Err(StateError::PrunedHeight { height: 0, earliest: 1 })
+    ));
+    assert_eq!(data_at(&state, 1, 1), Some(vec![11]));
+    assert_eq!(data_at(&state, 2, 1), Some(vec![20]));
+
+    // Retention follows new commits
+    state.commit().unwrap();
+    assert!(state.get_at(&id(2), 1).is_err());
+    assert_eq!(data_at(&state, 2, 2), Some(vec![20]));
+    assert_eq!(data_at(&state, 2, 3), Some(vec![20]));
+}
+
+// -------------------------------------------------------------
+// Test: Heights must increase
+// -------------------------------------------------------------
+#[test]
+fn commit_heights_must_increase() {
+    let mut state = StateStore::new();
+    state.commit_at(5).unwrap();
+
+    assert!(matches!(
+        state.commit_at(5),
+        Err(StateError::InvalidHeight { expected_above: 5, found: 5 })
+    ));
+
+    state.commit().unwrap();
+    assert_eq!(state.height(), Some(6));
+}
+
+// -------------------------------------------------------------
+// Test: History is rebuilt from the persistent log
+// -------------------------------------------------------------
+#[test]
+fn history_survives_reopen() {
+    let path = std::env::temp_dir().join(format!("axiom-state-history-{}.log", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    let _ = std::fs::remove_file(path.with_extension("log.tree"));
+
+    {
+        let mut state = StateStore::open(&path).unwrap();
+        state.insert(object(1, &[10])).unwrap();
+        state.commit_at(3).unwrap();
+        state.insert_or_update(state.get(&id(1)).unwrap().next_with_data(vec![11])).unwrap();
+        state.commit_at(7).unwrap();
+    }
+
+    let state = StateStore::open(&path).unwrap();
+    assert_eq!(state.height(), Some(7));
+    assert_eq!(data_at(&state, 1, 5), Some(vec![10]));
+    assert_eq!(data_at(&state, 1, 7), Some(vec![11]));
+
+    std::fs::remove_file(&path).unwrap();
+    let _ = std::fs::remove_file(path.with_extension("log.tree"));
+}
+
+// -------------------------------------------------------------
+// Test: Reverting restores an earlier height exactly
+// -------------------------------------------------------------
+#[test]
+fn revert_restores_earlier_height() {
+    let mut state = StateStore::new();
+    state.insert(object(1, &[10])).unwrap();
+    state.commit().unwrap();
+    let root_at_0 = compute_state_root(&state);
+
+    let mut reverted = three_blocks();
+    reverted.revert_to(0).unwrap();
+
+    assert_eq!(reverted.height(), Some(0));
+    assert_eq!(compute_state_root(&reverted), root_at_0);
+    assert_eq!(reverted.get(&id(1)).unwrap().data(), &[10]);
+    assert!(reverted.get(&id(2)).is_none());
+    assert!(reverted.tombstone(&id(1)).is_none());
+    assert!(matches!(reverted.get_at(&id(1), 1), Err(StateError::UnknownHeight { .. })));
+
+    // The reverted heights can be committed again
+    reverted.insert(object(3, &[30])).unwrap();
+    reverted.commit().unwrap();
+    assert_eq!(reverted.height(), Some(1));
+
+    // Pruned heights cannot be reverted to
+    let mut pruned = three_blocks();
+    pruned.set_retention(Some(0));
+    assert!(matches!(pruned.revert_to(1), Err(StateError::PrunedHeight { .. })));
+}
@@ -0,0 +1,431 @@
+use std::fs::{File, OpenOptions};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+use axiom_state::{
+    StateStore, StateObject, StateError, LogBackend, LogFile, TREE_SNAPSHOT_INTERVAL,
+    compute_state_root,
+};
+use axiom_types::{Address, ObjectId, Hash};
+
+// -------------------------------------------------------------
+// Helper: fresh log path per test
+// -------------------------------------------------------------
+fn log_path(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("axiom-state-{}-{}.log", name, std::process::id()));
+    remove_log(&path);
+    path
+}
+
+fn tree_path(path: &Path) -> PathBuf {
+    path.with_extension("log.tree")
+}
+
+fn remove_log(path: &Path) {
+    let _ = std::fs::remove_file(path);
+    let _ = std::fs::remove_file(tree_path(path));
+}
+
+// -------------------------------------------------------------
+// Helper: log file whose writes and truncations can be made to fail
+// -------------------------------------------------------------
+#[derive(Debug)]
+struct Faults {
+    /// Bytes that may still be written before writes fail.
+    write_budget: AtomicUsize,
+    fail_truncate: AtomicBool,
+}
+
+#[derive(Debug)]
+struct FaultyFile {
+    inner: File,
+    faults: Arc<Faults>,
+}
+
+impl Read for FaultyFile {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.inner.read(buf)
+    }
+}
+
+impl Seek for FaultyFile {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        self.inner.seek(pos)
+    }
+}
+
+impl Write for FaultyFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let budget = self.faults.write_budget.load(Ordering::SeqCst);
+        if budget == 0 {
+            return Err(io::Error::other("disk full"));
+        }
+
+        let written = self.inner.write(&buf[..buf.len().min(budget)])?;
+        self.faults.write_budget.store(budget.saturating_sub(written), Ordering::SeqCst);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+impl LogFile for FaultyFile {
+    fn set_len(&mut self, len: u64) -> io::Result<()> {
+        if self.faults.fail_truncate.load(Ordering::SeqCst) {
+            return Err(io::Error::other("truncate failed"));
+        }
+        self.inner.set_len(len)
+    }
+
+    fn sync_data(&mut self) -> io::Result<()> {
+        self.inner.sync_data()
+    }
+}
+
+fn open_faulty(path: &Path) -> (StateStore, Arc<Faults>) {
+    let faults = Arc::new(Faults {
+        write_budget: AtomicUsize::new(usize::MAX),
+        fail_truncate: AtomicBool::new(false),
+    });
+
+    let file = OpenOptions::new().read(true).append(true).create(true).open(path).unwrap();
+    let file = FaultyFile { inner: file, faults: faults.clone() };
+    let backend = LogBackend::from_file(path, file).unwrap();
+
+    (StateStore::with_backend(Box::new(backend)), faults)
+}
+
+fn object(seed: u8, data: &[u8]) -> StateObject {
+    StateObject::new(
+        ObjectId::new(Hash::new([seed; 32])),
+        Address::new([seed; 32]),
+        data.to_vec(),
+    )
+}
+
+// -------------------------------------------------------------
+// Test: Committed state survives a restart
+// -------------------------------------------------------------
+#[test]
+fn committed_state_survives_reopen() {
+    let path = log_path("reopen");
+
+    let root = {
+        let mut state = StateStore::open(&path).unwrap();
+        state.insert(object(1, &[1])).unwrap();
+        state.insert(object(2, &[2])).unwrap();
+        state.commit().unwrap();
+
+        // Second block: update one object, delete the other
+        let updated = state.get(&object(1, &[]).id()).unwrap().next_with_data(vec![9]);
+        state.insert_or_update(updated).unwrap();
+        state.remove(&object(2, &[]).id(), 1).unwrap();
+        state.commit().unwrap();
+
+        compute_state_root(&state)
+    };
+
+    let state = StateStore::open(&path).unwrap();
+
+    assert_eq!(compute_state_root(&state), root);
+    assert_eq!(state.get(&object(1, &[]).id()).unwrap().data(), &[9]);
+    assert_eq!(state.get(&object(1, &[]).id()).unwrap().version(), 1);
+    assert!(state.get(&object(2, &[]).id()).is_none());
+    assert_eq!(state.tombstone(&object(2, &[]).id()), Some(1));
+
+    remove_log(&path);
+}
+
+// -------------------------------------------------------------
+// Test: Uncommitted writes are not persisted
+// -------------------------------------------------------------
+#[test]
+fn uncommitted_writes_are_lost() {
+    let path = log_path("uncommitted");
+
+    {
+        let mut state = StateStore::open(&path).unwrap();
+        state.insert(object(1, &[1])).unwrap();
+        state.commit().unwrap();
+
+        state.insert(object(2, &[2])).unwrap();
+        assert!(state.has_pending());
+    }
+
+    let state = StateStore::open(&path).unwrap();
+    assert!(state.get(&object(1, &[]).id()).is_some());
+    assert!(state.get(&object(2, &[]).id()).is_none());
+
+    remove_log(&path);
+}
+
+// -------------------------------------------------------------
+// Test: A torn batch is discarded entirely on reopen
+// -------------------------------------------------------------
+#[test]
+fn torn_batch_is_discarded() {
+    let path = log_path("torn");
+
+    let root = {
+        let mut state = StateStore::open(&path).unwrap();
+        state.insert(object(1, &[1])).unwrap();
+        state.commit().unwrap();
+        compute_state_root(&state)
+    };
+
+    let committed_len = std::fs::metadata(&path).unwrap().len();
+
+    {
+        let mut state = StateStore::open(&path).unwrap();
+        state.insert(object(2, &[2])).unwrap();
+        state.insert(object(3, &[3])).unwrap();
+        state.commit().unwrap();
+    }
+
+    // Simulate a crash halfway through writing the second batch
+    let full_len = std::fs::metadata(&path).unwrap().len();
+    let file = OpenOptions::new().write(true).open(&path).unwrap();
+    file.set_len(committed_len + (full_len - committed_len) / 2).unwrap();
+
+    let mut state = StateStore::open(&path).unwrap();
+    assert_eq!(compute_state_root(&state), root);
+    assert!(state.get(&object(2, &[]).id()).is_none());
+    assert!(state.get(&object(3, &[]).id()).is_none());
+
+    // The log keeps working after recovery
+    state.insert(object(4, &[4])).unwrap();
+    state.commit().unwrap();
+    drop(state);
+
+    let state = StateStore::open(&path).unwrap();
+    assert!(state.get(&object(4, &[]).id()).is_some());
+
+    remove_log(&path);
+}
+
+// -------------------------------------------------------------
+// Test: Corrupted checksums are treated as torn writes
+// -------------------------------------------------------------
+#[test]
+fn corrupted_tail_is_discarded() {
+    let path = log_path("corrupt");
+
+    {
+        let mut state = StateStore::open(&path).unwrap();
+        state.insert(object(1, &[1])).unwrap();
+        state.commit().unwrap();
+    }
+
+    let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+    file.write_all(&[0, 0, 0, 1, 0xff, 0xaa]).unwrap();
+    drop(file);
+
+    let state = StateStore::open(&path).unwrap();
+    assert!(state.get(&object(1, &[]).id()).is_some());
+
+    remove_log(&path);
+}
+
+// -------------------------------------------------------------
+// Test: Reverted blocks stay reverted after reopen
+// -------------------------------------------------------------
+#[test]
+fn revert_is_persisted() {
+    let path = log_path("revert");
+
+    let root = {
+        let mut state = StateStore::open(&path).unwrap();
+        state.insert(object(1, &[1])).unwrap();
+        state.commit().unwrap();
+        let root = compute_state_root(&state);
+
+        state.insert(object(2, &[2])).unwrap();
+        state.commit().unwrap();
+
+        state.revert_to(0).unwrap();
+        assert_eq!(compute_state_root(&state), root);
+
+        // A replacement block at the reverted height
+        state.insert(object(3, &[3])).unwrap();
+        state.commit().unwrap();
+        compute_state_root(&state)
+    };
+
+    let state = StateStore::open(&path).unwrap();
+    assert_eq!(compute_state_root(&state), root);
+    assert_eq!(state.height(), Some(1));
+    assert!(state.get(&object(2, &[]).id()).is_none());
+    assert!(state.get(&object(3, &[]).id()).is_some());
+
+    remove_log(&path);
+}
+
+// -------------------------------------------------------------
+// Test: Reopening starts from the saved tree
+// -------------------------------------------------------------
+#[test]
+fn tree_snapshot_survives_reopen() {
+    let path = log_path("tree");
+
+    let root = {
+        let mut state = StateStore::open(&path).unwrap();
+        for seed in 1..=5 {
+            state.insert(object(seed, &[seed])).unwrap();
+            state.commit().unwrap();
+        }
+        compute_state_root(&state)
+    };
+
+    // Saved at the first height; later heights are replayed onto it
+    assert!(tree_path(&path).exists());
+    let state = StateStore::open(&path).unwrap();
+    assert_eq!(compute_state_root(&state), root);
+    drop(state);
+
+    // A damaged snapshot is ignored
+    std::fs::write(tree_path(&path), [0u8; 48]).unwrap();
+    let state = StateStore::open(&path).unwrap();
+    assert_eq!(compute_state_root(&state), root);
+
+    remove_log(&path);
+}
+
+// -------------------------------------------------------------
+// Test: A snapshot of reverted heights is never reused
+// -------------------------------------------------------------
+#[test]
+fn reverted_tree_snapshot_is_discarded() {
+    let path = log_path("tree-revert");
+
+    let root = {
+        let mut state = StateStore::open(&path).unwrap();
+        for height in 0..=TREE_SNAPSHOT_INTERVAL {
+            state.insert(object(height as u8 + 1, &[])).unwrap();
+            state.commit().unwrap();
+        }
+
+        // Replace every height after 10 with a different fork
+        state.revert_to(10).unwrap();
+        for height in 11..=TREE_SNAPSHOT_INTERVAL + 1 {
+            let replaced = state.get(&object(1, &[]).id()).unwrap().next_with_data(vec![height as u8]);
+            state.insert_or_update(replaced).unwrap();
+            state.commit().unwrap();
+        }
+        compute_state_root(&state)
+    };
+
+    let state = StateStore::open(&path).unwrap();
+    assert_eq!(compute_state_root(&state), root);
+
+    remove_log(&path);
+}
+
+// -------------------------------------------------------------
+// Test: A failed append leaves no partial record behind
+// -------------------------------------------------------------
+#[test]
+fn failed_append_is_rolled_back() {
+    let path = log_path("failed-append");
+
+    let root = {
+        let (mut state, faults) = open_faulty(&path);
+        state.insert(object(1, &[1])).unwrap();
+        state.commit().unwrap();
+
+        // Run out of space partway through the next record
+        faults.write_budget.store(10, Ordering::SeqCst);
+        state.insert(object(2, &[2])).unwrap();
+        assert!(matches!(state.commit(), Err(StateError::Io(_))));
+
+        // The writes stay staged and the retry lands after the valid log
+        faults.write_budget.store(usize::MAX, Ordering::SeqCst);
+        state.commit().unwrap();
+
+        state.insert(object(3, &[3])).unwrap();
+        state.commit().unwrap();
+        compute_state_root(&state)
+    };
+
+    let state = StateStore::open(&path).unwrap();
+    assert_eq!(state.height(), Some(2));
+    assert_eq!(compute_state_root(&state), root);
+    assert!(state.get(&object(2, &[]).id()).is_some());
+    assert!(state.get(&object(3, &[]).id()).is_some());
+
+    remove_log(&path);
+}
+
+// -------------------------------------------------------------
+// Test: A log that cannot be restored refuses further writes
+// -------------------------------------------------------------
+#[test]
+fn unrecoverable_append_poisons_log() {
+    let path = log_path("poisoned");
+
+    let root = {
+        let (mut state, faults) = open_faulty(&path);
+        state.insert(object(1, &[1])).unwrap();
+        state.commit().unwrap();
+        let root = compute_state_root(&state);
+
+        faults.write_budget.store(10, Ordering::SeqCst);
+        faults.fail_truncate.store(true, Ordering::SeqCst);
+        state.insert(object(2, &[2])).unwrap();
+        assert!(matches!(state.commit(), Err(StateError::Io(_))));
+
+        // Even once the disk recovers
+        faults.write_budget.store(usize::MAX, Ordering::SeqCst);
+        faults.fail_truncate.store(false, Ordering::SeqCst);
+        assert!(matches!(state.commit(), Err(StateError::PoisonedLog)));
+        assert!(matches!(state.revert_to(0), Err(StateError::PoisonedLog)));
+        root
+    };
+
+    // The partial record is a torn tail on reopen
+    let state = StateStore::open(&path).unwrap();
+    assert_eq!(state.height(), Some(0));
+    assert_eq!(compute_state_root(&state), root);
+
+    remove_log(&path);
+}
+
+// -------------------------------------------------------------
+// Test: Damage before the end of the log is not a torn write
+// -------------------------------------------------------------
+#[test]
+fn corrupted_middle_record_fails_open() {
+    let path = log_path("corrupt-middle");
+
+    let first_len = {
+        let mut state = StateStore::open(&path).unwrap();
+        state.insert(object(1, &[1])).unwrap();
+        state.commit().unwrap();
+        let first_len = std::fs::metadata(&path).unwrap().len();
+
+        for seed in 2..=3 {
+            state.insert(object(seed, &[seed])).unwrap();
+            state.commit().unwrap();
+        }
+        first_len
+    };
+
+    // Flip a byte inside the second record's payload
+    let mut bytes = std::fs::read(&path).unwrap();
+    let full_len = bytes.len();
+    bytes[first_len as usize + 12] ^= 0xff;
+    std::fs::write(&path, &bytes).unwrap();
+
+    assert!(matches!(
+        StateStore::open(&path),
+        Err(StateError::CorruptedLog { offset }) if offset == first_len
+    ));
+
+    // Committed history is left in place
+    assert_eq!(std::fs::metadata(&path).unwrap().len(), full_len as u64);
+
+    remove_log(&path);
+}
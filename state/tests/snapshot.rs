@@ -0,0 +1,138 @@
+use axiom_state::{
+    StateStore, StateObject, LogBackend, SnapshotImporter, SnapshotError,
+    compute_state_root, export_snapshot,
+};
+use axiom_types::{Address, ObjectId, Hash};
+
+// -------------------------------------------------------------
+// Helper: committed state with objects and a tombstone
+// -------------------------------------------------------------
+fn object(seed: u8, data: &[u8]) -> StateObject {
+    StateObject::new(
+        ObjectId::new(Hash::new([seed; 32])),
+        Address::new([seed; 32]),
+        data.to_vec(),
+    )
+}
+
+fn populated_state() -> StateStore {
+    let mut state = StateStore::new();
+    for seed in 1..=10 {
+        state.insert(object(seed, &[seed; 16])).unwrap();
+    }
+    state.commit().unwrap();
+
+    state.remove(&object(4, &[]).id(), 1).unwrap();
+    state.commit().unwrap();
+
+    state
+}
+
+// -------------------------------------------------------------
+// Test: Export and import reproduce the same state root
+// -------------------------------------------------------------
+#[test]
+fn snapshot_round_trip_preserves_state() {
+    let state = populated_state();
+    let (manifest, chunks) = export_snapshot(&state, 64).unwrap();
+
+    assert!(chunks.len() > 1, "small chunk size must split the snapshot");
+    assert_eq!(manifest.chunk_hashes.len(), chunks.len());
+    assert_eq!(manifest.state_root, compute_state_root(&state));
+    assert_eq!(manifest.height, 1);
+
+    let mut importer = SnapshotImporter::new(manifest).unwrap();
+    for chunk in &chunks {
+        importer.push_chunk(chunk).unwrap();
+    }
+    let imported = importer.finish().unwrap();
+
+    assert_eq!(compute_state_root(&imported), compute_state_root(&state));
+    assert_eq!(imported.height(), Some(1));
+    assert_eq!(imported.tombstone(&object(4, &[]).id()), Some(1));
+    assert_eq!(imported.get(&object(7, &[]).id()).unwrap().data(), &[7; 16]);
+}
+
+// -------------------------------------------------------------
+// Test: Export is deterministic
+// -------------------------------------------------------------
+#[test]
+fn snapshot_export_is_deterministic() {
+    let (m1, c1) = export_snapshot(&populated_state(), 64).unwrap();
+    let (m2, c2) = export_snapshot(&populated_state(), 64).unwrap();
+
+    assert_eq!(m1, m2);
+    assert_eq!(m1.hash(), m2.hash());
+    assert_eq!(c1, c2);
+}
+
+// -------------------------------------------------------------
+// Test: Tampered or out-of-order chunks are rejected
+// -------------------------------------------------------------
+#[test]
+fn snapshot_rejects_bad_chunks() {
+    let (manifest, chunks) = export_snapshot(&populated_state(), 64).unwrap();
+
+    let mut tampered = chunks[0].clone();
+    *tampered.last_mut().unwrap() ^= 1;
+
+    let mut importer = SnapshotImporter::new(manifest.clone()).unwrap();
+    assert!(matches!(
+        importer.push_chunk(&tampered),
+        Err(SnapshotError::ChunkHashMismatch { index: 0 })
+    ));
+    assert!(matches!(
+        importer.push_chunk(&chunks[1]),
+        Err(SnapshotError::ChunkHashMismatch { index: 0 })
+    ));
+
+    // Stopping early is an error
+    importer.push_chunk(&chunks[0]).unwrap();
+    assert!(matches!(importer.finish(), Err(SnapshotError::MissingChunks { .. })));
+}
+
+// -------------------------------------------------------------
+// Test: A manifest with the wrong root fails the final check
+// -------------------------------------------------------------
+#[test]
+fn snapshot_rejects_root_mismatch() {
+    let (mut manifest, chunks) = export_snapshot(&populated_state(), 64).unwrap();
+    manifest.state_root = Hash::new([0xab; 32]);
+
+    let mut importer = SnapshotImporter::new(manifest).unwrap();
+    for chunk in &chunks {
+        importer.push_chunk(chunk).unwrap();
+    }
+
+    assert!(matches!(importer.finish(), Err(SnapshotError::RootMismatch { .. })));
+}
+
+// -------------------------------------------------------------
+// Test: Pending writes block export; imports can go to disk
+// -------------------------------------------------------------
+#[test]
+fn snapshot_imports_into_persistent_backend() {
+    let mut state = populated_state();
+    state.insert(object(11, &[11])).unwrap();
+    assert!(matches!(export_snapshot(&state, 64), Err(SnapshotError::PendingWrites)));
+    state.commit().unwrap();
+
+    let (manifest, chunks) = export_snapshot(&state, 1024).unwrap();
+
+    let path = std::env::temp_dir().join(format!("axiom-state-snapshot-{}.log", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    let _ = std::fs::remove_file(path.with_extension("log.tree"));
+
+    let mut importer = SnapshotImporter::new(manifest).unwrap();
+    for chunk in &chunks {
+        importer.push_chunk(chunk).unwrap();
+    }
+    drop(importer.finish_into(Box::new(LogBackend::open(&path).unwrap())).unwrap());
+
+    let reopened = StateStore::open(&path).unwrap();
+    assert_eq!(compute_state_root(&reopened), compute_state_root(&state));
+    assert_eq!(reopened.height(), Some(2));
+
+    std::fs::remove_file(&path).unwrap();
+    let _ = std::fs::remove_file(path.with_extension("log.tree"));
+}
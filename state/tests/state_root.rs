@@ -0,0 +1,150 @@
+use axiom_state::{SparseMerkleTree, StateStore, StateObject, compute_state_root, verify_proof};
+use axiom_types::{Address, ObjectId, Hash};
+
+// -------------------------------------------------------------
+// Helper: object with a distinct id, owner and data
+// -------------------------------------------------------------
+fn object(seed: u8, data: &[u8]) -> StateObject {
+    StateObject::new(
+        ObjectId::new(Hash::new([seed; 32])),
+        Address::new([seed.wrapping_add(1); 32]),
+        data.to_vec(),
+    )
+}
+
+// -------------------------------------------------------------
+// Test: Root is independent of insertion order
+// -------------------------------------------------------------
+#[test]
+fn state_root_is_order_independent() {
+    let mut a = StateStore::new();
+    let mut b = StateStore::new();
+
+    for seed in [1u8, 2, 3] {
+        a.insert(object(seed, &[seed])).unwrap();
+    }
+    for seed in [3u8, 1, 2] {
+        b.insert(object(seed, &[seed])).unwrap();
+    }
+
+    assert_eq!(compute_state_root(&a), compute_state_root(&b));
+    assert_ne!(compute_state_root(&a), compute_state_root(&StateStore::new()));
+}
+
+// -------------------------------------------------------------
+// Test: Updates are reflected incrementally
+// -------------------------------------------------------------
+#[test]
+fn state_root_tracks_updates() {
+    let mut incremental = StateStore::new();
+    incremental.insert(object(1, &[1])).unwrap();
+    incremental.insert(object(2, &[2])).unwrap();
+
+    let updated = incremental.get(&object(1, &[]).id()).unwrap().next_with_data(vec![9]);
+    incremental.insert_or_update(updated.clone()).unwrap();
+
+    // Same contents built from scratch
+    let mut fresh = StateStore::new();
+    fresh.insert(updated).unwrap();
+    fresh.insert(object(2, &[2])).unwrap();
+
+    assert_eq!(compute_state_root(&incremental), compute_state_root(&fresh));
+}
+
+// -------------------------------------------------------------
+// Test: Inclusion proofs verify only the committed object
+// -------------------------------------------------------------
+#[test]
+fn inclusion_proof_verifies() {
+    let mut state = StateStore::new();
+    for seed in [1u8, 2, 3] {
+        state.insert(object(seed, &[seed])).unwrap();
+    }
+
+    let root = compute_state_root(&state);
+    let target = object(2, &[2]);
+    let proof = state.prove(&target.id());
+
+    assert!(verify_proof(&root, &target.id(), Some(&target), &proof));
+
+    // Wrong data, wrong id, or claimed absence all fail
+    assert!(!verify_proof(&root, &target.id(), Some(&object(2, &[7])), &proof));
+    assert!(!verify_proof(&root, &object(1, &[]).id(), Some(&target), &proof));
+    assert!(!verify_proof(&root, &target.id(), None, &proof));
+}
+
+// -------------------------------------------------------------
+// Test: Exclusion proofs for missing and deleted objects
+// -------------------------------------------------------------
+#[test]
+fn exclusion_proof_verifies() {
+    let mut state = StateStore::new();
+    state.insert(object(1, &[1])).unwrap();
+    state.insert(object(2, &[2])).unwrap();
+    state.remove(&object(2, &[]).id(), 1).unwrap();
+
+    let root = compute_state_root(&state);
+
+    // Never created
+    let missing = object(5, &[]).id();
+    let proof = state.prove(&missing);
+    assert!(verify_proof(&root, &missing, None, &proof));
+
+    // Deleted
+    let deleted = object(2, &[2]);
+    let proof = state.prove(&deleted.id());
+    assert_eq!(proof.tombstone, Some(1));
+    assert!(verify_proof(&root, &deleted.id(), None, &proof));
+    assert!(!verify_proof(&root, &deleted.id(), Some(&deleted), &proof));
+}
+
+// -------------------------------------------------------------
+// Test: Proofs are bound to the root they were built against
+// -------------------------------------------------------------
+#[test]
+fn proof_rejects_stale_root() {
+    let mut state = StateStore::new();
+    let first = object(1, &[1]);
+    state.insert(first.clone()).unwrap();
+
+    let old_root = compute_state_root(&state);
+    let old_proof = state.prove(&first.id());
+
+    state.insert(object(2, &[2])).unwrap();
+    let new_root = compute_state_root(&state);
+
+    assert!(verify_proof(&old_root, &first.id(), Some(&first), &old_proof));
+    assert!(!verify_proof(&new_root, &first.id(), Some(&first), &old_proof));
+    assert!(verify_proof(&new_root, &first.id(), Some(&first), &state.prove(&first.id())));
+}
+
+// -------------------------------------------------------------
+// Test: Decoded trees recompute their internal hashes
+// -------------------------------------------------------------
+#[test]
+fn decoded_tree_rehashes_internal_nodes() {
+    let ids: Vec<ObjectId> = [1u8, 2, 3].map(|seed| ObjectId::new(Hash::new([seed; 32]))).to_vec();
+
+    let mut tree = SparseMerkleTree::new();
+    for (index, id) in ids.iter().enumerate() {
+        tree.update(id, Some(Hash::new([index as u8 + 10; 32])));
+    }
+
+    let encoded = tree.encode();
+    assert_eq!(SparseMerkleTree::decode(&encoded).unwrap().root(), tree.root());
+
+    // The last encoded bytes are the hash of the last leaf; the decoded
+    // root commits to the tampered leaf, not to the original tree
+    let mut tampered = encoded.clone();
+    let last = tampered.len() - 1;
+    tampered[last] ^= 1;
+
+    let mut leaf = [12u8; 32];
+    leaf[31] ^= 1;
+    let mut expected = tree.clone();
+    expected.update(&ids[2], Some(Hash::new(leaf)));
+
+    let decoded = SparseMerkleTree::decode(&tampered).unwrap();
+    assert_eq!(decoded.root(), expected.root());
+    assert_ne!(decoded.root(), tree.root());
+}
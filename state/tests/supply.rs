@@ -0,0 +1,60 @@
+use axiom_state::{
+    StateStore, StateObject, Supply, SupplyError, balance_object_id, check_supply, encode_balance,
+    supply_object_id,
+};
+use axiom_types::Address;
+
+// -------------------------------------------------------------
+// Helper: state holding balances and a supply record
+// -------------------------------------------------------------
+fn state_with(balances: &[(u8, u64)], supply: Supply) -> StateStore {
+    let mut state = StateStore::new();
+
+    for (seed, amount) in balances {
+        let owner = Address::new([*seed; 32]);
+        state.insert(StateObject::new(balance_object_id(owner), owner, encode_balance(*amount))).unwrap();
+    }
+
+    state.insert(supply.to_object()).unwrap();
+    state
+}
+
+// -------------------------------------------------------------
+// Test: Balances plus burned equal issued supply
+// -------------------------------------------------------------
+#[test]
+fn supply_is_conserved() {
+    let state = state_with(&[(1, 60), (2, 30)], Supply { issued: 100, burned: 10 });
+
+    assert_eq!(check_supply(&state).unwrap(), Supply { issued: 100, burned: 10 });
+}
+
+// -------------------------------------------------------------
+// Test: Unaccounted balance changes are detected
+// -------------------------------------------------------------
+#[test]
+fn supply_detects_imbalance() {
+    let minted_from_nothing = state_with(&[(1, 60), (2, 31)], Supply { issued: 100, burned: 10 });
+    assert!(matches!(
+        check_supply(&minted_from_nothing),
+        Err(SupplyError::NotConserved { issued: 100, burned: 10, balances: 91 })
+    ));
+
+    // Objects stored under another account's balance id do not count
+    let mut state = state_with(&[(1, 90)], Supply { issued: 100, burned: 10 });
+    let impostor = StateObject::new(balance_object_id(Address::new([3; 32])), Address::new([4; 32]), encode_balance(5));
+    state.insert(impostor).unwrap();
+    assert!(check_supply(&state).is_ok());
+}
+
+// -------------------------------------------------------------
+// Test: States without a valid supply record cannot be checked
+// -------------------------------------------------------------
+#[test]
+fn supply_requires_record() {
+    assert!(matches!(check_supply(&StateStore::new()), Err(SupplyError::MissingSupply)));
+
+    let mut state = StateStore::new();
+    state.insert(StateObject::new(supply_object_id(), Address::zero(), vec![1, 2, 3])).unwrap();
+    assert!(matches!(check_supply(&state), Err(SupplyError::MalformedSupply)));
+}
@@ -1,4 +1,4 @@
-use axiom_state::StateStore;
+use axiom_state::{ObjectWrite, StateStore};
 use crate::StateDiff;
 use crate::CommitError;
 
@@ -22,16 +22,34 @@ pub fn commit_state_diff(
         }
     }
 
-    // 2️⃣ Apply writes atomically
-    for (_, object) in diff.writes {
-        // copy BEFORE move
-        let object_id = object.id(); 
+    // 2️⃣ Validate writes before touching state
+    for (object_id, write) in &diff.writes {
+        let valid = match write {
+            // Deletions remove exactly the current version
+            ObjectWrite::Delete { version, .. } => state
+                .get(object_id)
+                .is_some_and(|existing| *version == existing.version() + 1),
 
-        state.insert_or_update(object).map_err(|_| {
-            CommitError::InvalidWrite {
-                object: object_id,
+            // Deleted ids may only come back at a newer version
+            ObjectWrite::Put(object) => {
+                state.get(object_id).is_some()
+                    || state.tombstone(object_id).is_none_or(|deleted| object.version() > deleted)
             }
-        })?;
+        };
+
+        if !valid {
+            return Err(CommitError::InvalidWrite { object: *object_id });
+        }
+    }
+
+    // 3️⃣ Apply writes atomically
+    for (object_id, write) in diff.writes {
+        let result = match write {
+            ObjectWrite::Put(object) => state.insert_or_update(object),
+            ObjectWrite::Delete { version, .. } => state.remove(&object_id, version).map(|_| ()),
+        };
+
+        result.map_err(|_| CommitError::InvalidWrite { object: object_id })?;
     }
 
     Ok(())
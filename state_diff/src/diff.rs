@@ -1,7 +1,7 @@
 use std::collections::BTreeMap;
 
 use axiom_types::ObjectId;
-use axiom_state::{StateObject, ReadSet};
+use axiom_state::{ObjectWrite, ReadSet};
 
 /// Canonical state transition produced by execution.
 #[derive(Debug)]
@@ -11,10 +11,10 @@ pub struct StateDiff {
     /// Used for optimistic concurrency control.
     pub read_set: ReadSet,
 
-    /// Objects that will be written.
+    /// Objects that will be written or deleted.
     ///
     /// Includes:
-    /// - execution writes
+    /// - execution writes and deletions (tombstones)
     /// - forced protocol writes (nonce, fees)
-    pub writes: BTreeMap<ObjectId, StateObject>,
+    pub writes: BTreeMap<ObjectId, ObjectWrite>,
 }
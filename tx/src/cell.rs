@@ -1,7 +1,7 @@
 /// Imports and dependencies
 use std::collections::BTreeMap;
 
-use axiom_types::{Hash, ObjectId, Slot};
+use axiom_types::{Hash, ObjectId};
 use axiom_state::{ReadSet};
 use crate::TxError;
 
@@ -27,10 +27,28 @@ pub struct CallData {
 
 /// Intent describing which objects may be written.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
 pub enum WriteIntent {
-    Create,
-    Modify,
-    Delete,
+    Create = 0,
+    Modify = 1,
+    Delete = 2,
+}
+
+impl WriteIntent {
+    /// Returns the canonical tag byte.
+    pub const fn as_u8(self) -> u8 {
+        self as u8
+    }
+
+    /// Parses a canonical tag byte.
+    pub const fn from_u8(tag: u8) -> Option<Self> {
+        match tag {
+            0 => Some(Self::Create),
+            1 => Some(Self::Modify),
+            2 => Some(Self::Delete),
+            _ => None,
+        }
+    }
 }
 
 /// A transaction cell.
@@ -39,9 +57,6 @@ pub enum WriteIntent {
 /// It declares *what may be touched*, not *what will be written*.
 #[derive(Clone, Debug)]
 pub struct TransactionCell {
-    /// Slot in which the transaction is valid (context, not identity).
-    slot: Slot,
-
     /// Read set of the transaction.
     read_set: ReadSet,
 
@@ -55,7 +70,6 @@ pub struct TransactionCell {
 impl TransactionCell {
     /// Creates a new transaction cell.
     pub fn new(
-        slot: Slot,
         read_set: ReadSet,
         write_set: BTreeMap<ObjectId, WriteIntent>,
         call: CallData,
@@ -70,45 +84,61 @@ impl TransactionCell {
         }
 
         Ok(Self {
-            slot,
             read_set,
             write_set,
             call,
         })
     }
 
-    /// Computes the deterministic identifier for this transaction cell.
+    /// Canonical encoding of the full cell.
     ///
-    /// IMPORTANT:
-    /// - Depends only on declared intent
-    /// - Must NOT depend on execution output
-    /// - Slot is intentionally excluded
-    pub fn id(&self) -> Hash {
+    /// Covers read set and write set (ascending by object id) and call
+    /// data. Every variable-length field is length-prefixed, so
+    /// distinct cells never share an encoding.
+    pub fn encode(&self) -> Vec<u8> {
         let mut bytes = Vec::new();
 
         // 1️⃣ ReadSet (canonical order)
-        for (id, version) in &self.read_set {
+        let mut reads: Vec<_> = self.read_set.iter().collect();
+        reads.sort_by_key(|(id, _)| **id);
+
+        bytes.extend_from_slice(&(reads.len() as u32).to_be_bytes());
+        for (id, version) in reads {
             bytes.extend_from_slice(id.as_hash().as_bytes());
             bytes.extend_from_slice(&version.to_be_bytes());
         }
 
         // 2️⃣ WriteSet (canonical order)
+        bytes.extend_from_slice(&(self.write_set.len() as u32).to_be_bytes());
         for (id, intent) in &self.write_set {
             bytes.extend_from_slice(id.as_hash().as_bytes());
-            bytes.push(*intent as u8);
+            bytes.push(intent.as_u8());
         }
 
         // 3️⃣ CallData
         bytes.extend_from_slice(self.call.target.as_hash().as_bytes());
+        bytes.extend_from_slice(&(self.call.selector.len() as u32).to_be_bytes());
         bytes.extend_from_slice(&self.call.selector);
+        bytes.extend_from_slice(&(self.call.payload.len() as u32).to_be_bytes());
         bytes.extend_from_slice(&self.call.payload);
 
-        Hash::new(blake3::hash(&bytes).into())
+        bytes
     }
 
-    /// Returns the slot of the transaction cell.
-    pub fn slot(&self) -> Slot {
-        self.slot
+    /// Computes the deterministic identifier for this transaction cell.
+    ///
+    /// IMPORTANT:
+    /// - Depends only on declared intent
+    /// - Must NOT depend on execution output
+    /// - Commits to the full canonical encoding
+    pub fn id(&self) -> Hash {
+        let mut bytes = Vec::new();
+
+        // Domain separator
+        bytes.extend_from_slice(b"Axiom::TransactionCell::v2");
+        bytes.extend_from_slice(&self.encode());
+
+        Hash::new(blake3::hash(&bytes).into())
     }
 
     /// Returns the read set of the transaction cell.
@@ -17,8 +17,7 @@ pub const ADDRESS_LENGTH: usize = 32;
 /// - protocol-owned objects
 ///
 /// Addresses have no inherent meaning beyond identity.
-
-#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct Address([u8; ADDRESS_LENGTH]);
 
 impl Address {